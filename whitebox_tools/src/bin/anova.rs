@@ -8,75 +8,42 @@ use std::process::Command;
 use std::io::BufWriter;
 use std::fs::File;
 use std::io::prelude::*;
-use std::env;
 use std::path;
 use std::path::Path;
 use std::f64;
 use std::f64::consts::PI;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::raster::*;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file = String::new();
-    let mut output_file = String::new();
-    let mut feature_file = String::new();
-    let mut working_directory: String = "".to_string();
-    let mut verbose: bool = false;
-    let mut keyval: bool;
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-features" || vec[0].to_lowercase() == "--features" {
-            if keyval {
-                feature_file = vec[1].to_string();
-            } else {
-                feature_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-            vec[0].to_lowercase() == "--h"{
-            let mut s: String = "Help:\n".to_owned();
-                     s.push_str("-i          Input raster file.\n");
-                     s.push_str("-o          Output HTML file.\n");
-                     s.push_str("-features   Feature definition (or class) raster.\n");
-                     s.push_str("-wd         Optional working directory. If specified, filenames parameters need not include a full path.\n");
-                     s.push_str("-version    Prints the tool version number.\n");
-                     s.push_str("-h          Prints help information.\n\n");
-                     s.push_str("Example usage:\n\n");
-                     s.push_str(&">> .*anova -wd *path*to*data* -i input.tif -features classes.tif -o anova.html -v\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("slope v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i          Input raster file.\n");
+        s.push_str("-o          Output HTML file.\n");
+        s.push_str("-features   Feature definition (or class) raster.\n");
+        s.push_str("-wd         Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite  Replace the output file if it already exists.\n");
+        s.push_str("-version    Prints the tool version number.\n");
+        s.push_str("-h          Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.tif -features classes.tif -o anova.html -v\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
     }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut feature_file = args.value(&["features"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
 
     if !working_directory.ends_with(&sep) {
         working_directory.push_str(&(sep.to_string()));
@@ -91,6 +58,9 @@ fn main() {
     if !output_file.ends_with(".html") {
         output_file = output_file + ".html";
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
     if !feature_file.contains(&sep) {
         feature_file = format!("{}{}", working_directory, feature_file);
     }
@@ -110,7 +80,6 @@ fn anova(input_file: String, feature_file: String, output_file: String, verbose:
 
     println!("Reading data...");
     let input =  Raster::new(&input_file, "r")?;
-    let nodata = input.configs.nodata;
     let columns = input.configs.columns;
     let rows = input.configs.rows;
 
@@ -194,7 +163,6 @@ fn anova(input_file: String, feature_file: String, output_file: String, verbose:
         Ok(f) => f,
         Err(err) => panic!("Error: {}", err),
     };
-    let nodata_features = features.configs.nodata;
     if features.configs.columns != columns || features.configs.rows != rows {
         panic!(Error::new(ErrorKind::InvalidInput, "The input and feature definition rasters must have the same number of rows and columns."));
     }
@@ -215,7 +183,7 @@ fn anova(input_file: String, feature_file: String, output_file: String, verbose:
         for col in 0..columns as isize {
             z = input.get_value(row, col);
             id = features.get_value(row, col);
-            if z != nodata && id != nodata_features {
+            if !input.is_nodata(z) && !features.is_nodata(id) {
                 id_int = id.round() as i32;
                 if id_int > max_id { max_id = id_int; }
                 if id_int < min_id { min_id = id_int; }
@@ -254,7 +222,7 @@ fn anova(input_file: String, feature_file: String, output_file: String, verbose:
         for col in 0..columns as isize {
             z = input.get_value(row, col);
             id = features.get_value(row, col);
-            if z != nodata && id != nodata_features {
+            if !input.is_nodata(z) && !features.is_nodata(id) {
                 // class statistics
                 id_int = id.round() as i32;
                 vec_id = (id_int - min_id) as usize;
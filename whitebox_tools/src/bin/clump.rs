@@ -0,0 +1,83 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::clump::clump;
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i          Input raster file, e.g. a classified image.\n");
+        s.push_str("-o          Output raster file.\n");
+        s.push_str("-diag       Connect diagonal neighbours too (8-connectivity).\n");
+        s.push_str("-wd         Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite  Replace the output file if it already exists.\n");
+        s.push_str("-version    Prints the tool version number.\n");
+        s.push_str("-h          Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i landcover.dep -o patches.dep -diag\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let diagonals = args.flag(&["diag"]);
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, output_file, working_directory, diagonals, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
+    diagonals: bool, overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("********************");
+        println!("* Welcome to clump *");
+        println!("********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    if verbose { println!("Clumping...") };
+    let mut output = clump(&input, diagonals);
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' clump tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Diagonal connectivity: {}", diagonals));
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+extern crate whitebox_tools;
+
+use std::f64;
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::conditional::{conditional, parse_statement};
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i            Input raster file.\n");
+        s.push_str("-o            Output raster file.\n");
+        s.push_str("-statement    Comparison, e.g. \"> 30\" or \"value <= 12.5\"; operators are <, <=, >, >=, == and !=.\n");
+        s.push_str("-true_value   Value where the statement is true: a number, 'input' or 'nodata' (default is 1).\n");
+        s.push_str("-false_value  Value where the statement is false: a number, 'input' or 'nodata' (default is nodata).\n");
+        s.push_str("-wd           Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite    Replace the output file if it already exists.\n");
+        s.push_str("-version      Prints the tool version number.\n");
+        s.push_str("-h            Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i slope.dep -o steep.dep -statement \">30\" -true_value 1 -false_value nodata\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let statement = args.value(&["statement"]).unwrap_or(String::new());
+    let true_value = args.value(&["true_value"]).unwrap_or("1".to_string());
+    let false_value = args.value(&["false_value"]).unwrap_or("nodata".to_string());
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, output_file, working_directory, statement, true_value, false_value, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
+    statement: String, true_value: String, false_value: String, overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("******************");
+        println!("* Welcome to con *");
+        println!("******************");
+    }
+
+    let (op, threshold) = parse_statement(&statement)?;
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let true_val = assigned_value(&true_value, &input)?;
+    let false_val = assigned_value(&false_value, &input)?;
+
+    if verbose { println!("Evaluating statement...") };
+    let mut output = conditional(&input, op, threshold, true_val, false_val);
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' con tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Statement: {}", statement));
+    output.add_metadata_entry(format!("True value: {}", true_value));
+    output.add_metadata_entry(format!("False value: {}", false_value));
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
+
+/// Interprets a -true_value or -false_value argument in the form `conditional` expects: NaN
+/// for 'input' and the input's nodata value for 'nodata'.
+fn assigned_value(value: &str, input: &Raster) -> Result<f64, io::Error> {
+    match value.trim().to_lowercase().as_ref() {
+        "input" => Ok(f64::NAN),
+        "nodata" => Ok(input.configs.nodata),
+        v => v.parse::<f64>().map_err(|_| io::Error::new(io::ErrorKind::InvalidInput,
+            format!("Invalid assigned value '{}'; use a number, 'input' or 'nodata'.", value))),
+    }
+}
@@ -0,0 +1,96 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::convert_vertical_units::{convert_vertical_units, METERS_PER_FOOT};
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i               Input DEM raster file.\n");
+        s.push_str("-o               Output raster file.\n");
+        s.push_str("-factor          Factor by which elevations are multiplied.\n");
+        s.push_str("-feet_to_meters  Converts elevations in feet to meters (a factor of 0.3048).\n");
+        s.push_str("-meters_to_feet  Converts elevations in meters to feet (a factor of 1 / 0.3048).\n");
+        s.push_str("-wd              Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite       Replace the output file if it already exists.\n");
+        s.push_str("-version         Prints the tool version number.\n");
+        s.push_str("-h               Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i DEM_ft.dep -o DEM_m.dep -feet_to_meters\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let factor = args.parse::<f64>(&["factor"]);
+    let feet_to_meters = args.flag(&["feet_to_meters"]);
+    let meters_to_feet = args.flag(&["meters_to_feet"]);
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, output_file, working_directory, factor, feet_to_meters, meters_to_feet, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
+    factor: Option<f64>, feet_to_meters: bool, meters_to_feet: bool, overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("*************************************");
+        println!("* Welcome to convert_vertical_units *");
+        println!("*************************************");
+    }
+
+    let (factor, z_units) = match (factor, feet_to_meters, meters_to_feet) {
+        (Some(factor), false, false) => (factor, None),
+        (None, true, false) => (METERS_PER_FOOT, Some("metres")),
+        (None, false, true) => (1f64 / METERS_PER_FOOT, Some("feet")),
+        _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, "Exactly one of -factor, -feet_to_meters and -meters_to_feet must be specified.")),
+    };
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    if verbose { println!("Converting vertical units...") };
+    let mut output = convert_vertical_units(&input, factor);
+    if let Some(z_units) = z_units {
+        output.configs.z_units = z_units.to_string();
+    }
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' convert_vertical_units tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
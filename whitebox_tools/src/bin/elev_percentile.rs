@@ -0,0 +1,86 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::elev_percentile::elev_percentile;
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i          Input DEM raster file.\n");
+        s.push_str("-o          Output raster file.\n");
+        s.push_str("-filter     Size of the filter kernel (default is 11).\n");
+        s.push_str("-zero_is_nodata  Treat zero-valued cells as nodata.\n");
+        s.push_str("-wd         Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite  Replace the output file if it already exists.\n");
+        s.push_str("-version    Prints the tool version number.\n");
+        s.push_str("-h          Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i DEM.dep -o elev_percentile.dep -filter 25\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let filter_size = args.parse_or(&["filter"], 11usize);
+    let zero_is_nodata = args.flag(&["zero_is_nodata"]);
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, output_file, working_directory, filter_size, zero_is_nodata, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
+    filter_size: usize, zero_is_nodata: bool, overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to elev_percentile *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    if verbose { println!("Calculating elevation percentiles...") };
+    let mut output = elev_percentile(&input, filter_size);
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' elev_percentile tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
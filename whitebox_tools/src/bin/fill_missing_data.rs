@@ -2,83 +2,64 @@ extern crate whitebox_tools;
 extern crate time;
 
 use std::io;
-use std::env;
 use std::path;
 use std::f64;
+use std::collections::HashMap;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::raster::*;
 use whitebox_tools::structures::fixed_radius_search::FixedRadiusSearch;
+use whitebox_tools::tools::clump::clump;
+use whitebox_tools::raster::resample::cubic_convolution;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file = String::new();
-    let mut output_file = String::new();
-    let mut working_directory: String = "".to_string();
-    let mut filter_size = 11usize;
-    let mut verbose: bool = false;
-    let mut keyval: bool;
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-            if keyval {
-                filter_size = vec[1].to_string().parse::<usize>().unwrap();
-            } else {
-                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-            vec[0].to_lowercase() == "--h"{
-            let mut s: String = "Help:\n".to_owned();
-                     s.push_str("-i       Input LAS file (classification).\n");
-                     s.push_str("-o       Output HTML file.\n");
-                     s.push_str("-wd      Optional working directory. If specified, filenames parameters need not include a full path.\n");
-                     s.push_str("-filter  Size of the filter kernel (default is 11).\n");
-                     s.push_str("-version Prints the tool version number.\n");
-                     s.push_str("-h       Prints help information.\n\n");
-                     s.push_str("Example usage:\n\n");
-                     s.push_str(&">> .*fill_missing_data -wd *path*to*data* -i input.dep -o NoOTOs.dep -filter 25 -slope 15.0\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("slope v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i       Input raster file.\n");
+        s.push_str("-o       Output raster file.\n");
+        s.push_str("-wd      Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-filter  Size of the filter kernel (default is 11).\n");
+        s.push_str("-aniso_dir   Azimuth (degrees from north) of the principal direction of continuity (default is 0.0).\n");
+        s.push_str("-aniso_ratio Anisotropy ratio; values > 1 favour donors along the principal direction (default is 1.0, isotropic).\n");
+        s.push_str("-method  Fill method, 'idw' or 'cubic' (default is idw).\n");
+        s.push_str("-max_hole_size  Optional maximum size, in cells, of the holes that are filled; larger holes remain nodata.\n");
+        s.push_str("-confidence  Optional output raster of the support of each filled cell: the sum of its IDW donor weights or, for cells filled by the cubic method, the number of valid stencil cells used; original cells are nodata and unfilled cells are 0.\n");
+        s.push_str("-overwrite  Replace the output file if it already exists.\n");
+        s.push_str("-version Prints the tool version number.\n");
+        s.push_str("-h       Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.dep -o filled.dep -filter 25 -method cubic\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let filter_size = args.parse_or(&["filter"], 11usize);
+    let aniso_dir = args.parse_or(&["aniso_dir"], 0f64);
+    let aniso_ratio = args.parse_or(&["aniso_ratio"], 1f64);
+    let cubic = args.value(&["method"]).map_or(false, |m| m.to_lowercase().contains("cubic"));
+    let max_hole_size = args.parse::<usize>(&["max_hole_size"]);
+    let confidence_file = args.value(&["confidence"]);
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
 
-    match run(input_file, output_file, working_directory, filter_size, verbose) {
+    match run(input_file, output_file, working_directory, filter_size, aniso_dir, aniso_ratio, cubic, max_hole_size, confidence_file, overwrite, verbose) {
         Ok(()) => println!("Complete!"),
         Err(err) => panic!("{}", err),
     }
 }
 
 fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
-    mut filter_size: usize, verbose: bool) -> Result<(), io::Error> {
+    mut filter_size: usize, aniso_dir: f64, aniso_ratio: f64, cubic: bool, max_hole_size: Option<usize>, mut confidence_file: Option<String>,
+    overwrite: bool, verbose: bool) -> Result<(), io::Error> {
 
     if verbose {
         println!("********************************");
@@ -108,11 +89,27 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     if !output_file.contains(&sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    check_output_file(&output_file, overwrite)?;
+    if let Some(ref mut f) = confidence_file {
+        if !f.contains(&sep) {
+            *f = format!("{}{}", working_directory, f);
+        }
+        check_output_file(f, overwrite)?;
+    }
 
     if verbose { println!("Reading data...") };
 
     let input = Raster::new(&input_file, "r")?;
     let mut output = Raster::initialize_using_file(&output_file, &input);
+    // How well supported each fill is: the sum of the IDW weights of the cell's donors or, for a
+    // cubic fill, the number of valid stencil cells used; original cells are nodata and cells
+    // that couldn't be filled are 0.
+    let mut confidence = confidence_file.as_ref().map(|f| {
+        let mut r = Raster::initialize_using_file(f, &input);
+        r.configs.data_type = DataType::F32;
+        r.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        r
+    });
 
     let start = time::now();
 
@@ -122,17 +119,38 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
 	let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
 
+    // Measure each hole (8-connected region of nodata cells) so that those larger than
+    // max_hole_size can be left unfilled, e.g. legitimately missing areas like lakes.
+    let hole_sizes = match max_hole_size {
+        Some(_) => {
+            if verbose { println!("Measuring data holes...") };
+            measure_holes(&input)
+        },
+        None => vec![],
+    };
+    let is_fillable = |row: isize, col: isize| -> bool {
+        match max_hole_size {
+            Some(max_size) => {
+                row >= 0 && col >= 0 && row < rows && col < columns &&
+                hole_sizes[(row * columns + col) as usize] <= max_size
+            },
+            None => true,
+        }
+    };
+
     // Interpolate the data holes. Start by locating all the edge cells.
     if verbose { println!("Interpolating data holes...") };
-    let mut frs: FixedRadiusSearch<f64> = FixedRadiusSearch::new(filter_size as f64);
+    // The donor's coordinates are stored with its value so that the anisotropic distance
+    // can be calculated from the offset to the hole cell.
+    let mut frs: FixedRadiusSearch<(f64, f64, f64)> = FixedRadiusSearch::new(filter_size as f64);
     for row in 0..rows {
         for col in 0..columns {
-            if input[(row, col)] != nodata {
+            if !input.is_nodata(input[(row, col)]) {
                 for i in 0..8 {
                     row_n = row + d_y[i];
                     col_n = col + d_x[i];
-                    if input[(row_n, col_n)] == nodata {
-                        frs.insert(col as f64, row as f64, input[(row, col)]);
+                    if input.is_nodata(input[(row_n, col_n)]) && is_fillable(row_n, col_n) {
+                        frs.insert(col as f64, row as f64, (col as f64, row as f64, input[(row, col)]));
                         break;
                     }
                 }
@@ -147,13 +165,30 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
         }
     }
 
+    // Rows increase southward, so the unit vector of the azimuth in (column, row) space is
+    // (sin, -cos). Offsets perpendicular to it are stretched by the anisotropy ratio.
+    let isotropic = aniso_ratio == 1f64;
+    let (sin_dir, cos_dir) = (aniso_dir.to_radians().sin(), aniso_dir.to_radians().cos());
+    let (mut d_along, mut d_across): (f64, f64);
     let mut sum_weights: f64;
     let mut dist: f64;
     for row in 0..rows {
         for col in 0..columns {
-            if input[(row, col)] == nodata {
+            if input.is_nodata(input[(row, col)]) && !is_fillable(row, col) {
+                output[(row, col)] = nodata;
+                if let Some(ref mut c) = confidence {
+                    c.set_value(row, col, 0f64);
+                }
+            } else if input.is_nodata(input[(row, col)]) {
                 sum_weights = 0f64;
-                let ret = frs.search(col as f64, row as f64);
+                let mut ret = frs.search(col as f64, row as f64);
+                if !isotropic {
+                    for j in 0..ret.len() {
+                        d_along = ((ret[j].0).0 - col as f64) * sin_dir - ((ret[j].0).1 - row as f64) * cos_dir;
+                        d_across = ((ret[j].0).0 - col as f64) * cos_dir + ((ret[j].0).1 - row as f64) * sin_dir;
+                        ret[j].1 = (d_along * d_along + aniso_ratio * aniso_ratio * d_across * d_across).sqrt();
+                    }
+                }
                 for j in 0..ret.len() {
                     dist = ret[j].1;
                     if dist > 0.0 {
@@ -164,10 +199,20 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
                 for j in 0..ret.len() {
                     dist = ret[j].1;
                     if dist > 0.0 {
-                        z += ret[j].0 * (1.0 / (dist * dist)) / sum_weights;
+                        z += (ret[j].0).2 * (1.0 / (dist * dist)) / sum_weights;
+                    }
+                }
+                let mut confidence_value = sum_weights;
+                if cubic {
+                    if let Some((z_c, num_stencil_cells)) = cubic_gap_estimate(&input, row, col, filter_size as isize) {
+                        z = z_c;
+                        confidence_value = num_stencil_cells as f64;
                     }
                 }
                 output[(row, col)] = z;
+                if let Some(ref mut c) = confidence {
+                    c.set_value(row, col, confidence_value);
+                }
             } else {
                 output[(row, col)] = input[(row, col)];
             }
@@ -186,6 +231,14 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
 
     output.add_metadata_entry("Created by whitebox_tools\' fill_missing_data tool".to_owned());
     output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Fill method: {}", if cubic { "cubic" } else { "idw" }));
+    if let Some(max_size) = max_hole_size {
+        output.add_metadata_entry(format!("Maximum hole size: {}", max_size));
+    }
+    if !isotropic {
+        output.add_metadata_entry(format!("Anisotropy direction: {}", aniso_dir));
+        output.add_metadata_entry(format!("Anisotropy ratio: {}", aniso_ratio));
+    }
     output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
 
     if verbose { println!("Saving data...") };
@@ -193,6 +246,13 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
         Ok(_) => if verbose { println!("Output file written") },
         Err(e) => return Err(e),
     };
+    if let Some(ref mut c) = confidence {
+        c.add_metadata_entry("Created by whitebox_tools\' fill_missing_data tool".to_owned());
+        c.add_metadata_entry(format!("Input file: {}", input_file));
+        c.add_metadata_entry(format!("Filter size: {}", filter_size));
+        c.write()?;
+        if verbose { println!("Confidence file written") };
+    }
 
 
 
@@ -228,11 +288,11 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     // for row in 0..rows {
     //     for col in 0..columns {
     //         z = input.get_value(row, col);
-    //         if z != nodata {
+    //         if !input.is_nodata(z) {
     //             let mut min_val = f64::INFINITY;
     //             for i in 0..num_pixels_in_filter {
     //                 z_n = input.get_value(row + d_y[i], col + d_x[i]);
-    //                 if z_n < min_val && filter_shape[i] && z_n != nodata { min_val = z_n }
+    //                 if z_n < min_val && filter_shape[i] && !input.is_nodata(z_n) { min_val = z_n }
     //             }
     //             erosion[(row, col)] = min_val;
     //         } else {
@@ -254,13 +314,13 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     // for row in 0..rows {
     //     for col in 0..columns {
     //         z = input.get_value(row, col);
-    //         if z != nodata {
+    //         if !input.is_nodata(z) {
     //             let mut max_val = f64::NEG_INFINITY;
     //             for i in 0..num_pixels_in_filter {
     //                 col_n = col + d_x[i];
     //                 row_n = row + d_y[i];
     //                 z_n = erosion[(row_n, col_n)];
-    //                 if z_n > max_val && filter_shape[i] && z_n != nodata { max_val = z_n }
+    //                 if z_n > max_val && filter_shape[i] && !input.is_nodata(z_n) { max_val = z_n }
     //             }
     //             tophat[(row, col)] = z - max_val;
     //             opening[(row, col)] = max_val;
@@ -280,10 +340,11 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     ///////////////////////////////////////////////////////////////////////////////////////////////
     // NOTE:
     // This disused code perfomed peak cleaving using a modified depression filling algorithm on
-    // the tophat transform. The current method of region growing is more straight forward.
+    // the tophat transform. The current method of region growing is more straight forward. If
+    // revived, it should use the f64 priorities of structures::grid_cell::GridCell; scaling
+    // elevations into isize priorities by a multiplier can overflow on large DEMs.
     ///////////////////////////////////////////////////////////////////////////////////////////////
     // find grid cells with nodata neighbours
-    // let multiplier = 10000f64;
     // let mut heap = BinaryHeap::new();
     // let initial_value = f64::NEG_INFINITY;
     // let mut num_solved_cells = 0usize;
@@ -294,16 +355,16 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     //     for col in 0..columns as isize {
     //         output.set_value(row, col, initial_value);
     //         z = input.get_value(row, col);
-    //         if z != nodata {
+    //         if !input.is_nodata(z) {
     //             let mut flag = false;
     //             for i in 0..8 {
     //                 z_n = input.get_value(row + d_y[i], col + d_x[i]);
-    //                 if z_n == nodata {
+    //                 if input.is_nodata(z_n) {
     //                     flag = true;
     //                 }
     //             }
     //             if flag {
-    //                 heap.push(GridCell { priority: -(tophat[row as usize][col as usize] * multiplier).floor() as isize, row: row, column: col });
+    //                 heap.push(GridCell::new(row, col, -tophat[row as usize][col as usize]));
     //                 output.set_value(row, col, tophat[row as usize][col as usize]);
     //                 num_solved_cells += 1;
     //             }
@@ -327,13 +388,13 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     //     let gc = heap.pop().unwrap();
     //     row = gc.row;
     //     col = gc.column;
-    //     z = -(gc.priority as f64 / multiplier);
+    //     z = -gc.priority;
     //     for i in 0..8 {
     //         row_n = row + d_y[i];
     //         col_n = col + d_x[i];
     //         if col_n >= 0 && col_n < columns as isize && row_n >= 0 && row_n < rows as isize {
     //             z_n = tophat[row_n as usize][col_n as usize];
-    //             if z_n != nodata && output.get_value(row_n, col_n) == initial_value {
+    //             if !input.is_nodata(z_n) && output.get_value(row_n, col_n) == initial_value {
     //                 if z_n - z >= height_diff_threshold { //z_n >= z {
     //                     z_n = z;
     //                     modified[row_n as usize][col_n as usize] = true;
@@ -343,7 +404,7 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     //                 }
     //                 output.set_value(row_n, col_n, z_n);
     //                 num_solved_cells += 1;
-    //                 heap.push(GridCell { priority: -(z_n * multiplier).floor() as isize, row: row_n, column: col_n });
+    //                 heap.push(GridCell::new(row_n, col_n, -z_n));
     //             }
     //         }
     //     }
@@ -424,9 +485,75 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     Ok(())
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct GridCell {
-    // priority: isize,
-    row: isize,
-    column: isize,
+/// Returns, for each cell, the number of cells in the 8-connected region of nodata cells that
+/// it belongs to, or 0 for valid cells.
+fn measure_holes(input: &Raster) -> Vec<usize> {
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    // Clump a mask in which the holes are one class and the valid data the other.
+    let mut mask = Raster::initialize_using_file(&input.file_name, input);
+    let (hole, valid) = if mask.configs.nodata != 1f64 { (1f64, 2f64) } else { (2f64, 3f64) };
+    for row in 0..rows {
+        for col in 0..columns {
+            mask.set_value(row, col, if input.is_nodata(input[(row, col)]) { hole } else { valid });
+        }
+    }
+    let clumps = clump(&mask, true);
+
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for row in 0..rows {
+        for col in 0..columns {
+            if input.is_nodata(input[(row, col)]) {
+                *counts.entry(clumps[(row, col)] as i64).or_insert(0) += 1;
+            }
+        }
+    }
+    let mut sizes = vec![0usize; (rows * columns) as usize];
+    for row in 0..rows {
+        for col in 0..columns {
+            if input.is_nodata(input[(row, col)]) {
+                sizes[(row * columns + col) as usize] = counts[&(clumps[(row, col)] as i64)];
+            }
+        }
+    }
+    sizes
+}
+
+/// Interpolates a hole cell with cubic convolution across the gap along its row and its column.
+/// Each direction needs a valid cell on both sides within `max_dist` cells; the cell beyond each
+/// of those supplies the slope, and the two directional estimates are weighted by the inverse of
+/// their gap widths. Returns the estimate and the number of valid cells in the stencils used,
+/// or None when neither direction spans the gap, leaving the IDW value.
+fn cubic_gap_estimate(input: &Raster, row: isize, col: isize, max_dist: isize) -> Option<(f64, usize)> {
+    let mut sum_weights = 0f64;
+    let mut z = 0f64;
+    let mut num_stencil_cells = 0usize;
+    for &(d_y, d_x) in [(0isize, 1isize), (1isize, 0isize)].iter() {
+        let mut d1 = 1isize;
+        while d1 <= max_dist && input.is_nodata(input[(row - d1 * d_y, col - d1 * d_x)]) { d1 += 1; }
+        let mut d2 = 1isize;
+        while d2 <= max_dist && input.is_nodata(input[(row + d2 * d_y, col + d2 * d_x)]) { d2 += 1; }
+        if d1 > max_dist || d2 > max_dist {
+            continue;
+        }
+        let (r1, c1) = (row - d1 * d_y, col - d1 * d_x);
+        let (r2, c2) = (row + d2 * d_y, col + d2 * d_x);
+        let z1 = input.get_value(r1, c1);
+        let z2 = input.get_value(r2, c2);
+        let z0 = input.get_value(r1 - d_y, c1 - d_x);
+        let z3 = input.get_value(r2 + d_y, c2 + d_x);
+        // The kernel assumes equally spaced samples, so the outer values are extrapolated from
+        // their one-cell slopes out to the width of the gap.
+        let gap = (d1 + d2) as f64;
+        num_stencil_cells += 2 + !input.is_nodata(z0) as usize + !input.is_nodata(z3) as usize;
+        let z0 = if !input.is_nodata(z0) { z1 - (z1 - z0) * gap } else { 2f64 * z1 - z2 };
+        let z3 = if !input.is_nodata(z3) { z2 + (z3 - z2) * gap } else { 2f64 * z2 - z1 };
+        z += cubic_convolution(z0, z1, z2, z3, d1 as f64 / gap) / gap;
+        sum_weights += 1f64 / gap;
+    }
+    if sum_weights > 0f64 {
+        Some((z / sum_weights, num_stencil_cells))
+    } else {
+        None
+    }
 }
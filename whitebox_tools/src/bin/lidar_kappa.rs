@@ -2,73 +2,42 @@
 
 extern crate whitebox_tools;
 
-use std::env;
 use std::io::prelude::*;
 use std::fs::File;
 use std::path;
 use std::process::Command;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file1: String = "".to_string();
-    let mut input_file2: String = "".to_string();
-    let mut output_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut keyval = false;
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i1" || vec[0].to_lowercase() == "--input1" {
-            if keyval {
-                input_file1 = vec[1].to_string();
-            } else {
-                input_file1 = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-i2" || vec[0].to_lowercase() == "--input2" {
-            if keyval {
-                input_file2 = vec[1].to_string();
-            } else {
-                input_file2 = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-            vec[0].to_lowercase() == "--h"{
-            let mut s: String = "Help:\n".to_owned();
-                     s.push_str("-i1      Input LAS file (classification).\n");
-                     s.push_str("-i2      Input LAS file (reference).\n");
-                     s.push_str("-o       Output HTML file.\n");
-                     s.push_str("-wd      Optional working directory. If specified, filenames parameters need not include a full path.\n");
-                     s.push_str("-version Prints the tool version number.\n");
-                     s.push_str("-h       Prints help information.\n\n");
-                     s.push_str("Example usage:\n\n");
-                     s.push_str(&">> .*lidar_kappa -wd *path*to*data* -i1 class.las -i2 ref.las -o kappa.html\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i1        Input LAS file (classification).\n");
+        s.push_str("-i2        Input LAS file (reference).\n");
+        s.push_str("-o         Output HTML file.\n");
+        s.push_str("-wd        Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite Replace the output file if it already exists.\n");
+        s.push_str("-version   Prints the tool version number.\n");
+        s.push_str("-h         Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i1 class.las -i2 ref.las -o kappa.html\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let mut input_file1 = args.value(&["i1", "input1"]).unwrap_or(String::new());
+    let mut input_file2 = args.value(&["i2", "input2"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
 
     println!("**************************");
     println!("* Welcome to lidar_kappa *");
@@ -94,6 +63,9 @@ fn main() {
     if !output_file.ends_with(".html") {
         output_file = output_file + ".html";
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
 
     // let input1 = las::LasFile::new(&input_file1, "r");
     // let input2 = las::LasFile::new(&input_file2, "r");
@@ -2,8 +2,9 @@ extern crate whitebox_tools;
 extern crate nalgebra as na;
 extern crate kdtree;
 
-use std::env;
 use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 use na::Vector3;
@@ -12,68 +13,33 @@ use kdtree::distance::squared_euclidean;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file: String = "".to_string();
-    let mut output_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut min_points: usize = 10;
-    let mut verbose: bool = false;
-
-    // read the arguments
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--o" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-num_points" || vec[0].to_lowercase() == "--num_points" {
-            if keyval {
-                min_points = vec[1].to_string().parse::<usize>().unwrap();
-            } else {
-                min_points = args[i+1].to_string().parse::<usize>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-          vec[0].to_lowercase() == "--h" {
-            let mut s: String = "Help:\n".to_owned();
-             s.push_str("-i           Input LAS file.\n");
-             s.push_str("-o           Output LAS file.\n");
-             s.push_str("-wd          Optional working directory. If specified, input and output filenames need not include a full path.\n");
-             s.push_str("-num_points  Number (integer) of nearest-neighbour points used for plane fitting; default is 10.\n");
-             s.push_str("-v           Optional verbose mode. Tool will report progress if this flag is provided.\n");
-             s.push_str("-version     Prints the tool version number.\n");
-             s.push_str("-h           Prints help information.\n\n");
-             s.push_str("Example usage:\n\n");
-             s.push_str(&">> .*lidar_normal_vec -v -wd *path*to*data* -i input.las -o output.las -num_points 15\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i           Input LAS file.\n");
+        s.push_str("-o           Output LAS file.\n");
+        s.push_str("-wd          Optional working directory. If specified, input and output filenames need not include a full path.\n");
+        s.push_str("-num_points  Number (integer) of nearest-neighbour points used for plane fitting; default is 10.\n");
+        s.push_str("-v           Optional verbose mode. Tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite   Replace the output file if it already exists.\n");
+        s.push_str("-version     Prints the tool version number.\n");
+        s.push_str("-h           Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -v -wd *path*to*data* -i input.las -o output.las -num_points 15\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let min_points = args.parse_or(&["num_points"], 10usize);
+    let verbose = args.verbose();
 
     if !working_directory.ends_with(&sep) {
         working_directory.push_str(&(sep.to_string()));
@@ -85,6 +51,9 @@ fn main() {
     if !output_file.contains(&sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
 
     lidar_normal_vec(input_file, output_file, min_points, verbose);
 }
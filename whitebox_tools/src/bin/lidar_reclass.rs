@@ -3,12 +3,13 @@ extern crate whitebox_tools;
 use std::io;
 use std::io::Error;
 use std::io::ErrorKind;
-use std::env;
 use std::path;
 use std::fs::File;
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::collections::HashMap;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::RgbData;
 
@@ -16,80 +17,37 @@ use whitebox_tools::lidar::point_data::RgbData;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file = String::new();
-    let mut reclass_file = String::new();
-    let mut output_file = String::new();
-    let mut working_directory = String::new();
-    let mut verbose: bool = false;
-    let mut byte_bit_mode: bool = true;
-    let mut unclassed_value = 1u8;
-
-    // read the arguments
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-reclass_file" || vec[0].to_lowercase() == "--reclass_file" {
-            if keyval {
-                reclass_file = vec[1].to_string();
-            } else {
-                reclass_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--o" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-unclassed_value" || vec[0].to_lowercase() == "--unclassed_value" {
-            if keyval {
-                unclassed_value = vec[1].to_string().parse::<u8>().unwrap();
-            } else {
-                unclassed_value = args[i+1].to_string().parse::<u8>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-16bitmode" || vec[0].to_lowercase() == "--16bitmode" {
-            byte_bit_mode = false;
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-          vec[0].to_lowercase() == "--h" {
-            let mut s: String = "Help:\n".to_owned();
-            s.push_str("-i               Input LAS file.\n");
-            s.push_str("-reclass_file    Input reclassification file. This is a CSV file of the format 'red,green,blue,class'.\n");
-            s.push_str("-o               Output LAS file.\n");
-            s.push_str("-wd              Optional working directory. If specified, input and output filenames need not include a full path.\n");
-            s.push_str("-unclassed_value Value (0-255) assigned to points that are not specified in reclass_file. Default is 1 (unclassified)\n");
-            s.push_str("-16bitmode       Use this optional flag only when 16-bit RGB values are used in the reclass_file. If unspecified, 8-bit colour is assumed.");
-            s.push_str("-v               Optional verbose mode. Tool will report progress if this flag is provided.\n");
-            s.push_str("-version         Prints the tool version number.\n");
-            s.push_str("-h               Prints help information.\n\n");
-            s.push_str("Example usage:\n\n");
-            s.push_str(&">> .*lidar_reclass -wd *path*to*data* -i input.las -reclass_file reclass.txt -o output.las -unclassed_value 1 -v\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i               Input LAS file.\n");
+        s.push_str("-reclass_file    Input reclassification file. This is a CSV file of the format 'red,green,blue,class'.\n");
+        s.push_str("-o               Output LAS file.\n");
+        s.push_str("-wd              Optional working directory. If specified, input and output filenames need not include a full path.\n");
+        s.push_str("-unclassed_value Value (0-255) assigned to points that are not specified in reclass_file. Default is 1 (unclassified)\n");
+        s.push_str("-16bitmode       Use this optional flag only when 16-bit RGB values are used in the reclass_file. If unspecified, 8-bit colour is assumed.");
+        s.push_str("-v               Optional verbose mode. Tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite       Replace the output file if it already exists.\n");
+        s.push_str("-version         Prints the tool version number.\n");
+        s.push_str("-h               Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.las -reclass_file reclass.txt -o output.las -unclassed_value 1 -v\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut reclass_file = args.value(&["reclass_file"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let unclassed_value = args.parse_or(&["unclassed_value"], 1u8);
+    let byte_bit_mode = !args.flag(&["16bitmode"]);
+    let verbose = args.verbose();
 
     let sep = std::path::MAIN_SEPARATOR;
     if !working_directory.ends_with(sep) {
@@ -102,6 +60,9 @@ fn main() {
     if !output_file.contains(sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
     if !reclass_file.contains(sep) {
         reclass_file = format!("{}{}", working_directory, reclass_file);
     }
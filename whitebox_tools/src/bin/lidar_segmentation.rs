@@ -1,122 +1,62 @@
 extern crate whitebox_tools;
 extern crate nalgebra as na;
 extern crate kdtree;
-extern crate rand;
 
-use std::env;
 use std::f64;
 use std::cmp;
 use std::path;
 use std::default::Default;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 use whitebox_tools::structures::fixed_radius_search::FixedRadiusSearch;
+use whitebox_tools::structures::seeded_rng::SeededRng;
 use na::{ Dot, Vector3 };
 use kdtree::KdTree;
 use kdtree::distance::squared_euclidean;
-use rand::Rng;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file: String = "".to_string();
-    let mut output_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut search_dist: f64 = 5.0;
-    let mut num_neighbouring_points: usize = 10;
-    let mut max_normal_angle = 2.0f64;
-    let mut max_z_diff = 1.0;
-    let mut verbose: bool = false;
-    let mut variable_dist = true;
-    let mut detrend_surface = 0.0f64;
-    let mut classify_ground = false;
-
-    // read the arguments
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--o" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-dist" || vec[0].to_lowercase() == "--dist" {
-            variable_dist = false;
-            if keyval {
-                search_dist = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                search_dist = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-num_points" || vec[0].to_lowercase() == "--num_points" {
-            if keyval {
-                num_neighbouring_points = vec[1].to_string().parse::<usize>().unwrap();
-            } else {
-                num_neighbouring_points = args[i+1].to_string().parse::<usize>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-max_norm_angle" || vec[0].to_lowercase() == "--max_norm_angle" {
-            if keyval {
-                max_normal_angle = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                max_normal_angle = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-maxzdiff" || vec[0].to_lowercase() == "--maxzdiff" {
-            if keyval {
-                max_z_diff = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                max_z_diff = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-detrend" || vec[0].to_lowercase() == "--detrend" {
-            if keyval {
-                detrend_surface = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                detrend_surface = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-classify_ground" || vec[0].to_lowercase() == "--classify_ground" {
-            classify_ground = true;
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-          vec[0].to_lowercase() == "--h" {
-            let mut s: String = "Help:\n".to_owned();
-            s.push_str("-i               Input LAS file.\n");
-            s.push_str("-o               Output LAS file.\n");
-            s.push_str("-wd              Optional working directory. If specified, input and output filenames need not include a full path.\n");
-            s.push_str("-dist            Optional search distance in xy units; default is variable, determined by num_points.\n");
-            s.push_str("-num_points      Number (integer) of nearest-neighbour points used for plane fitting; default is 10.\n");
-            s.push_str("-max_norm_angle  Maximum deviation (degrees) in normal vectors between neighbouring points of the same segment; default is 2.0.\n");
-            s.push_str("-maxzdiff        Maximum difference in elevation (z units) between neighbouring points of the same segment; defuault is 1.0.\n");
-            s.push_str("-classify_ground Optional mode. Surface in contact with the opening surface will be classified as ground points.");
-            s.push_str("-v               Optional verbose mode. Tool will report progress if this flag is provided.\n");
-            s.push_str("-version         Prints the tool version number.\n");
-            s.push_str("-h               Prints help information.\n\n");
-            s.push_str("Example usage:\n\n");
-            s.push_str(&">> .*lidar_segmentation -wd *path*to*data* -i input.las -o output.las -num_points 15 -max_norm_angle 3.5 -max_z_diff 0.5 -v\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i               Input LAS file.\n");
+        s.push_str("-o               Output LAS file.\n");
+        s.push_str("-wd              Optional working directory. If specified, input and output filenames need not include a full path.\n");
+        s.push_str("-dist            Optional search distance in xy units; default is variable, determined by num_points.\n");
+        s.push_str("-num_points      Number (integer) of nearest-neighbour points used for plane fitting; default is 10.\n");
+        s.push_str("-max_norm_angle  Maximum deviation (degrees) in normal vectors between neighbouring points of the same segment; default is 2.0.\n");
+        s.push_str("-maxzdiff        Maximum difference in elevation (z units) between neighbouring points of the same segment; defuault is 1.0.\n");
+        s.push_str("-classify_ground Optional mode. Surface in contact with the opening surface will be classified as ground points.");
+        s.push_str("-seed            Optional seed for the random segment colours, making them reproducible.\n");
+        s.push_str("-v               Optional verbose mode. Tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite       Replace the output file if it already exists.\n");
+        s.push_str("-version         Prints the tool version number.\n");
+        s.push_str("-h               Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.las -o output.las -num_points 15 -max_norm_angle 3.5 -max_z_diff 0.5 -v\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let search_dist = args.parse_or(&["dist"], 5.0f64);
+    let variable_dist = !args.flag(&["dist"]);
+    let num_neighbouring_points = args.parse_or(&["num_points"], 10usize);
+    let max_normal_angle = args.parse_or(&["max_norm_angle"], 2.0f64);
+    let max_z_diff = args.parse_or(&["maxzdiff"], 1.0f64);
+    let detrend_surface = args.parse_or(&["detrend"], 0.0f64);
+    let classify_ground = args.flag(&["classify_ground"]);
+    let seed = args.parse::<u64>(&["seed"]);
+    let verbose = args.verbose();
 
     let sep = std::path::MAIN_SEPARATOR;
     if !working_directory.ends_with(sep) {
@@ -129,14 +69,17 @@ fn main() {
     if !output_file.contains(sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
 
     lidar_segmentation(input_file, output_file, search_dist, num_neighbouring_points,
-        max_normal_angle, max_z_diff, verbose, variable_dist, detrend_surface, classify_ground);
+        max_normal_angle, max_z_diff, verbose, variable_dist, detrend_surface, classify_ground, seed);
 }
 
 fn lidar_segmentation(input_file: String, output_file: String, search_dist: f64, num_neighbouring_points: usize,
                       mut max_angle: f64, mut max_z_diff: f64, verbose: bool, variable_dist: bool,
-                      detrend_surface: f64, classify_ground: bool) {
+                      detrend_surface: f64, classify_ground: bool, seed: Option<u64>) {
     if verbose {
         println!("*********************************");
         println!("* Welcome to lidar_segmentation *");
@@ -513,15 +456,15 @@ fn lidar_segmentation(input_file: String, output_file: String, search_dist: f64,
     output.header.point_format = 2;
 
     let mut clrs: Vec<(u16, u16, u16)> = Vec::new();
-    let mut rng = rand::thread_rng();
+    let mut rng = SeededRng::from_optional_seed(seed);
     let (mut r, mut g, mut b): (u16, u16, u16) = (0u16, 0u16, 0u16);
     current_seg_id = segment_histo.len();
     for _ in 0..current_seg_id+1 as usize {
         let mut flag = false;
         while !flag {
-            r = rng.gen::<u8>() as u16 * 256u16;
-            g = rng.gen::<u8>() as u16 * 256u16;
-            b = rng.gen::<u8>() as u16 * 256u16;
+            r = rng.next_u8() as u16 * 256u16;
+            g = rng.next_u8() as u16 * 256u16;
+            b = rng.next_u8() as u16 * 256u16;
             let max_val = cmp::max(cmp::max(r, g), b);
             //let min_val = cmp::min(cmp::min(r, g), b);
             if max_val >= u16::max_value() / 2 { // && min_val >= u16::max_value() / 4 {
@@ -3,10 +3,11 @@ extern crate nalgebra as na;
 extern crate kdtree;
 extern crate rand;
 
-use std::env;
 use std::f64;
 use std::path;
 use std::default::Default;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 use na::{ Dot, Vector3 };
@@ -15,137 +16,57 @@ use kdtree::distance::squared_euclidean;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file: String = "".to_string();
-    let mut output_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut search_dist: f64 = 5.0;
-    let mut num_neighbouring_points: usize = 10;
-    let mut max_normal_angle = 2.0f64;
-    let mut maxzdiff = 1.0;
-    let mut minz = f64::NEG_INFINITY;
-    let mut filter = true;
-    let mut ground_class_value = 2u8;
-    let mut oto_class_value = 1u8;
-    let mut last_only = false;
-    let mut verbose: bool = false;
-    let mut variable_dist = true;
-
-    // read the arguments
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--o" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-dist" || vec[0].to_lowercase() == "--dist" {
-            variable_dist = false;
-            if keyval {
-                search_dist = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                search_dist = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-num_points" || vec[0].to_lowercase() == "--num_points" {
-            if keyval {
-                num_neighbouring_points = vec[1].to_string().parse::<usize>().unwrap();
-            } else {
-                num_neighbouring_points = args[i+1].to_string().parse::<usize>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-max_norm_angle" || vec[0].to_lowercase() == "--max_norm_angle" {
-            if keyval {
-                max_normal_angle = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                max_normal_angle = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-max_z_diff" || vec[0].to_lowercase() == "--max_z_diff" {
-            if keyval {
-                maxzdiff = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                maxzdiff = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-minz" || vec[0].to_lowercase() == "--minz" {
-            if keyval {
-                minz = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                minz = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-            filter = true;
-        } else if vec[0].to_lowercase() == "-class" || vec[0].to_lowercase() == "--class" {
-            filter = false;
-        } else if vec[0].to_lowercase() == "-groundclass" || vec[0].to_lowercase() == "--groundclass" {
-            filter = false;
-            if keyval {
-                ground_class_value = vec[1].to_string().parse::<u8>().unwrap();
-            } else {
-                ground_class_value = args[i+1].to_string().parse::<u8>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-otoclass" || vec[0].to_lowercase() == "--otoclass" {
-            filter = false;
-            if keyval {
-                oto_class_value = vec[1].to_string().parse::<u8>().unwrap();
-            } else {
-                oto_class_value = args[i+1].to_string().parse::<u8>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-last_only" || vec[0].to_lowercase() == "--last_only" {
-            last_only = true;
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-          vec[0].to_lowercase() == "--h" {
-            let mut s: String = "Help:\n".to_owned();
-            s.push_str("This tool can be used to filter a LiDAR point cloud for ground points. This filtering
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("This tool can be used to filter a LiDAR point cloud for ground points. This filtering
 is based on a segmentation procedure and entire segments are classed as either
 'ground' or 'off-terrain' segments. Off-terrain segments are considered to be those
 with mean normal vectors that are not upwards-facing and that are elevated above
 neighbouring segments. The tool can either remove off-terrain points within the
 output file, or if the optional -class flag is provided, it can simply classify
 points in the output file as 'ground' and 'unclassified'.\n");
-            s.push_str("\nTool flags:\n");
-            s.push_str("-i               Input LAS file.\n");
-            s.push_str("-o               Output LAS file.\n");
-            s.push_str("-wd              Optional working directory. If specified, input and output filenames need not include a full path.\n");
-            s.push_str("-dist            Optional search distance in xy units; default is variable, determined by num_points.\n");
-            s.push_str("-num_points      Number (integer) of nearest-neighbour points used for plane fitting; default is 10.\n");
-            s.push_str("-max_norm_angle  Maximum deviation (degrees) in normal vectors between neighbouring points of the same segment; default is 2.0.\n");
-            s.push_str("-max_z_diff      Maximum difference in elevation (z units) between neighbouring points of the same segment; defuault is 1.0.\n");
-            s.push_str("-last_only       Optional boolean indicating whether only last-return points should be considered.\n");
-            s.push_str("-class           If this flag is used, the output LAS file will contain all the points of the input, but classified to indicate whether a point belongs to the slice.\n");
-            s.push_str("-groundclass     Class value (integer between 0-31) to be assigned to ground points; default is 2.\n");
-            s.push_str("-otoclass        Class value (integer between 0-31) to be assigned to off-terrain objects (OTOs); default is 1.\n");
-            s.push_str("-v               Optional verbose mode. Tool will report progress if this flag is provided.\n");
-            s.push_str("-version         Prints the tool version number.\n");
-            s.push_str("-h               Prints help information.\n\n");
-            s.push_str("Example usage:\n\n");
-            s.push_str(&">> .*lidar_segmentation_based_filter -wd *path*to*data* -i input.las -o output.las -num_points 50 -max_norm_angle 3.5 -max_z_diff 0.5 -v\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+        s.push_str("\nTool flags:\n");
+        s.push_str("-i               Input LAS file.\n");
+        s.push_str("-o               Output LAS file.\n");
+        s.push_str("-wd              Optional working directory. If specified, input and output filenames need not include a full path.\n");
+        s.push_str("-dist            Optional search distance in xy units; default is variable, determined by num_points.\n");
+        s.push_str("-num_points      Number (integer) of nearest-neighbour points used for plane fitting; default is 10.\n");
+        s.push_str("-max_norm_angle  Maximum deviation (degrees) in normal vectors between neighbouring points of the same segment; default is 2.0.\n");
+        s.push_str("-max_z_diff      Maximum difference in elevation (z units) between neighbouring points of the same segment; defuault is 1.0.\n");
+        s.push_str("-last_only       Optional boolean indicating whether only last-return points should be considered.\n");
+        s.push_str("-class           If this flag is used, the output LAS file will contain all the points of the input, but classified to indicate whether a point belongs to the slice.\n");
+        s.push_str("-groundclass     Class value (integer between 0-31) to be assigned to ground points; default is 2.\n");
+        s.push_str("-otoclass        Class value (integer between 0-31) to be assigned to off-terrain objects (OTOs); default is 1.\n");
+        s.push_str("-v               Optional verbose mode. Tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite       Replace the output file if it already exists.\n");
+        s.push_str("-version         Prints the tool version number.\n");
+        s.push_str("-h               Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.las -o output.las -num_points 50 -max_norm_angle 3.5 -max_z_diff 0.5 -v\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let search_dist = args.parse_or(&["dist"], 5.0f64);
+    let variable_dist = !args.flag(&["dist"]);
+    let num_neighbouring_points = args.parse_or(&["num_points"], 10usize);
+    let max_normal_angle = args.parse_or(&["max_norm_angle"], 2.0f64);
+    let maxzdiff = args.parse_or(&["max_z_diff"], 1.0f64);
+    let minz = args.parse_or(&["minz"], f64::NEG_INFINITY);
+    let filter = !args.flag(&["class", "groundclass", "otoclass"]);
+    let ground_class_value = args.parse_or(&["groundclass"], 2u8);
+    let oto_class_value = args.parse_or(&["otoclass"], 1u8);
+    let last_only = args.flag(&["last_only"]);
+    let verbose = args.verbose();
 
     let sep = std::path::MAIN_SEPARATOR;
     if !working_directory.ends_with(sep) {
@@ -158,6 +79,9 @@ points in the output file as 'ground' and 'unclassified'.\n");
     if !output_file.contains(sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
 
     lidar_segmentation(input_file, output_file, search_dist, num_neighbouring_points,
         max_normal_angle, maxzdiff, verbose, variable_dist, minz, filter, ground_class_value,
@@ -1,8 +1,9 @@
 extern crate kdtree;
 extern crate whitebox_tools;
 
-use std::env;
 use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 use kdtree::KdTree;
@@ -11,133 +12,52 @@ use std::f64;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file: String = "".to_string();
-    let mut output_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut search_dist = 2.0f64;
-    let mut slope_threshold = 60f64;
-    let mut min_elev_diff = 0.15;
-    let mut minz = f64::NEG_INFINITY;
-    let mut verbose = false;
-    let mut filter = true;
-    let mut ground_class_value = 2u8;
-    let mut oto_class_value = 1u8;
-    let mut last_only = false;
-    let mut variable_dist = true;
-    let mut num_neighbouring_points = 25;
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--o" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-dist" || vec[0].to_lowercase() == "--dist" {
-            variable_dist = false;
-            if keyval {
-                search_dist = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                search_dist = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-num_points" || vec[0].to_lowercase() == "--num_points" {
-            variable_dist = true;
-            if keyval {
-                num_neighbouring_points = vec[1].to_string().parse::<usize>().unwrap();
-            } else {
-                num_neighbouring_points = args[i+1].to_string().parse::<usize>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-slope" || vec[0].to_lowercase() == "--slope" {
-            if keyval {
-                slope_threshold = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                slope_threshold = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-minz" || vec[0].to_lowercase() == "--minz" {
-            if keyval {
-                minz = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                minz = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-minzdiff" || vec[0].to_lowercase() == "--minzdiff" {
-            if keyval {
-                min_elev_diff = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                min_elev_diff = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
-            filter = true;
-        } else if vec[0].to_lowercase() == "-class" || vec[0].to_lowercase() == "--class" {
-            filter = false;
-        } else if vec[0].to_lowercase() == "-groundclass" || vec[0].to_lowercase() == "--groundclass" {
-            filter = false;
-            if keyval {
-                ground_class_value = vec[1].to_string().parse::<u8>().unwrap();
-            } else {
-                ground_class_value = args[i+1].to_string().parse::<u8>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-otoclass" || vec[0].to_lowercase() == "--otoclass" {
-            filter = false;
-            if keyval {
-                oto_class_value = vec[1].to_string().parse::<u8>().unwrap();
-            } else {
-                oto_class_value = args[i+1].to_string().parse::<u8>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-last_only" || vec[0].to_lowercase() == "--last_only" {
-            last_only = true;
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-            vec[0].to_lowercase() == "--h"{
-            let mut s: String = "Help:\n".to_owned();
-             s.push_str("-i           Input LAS file.\n");
-             s.push_str("-o           Output LAS file.\n");
-             s.push_str("-wd          Optional working directory. If specified, filenames parameters need not include a full path.\n");
-             s.push_str("-dist        Optional search distance in xy units; default is 2.0.\n");
-             s.push_str("-num_points  Optional number (integer) of nearest-neighbour points in place of constant search distance.\n");
-             s.push_str("-slope       Slope threshold, in degrees; default is 60.0.\n");
-             s.push_str("-minz        Minimum elevation used in the analysis (optional).\n");
-             s.push_str("-minzdiff    Minimum elevaton difference between points to be considered an off-terrain point; default 0.15.\n");
-             s.push_str("-last_only   Optional boolean indicating whether only last-return points should be considered.");
-             s.push_str("-class       If this flag is used, the output LAS file will contain all the points of the input, but classified to indicate whether a point belongs to the slice.\n");
-             s.push_str("-groundclass Class value (integer between 0-31) to be assigned to ground points; default is 2.\n");
-             s.push_str("-otoclass    Class value (integer between 0-31) to be assigned to off-terrain objects (OTOs); default is 1.\n");
-             s.push_str("-v           Verbose mode; if this flag is present, the tool will report progress if this flag is provided.\n");
-             s.push_str("-version     Prints the tool version number.\n");
-             s.push_str("-h           Prints help information.\n\n");
-             s.push_str("Example usage:\n\n");
-             s.push_str(&">> .*lidar_slope_based_filter -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -v\n".replace("*", &sep));
-             s.push_str(&">> .*lidar_slope_based_filter -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -minz 0.0 -class -v\n".replace("*", &sep));
-             s.push_str(&">> .*lidar_slope_based_filter -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -class -groundclass 1 -otoclass 0 -v\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i           Input LAS file.\n");
+        s.push_str("-o           Output LAS file.\n");
+        s.push_str("-wd          Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-dist        Optional search distance in xy units; default is 2.0.\n");
+        s.push_str("-num_points  Optional number (integer) of nearest-neighbour points in place of constant search distance.\n");
+        s.push_str("-slope       Slope threshold, in degrees; default is 60.0.\n");
+        s.push_str("-minz        Minimum elevation used in the analysis (optional).\n");
+        s.push_str("-minzdiff    Minimum elevaton difference between points to be considered an off-terrain point; default 0.15.\n");
+        s.push_str("-last_only   Optional boolean indicating whether only last-return points should be considered.");
+        s.push_str("-class       If this flag is used, the output LAS file will contain all the points of the input, but classified to indicate whether a point belongs to the slice.\n");
+        s.push_str("-groundclass Class value (integer between 0-31) to be assigned to ground points; default is 2.\n");
+        s.push_str("-otoclass    Class value (integer between 0-31) to be assigned to off-terrain objects (OTOs); default is 1.\n");
+        s.push_str("-v           Verbose mode; if this flag is present, the tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite   Replace the output file if it already exists.\n");
+        s.push_str("-version     Prints the tool version number.\n");
+        s.push_str("-h           Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -v\n", args.tool_name()).replace("*", &sep));
+        s.push_str(&format!(">> .*{} -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -minz 0.0 -class -v\n", args.tool_name()).replace("*", &sep));
+        s.push_str(&format!(">> .*{} -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -class -groundclass 1 -otoclass 0 -v\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
     }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let mut search_dist = args.parse_or(&["dist"], 2.0f64);
+    let num_neighbouring_points = args.parse_or(&["num_points"], 25usize);
+    let variable_dist = args.flag(&["num_points"]) || !args.flag(&["dist"]);
+    let mut slope_threshold = args.parse_or(&["slope"], 60f64);
+    let minz = args.parse_or(&["minz"], f64::NEG_INFINITY);
+    let min_elev_diff = args.parse_or(&["minzdiff"], 0.15f64);
+    let filter = !args.flag(&["class", "groundclass", "otoclass"]);
+    let ground_class_value = args.parse_or(&["groundclass"], 2u8);
+    let oto_class_value = args.parse_or(&["otoclass"], 1u8);
+    let last_only = args.flag(&["last_only"]);
+    let verbose = args.verbose();
 
     println!("***************************************");
     println!("* Welcome to lidar_slope_based_filter *");
@@ -161,6 +81,9 @@ fn main() {
     if !output_file.contains(sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    if let Err(err) = check_output_file(&output_file, overwrite) {
+        panic!("Error: {}", err);
+    }
 
     search_dist = search_dist * search_dist;
 
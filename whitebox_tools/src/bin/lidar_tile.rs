@@ -1,102 +1,50 @@
 extern crate whitebox_tools;
 extern crate nalgebra as na;
 
-use std::env;
 use std::f64;
 use std::fs::DirBuilder;
 use std::path;
 use std::path::Path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut width_x = 1000.0;
-    let mut width_y = 1000.0;
-    let mut origin_x = 0.0;
-    let mut origin_y = 0.0;
-    let mut min_points = 0;
-    let mut verbose: bool = false;
-
-    // read the arguments
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-width_x" || vec[0].to_lowercase() == "--width_x" {
-            if keyval {
-                width_x = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                width_x = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-width_y" || vec[0].to_lowercase() == "--width_y" {
-            if keyval {
-                width_y = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                width_y = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-origin_x" || vec[0].to_lowercase() == "--origin_x" {
-            if keyval {
-                origin_x = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                origin_x = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-origin_y" || vec[0].to_lowercase() == "--origin_y" {
-            if keyval {
-                origin_y = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                origin_y = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-min_points" || vec[0].to_lowercase() == "--min_points" {
-            if keyval {
-                min_points = vec[1].to_string().parse::<usize>().unwrap();
-            } else {
-                min_points = args[i+1].to_string().parse::<usize>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-          vec[0].to_lowercase() == "--h" {
-            let mut s: String = "Help:\n".to_owned();
-             s.push_str("-i           Input LAS file.\n");
-             s.push_str("-wd          Optional working directory. If specified, input filename need not include a full path.\n");
-             s.push_str("-width_x     Width of tiles in the x dimension; default 1000.0.\n");
-             s.push_str("-width_y     Width of tiles in the y dimension; default 1000.0.\n");
-             s.push_str("-origin_x    Origin point for tile grid, x dimension; default 0.0.\n");
-             s.push_str("-origin_y    Origin point for tile grid, y dimension; default 0.0.\n");
-             s.push_str("-min_points  Minimum number of points contained in a tile for it to be output; default 0.\n");
-             s.push_str("-v           Optional verbose mode. Tool will report progress if this flag is provided.\n");
-             s.push_str("-version     Prints the tool version number.\n");
-             s.push_str("-h           Prints help information.\n\n");
-             s.push_str("Example usage:\n\n");
-             s.push_str(&">> .*lidar_tile -v -i *path*to*data*input.las -width_x 100.0 -width_y 250.0 -min_points 100\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_segmentation v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i           Input LAS file.\n");
+        s.push_str("-wd          Optional working directory. If specified, input filename need not include a full path.\n");
+        s.push_str("-width_x     Width of tiles in the x dimension; default 1000.0.\n");
+        s.push_str("-width_y     Width of tiles in the y dimension; default 1000.0.\n");
+        s.push_str("-origin_x    Origin point for tile grid, x dimension; default 0.0.\n");
+        s.push_str("-origin_y    Origin point for tile grid, y dimension; default 0.0.\n");
+        s.push_str("-min_points  Minimum number of points contained in a tile for it to be output; default 0.\n");
+        s.push_str("-v           Optional verbose mode. Tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite   Replace tile files that already exist.\n");
+        s.push_str("-version     Prints the tool version number.\n");
+        s.push_str("-h           Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -v -i *path*to*data*input.las -width_x 100.0 -width_y 250.0 -min_points 100\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
     }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let mut input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let mut working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let width_x = args.parse_or(&["width_x"], 1000.0f64);
+    let width_y = args.parse_or(&["width_y"], 1000.0f64);
+    let origin_x = args.parse_or(&["origin_x"], 0.0f64);
+    let origin_y = args.parse_or(&["origin_y"], 0.0f64);
+    let min_points = args.parse_or(&["min_points"], 0usize);
+    let verbose = args.verbose();
 
     let sep = std::path::MAIN_SEPARATOR;
     if !working_directory.ends_with(sep) {
@@ -107,10 +55,10 @@ fn main() {
         input_file = format!("{}{}", working_directory, input_file);
     }
 
-    lidar_tile(input_file, width_x, width_y, origin_x, origin_y, min_points, verbose);
+    lidar_tile(input_file, width_x, width_y, origin_x, origin_y, min_points, overwrite, verbose);
 }
 
-fn lidar_tile(input_file: String, width_x: f64, width_y: f64, origin_x: f64, origin_y: f64, min_points: usize, verbose: bool) {
+fn lidar_tile(input_file: String, width_x: f64, width_y: f64, origin_x: f64, origin_y: f64, min_points: usize, overwrite: bool, verbose: bool) {
     if verbose {
         println!("*************************");
         println!("* Welcome to lidar_tile *");
@@ -218,6 +166,17 @@ fn lidar_tile(input_file: String, width_x: f64, width_y: f64, origin_x: f64, ori
     };
     let output_dir: String = format!("{}{}{}{}", dir.to_string(), sep, name, sep);
     DirBuilder::new().recursive(true).create(output_dir.clone()).unwrap();
+    // check every tile before writing any, so that a refusal doesn't leave a partial set
+    for tile_num in 0..num_tiles {
+        if output_tile[tile_num] {
+            row = (tile_num as f64 / cols as f64).floor() as usize;
+            col = tile_num % cols;
+            let output_file = format!("{}{}_row{}_col{}.las", output_dir, name, row - min_row + 1, col - min_col + 1);
+            if let Err(err) = check_output_file(&output_file, overwrite) {
+                panic!("Error: {}", err);
+            }
+        }
+    }
     let mut num_tiles_created = 0;
     for tile_num in 0..num_tiles {
         if output_tile[tile_num] {
@@ -6,87 +6,49 @@
 extern crate kdtree;
 extern crate whitebox_tools;
 
-use std::env;
 use std::f64;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
 use whitebox_tools::lidar::las;
 use whitebox_tools::lidar::point_data::*;
 use whitebox_tools::structures::fixed_radius_search::FixedRadiusSearch;
 
 fn main() {
     let sep: String = path::MAIN_SEPARATOR.to_string();
-    let mut input_file: String = "".to_string();
-    let mut output_file: String = "".to_string();
-    let mut working_directory: String = "".to_string();
-    let mut search_dist = 2.0f64;
-    let mut minz = f64::NEG_INFINITY;
-    let mut verbose = false;
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 1 { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
-    for i in 0..args.len() {
-        let mut arg = args[i].replace("\"", "");
-        arg = arg.replace("\'", "");
-        let cmd = arg.split("="); // in case an equals sign was used
-        let vec = cmd.collect::<Vec<&str>>();
-        let mut keyval = false;
-        if vec.len() > 1 { keyval = true; }
-        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--i" {
-            if keyval {
-                input_file = vec[1].to_string();
-            } else {
-                input_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--o" {
-            if keyval {
-                output_file = vec[1].to_string();
-            } else {
-                output_file = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-wd" || vec[0].to_lowercase() == "--wd" {
-            if keyval {
-                working_directory = vec[1].to_string();
-            } else {
-                working_directory = args[i+1].to_string();
-            }
-        } else if vec[0].to_lowercase() == "-dist" || vec[0].to_lowercase() == "--dist" {
-            if keyval {
-                search_dist = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                search_dist = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-minz" || vec[0].to_lowercase() == "--minz" {
-            if keyval {
-                minz = vec[1].to_string().parse::<f64>().unwrap();
-            } else {
-                minz = args[i+1].to_string().parse::<f64>().unwrap();
-            }
-        } else if vec[0].to_lowercase() == "-v" || vec[0].to_lowercase() == "--verbose" {
-            verbose = true;
-        } else if vec[0].to_lowercase() == "-h" || vec[0].to_lowercase() == "--help" ||
-            vec[0].to_lowercase() == "--h"{
-            let mut s: String = "Help:\n".to_owned();
-             s.push_str("-i           Input LAS file.\n");
-             s.push_str("-o           Output LAS file.\n");
-             s.push_str("-wd          Optional working directory. If specified, filenames parameters need not include a full path.\n");
-             s.push_str("-dist        Optional search distance in xy units; default is 2.0.\n");
-             s.push_str("-minz        Minimum elevation used in the analysis (optional).\n");
-             s.push_str("-v           Verbose mode; if this flag is present, the tool will report progress if this flag is provided.\n");
-             s.push_str("-version     Prints the tool version number.\n");
-             s.push_str("-h           Prints help information.\n\n");
-             s.push_str("Example usage:\n\n");
-             s.push_str(&">> .*lidar_slope_based_filter -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -v\n".replace("*", &sep));
-             s.push_str(&">> .*lidar_slope_based_filter -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -minz 0.0 -class -v\n".replace("*", &sep));
-             s.push_str(&">> .*lidar_slope_based_filter -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -class -groundclass 1 -otoclass 0 -v\n".replace("*", &sep));
-            println!("{}", s);
-            return;
-        } else if vec[0].to_lowercase() == "-version" || vec[0].to_lowercase() == "--version" {
-            const VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
-            println!("lidar_elev_above_ground v{}", VERSION.unwrap_or("unknown"));
-            return;
-        }
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i           Input LAS file.\n");
+        s.push_str("-o           Output LAS file.\n");
+        s.push_str("-wd          Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-dist        Optional search distance in xy units; default is 2.0.\n");
+        s.push_str("-minz        Minimum elevation used in the analysis (optional).\n");
+        s.push_str("-v           Verbose mode; if this flag is present, the tool will report progress if this flag is provided.\n");
+        s.push_str("-overwrite   Replace the output file if it already exists.\n");
+        s.push_str("-version     Prints the tool version number.\n");
+        s.push_str("-h           Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -v\n", args.tool_name()).replace("*", &sep));
+        s.push_str(&format!(">> .*{} -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -minz 0.0 -class -v\n", args.tool_name()).replace("*", &sep));
+        s.push_str(&format!(">> .*{} -wd \"*path*to*data*\" -i \"input.las\" -o \"output.las\" -dist 5.0 -slope 45.0 -class -groundclass 1 -otoclass 0 -v\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
     }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let search_dist = args.parse_or(&["dist"], 2.0f64);
+    let minz = args.parse_or(&["minz"], f64::NEG_INFINITY);
+    let verbose = args.verbose();
 
     match run(
         input_file,
@@ -94,6 +56,7 @@ fn main() {
         working_directory,
         search_dist,
         minz,
+        overwrite,
         verbose,
     ) {
         Ok(()) => println!("Complete!"),
@@ -102,7 +65,7 @@ fn main() {
 }
 
 fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
-    search_dist: f64, minz: f64, verbose: bool)
+    search_dist: f64, minz: f64, overwrite: bool, verbose: bool)
     -> Result<(), Error> {
 
     println!("**************************************");
@@ -121,6 +84,7 @@ fn run(mut input_file: String, mut output_file: String, mut working_directory: S
     if !output_file.contains(sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    check_output_file(&output_file, overwrite)?;
 
     let input: las::LasFile = las::LasFile::new(&input_file, "r")?;
 
@@ -0,0 +1,96 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::replace_nodata::{replace_nodata, ReplaceValue};
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i           Input raster file.\n");
+        s.push_str("-o           Output raster file.\n");
+        s.push_str("-wd          Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-method      Replacement method, 'mean', 'median' or 'constant' (default is mean).\n");
+        s.push_str("-fill_value  Value assigned to nodata cells; implies the 'constant' method.\n");
+        s.push_str("-overwrite   Replace the output file if it already exists.\n");
+        s.push_str("-version     Prints the tool version number.\n");
+        s.push_str("-h           Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.dep -o output.dep -method median\n", args.tool_name()).replace("*", &sep));
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.dep -o output.dep -fill_value 0.0\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let value = match args.parse::<f64>(&["fill_value"]) {
+        Some(v) => ReplaceValue::Constant(v),
+        None => {
+            let method = args.value(&["method"]).unwrap_or("mean".to_string()).to_lowercase();
+            if method.contains("median") {
+                ReplaceValue::GlobalMedian
+            } else if method.contains("mean") {
+                ReplaceValue::GlobalMean
+            } else {
+                panic!("The 'constant' method requires a -fill_value.");
+            }
+        },
+    };
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, output_file, working_directory, value, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
+    value: ReplaceValue, overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("*****************************");
+        println!("* Welcome to replace_nodata *");
+        println!("*****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let mut output = replace_nodata(&input, value);
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' replace_nodata tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Replacement value: {:?}", value));
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
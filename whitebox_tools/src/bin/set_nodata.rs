@@ -0,0 +1,99 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::set_nodata_value::set_nodata_value;
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i          Input raster file.\n");
+        s.push_str("-o          Output raster file.\n");
+        s.push_str("-old_value  Cell value to be treated as nodata (e.g. -9999).\n");
+        s.push_str("-new_value  New value to use as the declared nodata value.\n");
+        s.push_str("-wd         Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite  Replace the output file if it already exists.\n");
+        s.push_str("-version    Prints the tool version number.\n");
+        s.push_str("-h          Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i input.dep -o output.dep -old_value -9999.0 -new_value -32768.0\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let old_value = args.parse::<f64>(&["old_value"]);
+    let new_value = args.parse::<f64>(&["new_value"]);
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, output_file, working_directory, old_value, new_value, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut output_file: String, mut working_directory: String,
+    old_value: Option<f64>, new_value: Option<f64>, overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("*************************");
+        println!("* Welcome to set_nodata *");
+        println!("*************************");
+    }
+
+    if old_value.is_none() && new_value.is_none() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "At least one of -old_value or -new_value must be specified."));
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    if verbose { println!("Setting nodata values...") };
+    let mut output = match (old_value, new_value) {
+        (Some(old), Some(new)) => set_nodata_value(&set_nodata_value(&input, old, true), new, false),
+        (Some(old), None) => set_nodata_value(&input, old, true),
+        (None, Some(new)) => set_nodata_value(&input, new, false),
+        (None, None) => unreachable!(),
+    };
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' set_nodata tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    if let Some(old) = old_value {
+        output.add_metadata_entry(format!("Value assigned as nodata: {}", old));
+    }
+    if let Some(new) = new_value {
+        output.add_metadata_entry(format!("New nodata value: {}", new));
+    }
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::tools::split_bands::{split_bands, band_file_name};
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-i          Input multi-band (e.g. RGB) raster file; the bands are written to name_band1.dep, name_band2.dep, etc.\n");
+        s.push_str("-wd         Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite  Replace the output file if it already exists.\n");
+        s.push_str("-version    Prints the tool version number.\n");
+        s.push_str("-h          Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -i image.tif\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let input_file = args.value(&["i", "input"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(input_file, working_directory, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut input_file: String, mut working_directory: String,
+    overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("**************************");
+        println!("* Welcome to split_bands *");
+        println!("**************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+
+    if verbose { println!("Reading data...") };
+    let bands = split_bands(&input_file)?;
+
+    let output_files: Vec<String> = (0..bands.len()).map(|i| band_file_name(&input_file, i + 1)).collect();
+    for f in &output_files {
+        check_output_file(f, overwrite)?;
+    }
+
+    if verbose { println!("Saving data...") };
+    for (i, mut band) in bands.into_iter().enumerate() {
+        band.set_file_name(&output_files[i]);
+        band.add_metadata_entry("Created by whitebox_tools\' split_bands tool".to_owned());
+        band.add_metadata_entry(format!("Input file: {}", input_file));
+        band.add_metadata_entry(format!("Band: {}", i + 1));
+        band.write()?;
+        if verbose { println!("Output file written: {}", output_files[i]) };
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+extern crate whitebox_tools;
+
+use std::io;
+use std::path;
+use whitebox_tools::cli::Args;
+use whitebox_tools::io_utils::file_utils::check_output_file;
+use whitebox_tools::raster::*;
+use whitebox_tools::tools::stream_power_index::stream_power_index;
+
+fn main() {
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    let args = Args::from_env(env!("CARGO_BIN_NAME"));
+    if args.is_empty() { panic!("Tool run with no paramters. Please see help (-h) for parameter descriptions."); }
+    if args.help() {
+        let mut s: String = "Help:\n".to_owned();
+        s.push_str("-flow_accum  Input flow accumulation raster file, in cells.\n");
+        s.push_str("-slope       Input slope raster file, in degrees.\n");
+        s.push_str("-o           Output raster file.\n");
+        s.push_str("-wd          Optional working directory. If specified, filenames parameters need not include a full path.\n");
+        s.push_str("-overwrite   Replace the output file if it already exists.\n");
+        s.push_str("-version     Prints the tool version number.\n");
+        s.push_str("-h           Prints help information.\n\n");
+        s.push_str("Example usage:\n\n");
+        s.push_str(&format!(">> .*{} -wd *path*to*data* -flow_accum flow_accum.dep -slope slope.dep -o spi.dep\n", args.tool_name()).replace("*", &sep));
+        println!("{}", s);
+        return;
+    }
+    if args.version() {
+        println!("{}", args.version_string());
+        return;
+    }
+    let accum_file = args.value(&["flow_accum"]).unwrap_or(String::new());
+    let slope_file = args.value(&["slope"]).unwrap_or(String::new());
+    let output_file = args.value(&["o", "output"]).unwrap_or(String::new());
+    let working_directory = args.working_directory();
+    let overwrite = args.flag(&["overwrite"]);
+    let verbose = args.verbose();
+
+    match run(accum_file, slope_file, output_file, working_directory, overwrite, verbose) {
+        Ok(()) => println!("Complete!"),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn run(mut accum_file: String, mut slope_file: String, mut output_file: String, mut working_directory: String,
+    overwrite: bool, verbose: bool) -> Result<(), io::Error> {
+
+    if verbose {
+        println!("*********************************");
+        println!("* Welcome to stream_power_index *");
+        println!("*********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !working_directory.ends_with(&sep) {
+        working_directory.push_str(&(sep.to_string()));
+    }
+
+    if !accum_file.contains(&sep) {
+        accum_file = format!("{}{}", working_directory, accum_file);
+    }
+    if !slope_file.contains(&sep) {
+        slope_file = format!("{}{}", working_directory, slope_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let flow_accum = Raster::new(&accum_file, "r")?;
+    let slope = Raster::new(&slope_file, "r")?;
+
+    let mut output = stream_power_index(&flow_accum, &slope)?;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' stream_power_index tool".to_owned());
+    output.add_metadata_entry(format!("Flow accumulation file: {}", accum_file));
+    output.add_metadata_entry(format!("Slope file: {}", slope_file));
+
+    if verbose { println!("Saving data...") };
+    output.write()?;
+    if verbose { println!("Output file written") };
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Command-line argument parsing shared by the stand-alone binaries.
+
+use std::env;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// The command-line arguments of a stand-alone binary. Flags are matched case-insensitively with
+/// one or two leading dashes, so the name `"input"` matches both `-input` and `--INPUT`, and
+/// values may be given either as `-flag=value` or `-flag value`. Quotes are stripped.
+pub struct Args {
+    tool_name: String,
+    args: Vec<String>,
+}
+
+impl Args {
+    /// Reads the arguments of the current process, excluding the program name. Binaries should
+    /// pass `env!("CARGO_BIN_NAME")` as the tool name, so that it can't fall out of step with
+    /// the binary.
+    pub fn from_env(tool_name: &str) -> Args {
+        Args::new(tool_name, env::args().skip(1).collect())
+    }
+
+    pub fn new(tool_name: &str, args: Vec<String>) -> Args {
+        Args {
+            tool_name: tool_name.to_string(),
+            args: args.iter().map(|a| a.replace("\"", "").replace("\'", "")).collect(),
+        }
+    }
+
+    pub fn tool_name(&self) -> &str {
+        &self.tool_name
+    }
+
+    /// The tool name and the crate version, as printed for `-version`.
+    pub fn version_string(&self) -> String {
+        format!("{} v{}", self.tool_name, env!("CARGO_PKG_VERSION"))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns true if any of the flags `names` is present.
+    pub fn flag(&self, names: &[&str]) -> bool {
+        self.args.iter().any(|a| matches(key(a), names))
+    }
+
+    /// Returns the value of the last of the flags `names` given, or None if none of them is
+    /// present or the last is not followed by a value.
+    pub fn value(&self, names: &[&str]) -> Option<String> {
+        let mut value = None;
+        for (i, a) in self.args.iter().enumerate() {
+            if matches(key(a), names) {
+                value = match a.find('=') {
+                    Some(j) => Some(a[j + 1..].to_string()),
+                    None => self.args.get(i + 1).cloned(),
+                };
+            }
+        }
+        value
+    }
+
+    /// Returns the value of the flags `names` parsed as a `T`, or None if it is not given.
+    /// Panics, like the binaries' other argument errors, if the value can't be parsed.
+    pub fn parse<T>(&self, names: &[&str]) -> Option<T> where T: FromStr, T::Err: Debug {
+        self.value(names).map(|v| match v.trim().parse::<T>() {
+            Ok(x) => x,
+            Err(e) => panic!("Invalid value '{}' for -{}: {:?}", v, names[0], e),
+        })
+    }
+
+    /// Like `parse`, but returns `default` if the flag is not given.
+    pub fn parse_or<T>(&self, names: &[&str], default: T) -> T where T: FromStr, T::Err: Debug {
+        self.parse(names).unwrap_or(default)
+    }
+
+    /// The `-wd` working directory, or an empty string if it is not given.
+    pub fn working_directory(&self) -> String {
+        self.value(&["wd"]).unwrap_or(String::new())
+    }
+
+    /// Whether the `-v` (verbose) flag is given.
+    pub fn verbose(&self) -> bool {
+        self.flag(&["v", "verbose"])
+    }
+
+    /// Whether the `-h` (help) flag is given.
+    pub fn help(&self) -> bool {
+        self.flag(&["h", "help"])
+    }
+
+    /// Whether the `-version` flag is given.
+    pub fn version(&self) -> bool {
+        self.flag(&["version"])
+    }
+}
+
+/// The flag name of an argument, without its leading dashes and any `=value`, or None if the
+/// argument is not a flag.
+fn key(arg: &str) -> Option<String> {
+    if !arg.starts_with('-') {
+        return None;
+    }
+    let name = arg.split('=').next().unwrap().trim_start_matches('-');
+    // Negative numbers are values, not flags.
+    if name.is_empty() || name.parse::<f64>().is_ok() {
+        return None;
+    }
+    Some(name.to_lowercase())
+}
+
+fn matches(key: Option<String>, names: &[&str]) -> bool {
+    match key {
+        Some(k) => names.iter().any(|n| *n == k),
+        None => false,
+    }
+}
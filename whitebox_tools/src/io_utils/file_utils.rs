@@ -0,0 +1,12 @@
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+/// Returns an error naming the file if it already exists and overwriting has not been
+/// explicitly requested. Tools should call this before doing any work so that a mistyped
+/// output name doesn't clobber an existing file, e.g. the input.
+pub fn check_output_file<'a>(file_name: &'a str, overwrite: bool) -> Result<(), Error> {
+    if !overwrite && Path::new(file_name).exists() {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("The output file {} already exists. Use the --overwrite flag to replace it.", file_name)));
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
 // extern crate byteorder;
 
 pub mod byte_order_reader;
+pub mod file_utils;
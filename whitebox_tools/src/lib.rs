@@ -1,11 +1,13 @@
 // extern crate libc;
 extern crate byteorder;
 
+pub mod cli;
 pub mod io_utils;
 pub mod lidar;
 pub mod raster;
 pub mod tools;
 pub mod structures;
+pub mod vector;
 
 // use libc::{c_char};
 // use std::ffi::CStr;
@@ -5,6 +5,7 @@ pub mod lidar;
 pub mod raster;
 pub mod tools;
 pub mod structures;
+pub mod vector;
 
 use std::io::{Error, ErrorKind};
 use std::env;
@@ -56,7 +57,9 @@ fn run() -> Result<(), Error> {
             run_tool = true;
             //println!("running: {}", tool_name);
         } else if arg.starts_with("-args") || arg.starts_with("--args") || arg.starts_with("-a") {
-            tool_args = arg.replace("--args", "").replace("-args", "").replace("-a", "");
+            // Only strip the flag itself; tool arguments such as --azimuth also contain "-a".
+            let flag_len = if arg.starts_with("--args") { 6 } else if arg.starts_with("-args") { 5 } else { 2 };
+            tool_args = arg[flag_len..].to_string();
             if tool_args.starts_with("=") {
                 tool_args = tool_args[1..tool_args.len()].to_string();
             }
@@ -104,6 +107,14 @@ fn run() -> Result<(), Error> {
         } else if arg.starts_with("-version") || arg.starts_with("--version") {
             version();
             return Ok(());
+        } else if arg.starts_with("-compress") || arg.starts_with("--compress") {
+            let mut v = arg.replace("--compress", "").replace("-compress", "").replace("\"", "").replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            // each tool sets the compression of the rasters it writes
+            v.parse::<raster::Compression>()?;
+            tool_args_vec.push(format!("--compress={}", v));
         } else if arg.starts_with("-v") {
             verbose = true;
         }
@@ -142,6 +153,7 @@ fn help() {
 The following commands are recognized:
 -a, --args       Sets the arguments for running tools; --args=\"-i=file.las -o=newfile.las\".
 --cd, --wd       Changes the working directory; used in conjunction with --run flag.
+--compress       Compresses output rasters; none, rle (Whitebox rasters), deflate or lzw (GeoTIFFs).
 -l, --license    Prints the whitebox-tools license.
 --listtools      Lists all available tools.
 -r, --run        Runs a tool; used in conjuction with --args and --cd flags; -r=\"lidar_info\".
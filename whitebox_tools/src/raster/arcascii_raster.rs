@@ -17,24 +17,24 @@ pub fn read_arcascii(file_name: &String, configs: &mut RasterConfigs, data: &mut
     let mut yllcorner: f64 = f64::NEG_INFINITY;
     //let mut likely_float = false;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         let line_split = line_unwrapped.split(" ");
         let vec = line_split.collect::<Vec<&str>>();
         if vec[0].to_lowercase().contains("nrows") {
-            configs.rows = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.rows = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("ncols") {
-            configs.columns = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("xllcorner") {
-            xllcenter = vec[1].trim().to_string().parse::<f64>().unwrap();
+            xllcenter = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("yllcorner") {
-            yllcenter = vec[1].trim().to_string().parse::<f64>().unwrap();
+            yllcenter = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("xllcenter") {
-            xllcorner = vec[1].trim().to_string().parse::<f64>().unwrap();
+            xllcorner = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("yllcenter") {
-            yllcorner = vec[1].trim().to_string().parse::<f64>().unwrap();
+            yllcorner = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("cellsize") {
-            configs.resolution_x = vec[1].trim().to_string().parse::<f64>().unwrap();
-            configs.resolution_y = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.resolution_x = parse_field::<f64>(vec[0], vec[1])?;
+            configs.resolution_y = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("nodata_value") {
             if vec[1].contains(".") {
                 //likely_float = true;
@@ -42,10 +42,10 @@ pub fn read_arcascii(file_name: &String, configs: &mut RasterConfigs, data: &mut
             } else {
                 configs.data_type = DataType::I32;
             }
-            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.nodata = parse_field::<f64>(vec[0], vec[1])?;
         } else { // it's a data line
             for val in vec {
-                data.push(val.trim().to_string().parse::<f64>().unwrap());
+                data.push(parse_field::<f64>("data", val)?);
             }
         }
     }
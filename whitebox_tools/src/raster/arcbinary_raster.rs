@@ -1,12 +1,13 @@
 use std::io::Error;
+use std::io::ErrorKind;
 use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::prelude::*;
 use std::f64;
 use std::fs::File;
-use std::mem;
+use byteorder::{ByteOrder, LittleEndian, BigEndian};
 use raster::*;
-use io_utils::byte_order_reader::Endianness;
+use io_utils::byte_order_reader::{ByteOrderReader, Endianness};
 
 pub fn read_arcbinary(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec<f64>) -> Result<(), Error> {
     // read the header file
@@ -20,27 +21,31 @@ pub fn read_arcbinary(file_name: &String, configs: &mut RasterConfigs, data: &mu
     let mut yllcorner: f64 = f64::NEG_INFINITY;
 
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         // println!("{}", line_unwrapped);
-        let line_split = line_unwrapped.split(" ");
+        // Header keys and values may be separated by any amount of whitespace.
+        let line_split = line_unwrapped.split_whitespace();
         let vec = line_split.collect::<Vec<&str>>();
+        if vec.len() < 2 {
+            continue;
+        }
         if vec[0].to_lowercase().contains("nrows") {
-            configs.rows = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.rows = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("ncols") {
-            configs.columns = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("xllcorner") {
-            xllcenter = vec[1].trim().to_string().parse::<f64>().unwrap();
+            xllcorner = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("yllcorner") {
-            yllcenter = vec[1].trim().to_string().parse::<f64>().unwrap();
+            yllcorner = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("xllcenter") {
-            xllcorner = vec[1].trim().to_string().parse::<f64>().unwrap();
+            xllcenter = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("yllcenter") {
-            yllcorner = vec[1].trim().to_string().parse::<f64>().unwrap();
+            yllcenter = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("cellsize") {
-            configs.resolution_x = vec[1].trim().to_string().parse::<f64>().unwrap();
-            configs.resolution_y = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.resolution_x = parse_field::<f64>(vec[0], vec[1])?;
+            configs.resolution_y = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("nodata_value") {
-            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.nodata = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("byteorder") {
             if vec[1].trim().to_lowercase().contains("lsb") {
                  configs.endian = Endianness::LittleEndian;
@@ -71,24 +76,18 @@ pub fn read_arcbinary(file_name: &String, configs: &mut RasterConfigs, data: &mu
     let data_file = file_name.replace(".hdr", ".flt");
     let mut f = File::open(data_file.clone())?;
 
-    let data_size = 4;
     let num_cells = configs.rows * configs.columns;
-    let buf_size = 1_000_000usize;
-    let mut j = 0;
-    while j < num_cells {
-        let mut buffer = vec![0; buf_size * data_size];
-
-        f.read(&mut buffer)?;
-
-        let mut offset: usize;
-        for i in 0..buf_size {
-            offset = i * 4;
-            data.push(unsafe { mem::transmute::<[u8; 4], f32>([buffer[offset], buffer[offset+1],
-                buffer[offset+2], buffer[offset+3]])} as f64);
-            j += 1;
-            if j == num_cells { break; }
-        }
+    let mut buffer = vec![];
+    f.read_to_end(&mut buffer)?;
+    if buffer.len() < num_cells * 4 {
+        return Err(Error::new(ErrorKind::UnexpectedEof, format!("{} is smaller than the {} rows and {} columns given in its header.", data_file, configs.rows, configs.columns)));
+    }
 
+    // The values are float32 in row-major order, in the byte order given by the header.
+    let mut bor = ByteOrderReader::new(buffer, configs.endian);
+    data.clear();
+    for _ in 0..num_cells {
+        data.push(bor.read_f32() as f64);
     }
 
     Ok(())
@@ -134,12 +133,16 @@ pub fn write_arcbinary<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let f = File::create(&data_file)?;
     let mut writer = BufWriter::new(f);
 
-    let mut u32_bytes: [u8; 4];
+    let mut f32_bytes = [0u8; 4];
 
     let num_cells: usize = r.configs.rows * r.configs.columns;
     for i in 0..num_cells {
-        u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
-        writer.write(&u32_bytes)?;
+        if r.configs.endian == Endianness::LittleEndian {
+            LittleEndian::write_f32(&mut f32_bytes, r.data[i] as f32);
+        } else {
+            BigEndian::write_f32(&mut f32_bytes, r.data[i] as f32);
+        }
+        writer.write_all(&f32_bytes)?;
     }
 
     let _ = writer.flush();
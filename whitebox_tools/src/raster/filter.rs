@@ -0,0 +1,36 @@
+use raster::Raster;
+
+/// Applies `filter` to the input and then repeatedly to its own output, up to `num_iterations`
+/// passes in all, stopping early once the mean absolute change of the valid cells in a pass
+/// falls below `tolerance` (a tolerance of 0 never stops early). Returns the filtered raster
+/// and the number of passes performed. A single pass often under-smooths noisy data, while too
+/// many passes flatten real features.
+pub fn iterate_filter<F>(input: &Raster, num_iterations: usize, tolerance: f64, filter: F) -> (Raster, usize)
+    where F: Fn(&Raster) -> Raster {
+    let mut output = filter(input);
+    let mut passes = 1;
+    while passes < num_iterations {
+        let next = filter(&output);
+        passes += 1;
+        let change = mean_absolute_change(&output, &next);
+        output = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    (output, passes)
+}
+
+// Returns the mean absolute difference between two aligned rasters over the cells that are
+// valid in both, or 0 if there are none.
+fn mean_absolute_change(a: &Raster, b: &Raster) -> f64 {
+    let mut sum = 0f64;
+    let mut n = 0f64;
+    for (&z_a, &z_b) in a.as_slice().iter().zip(b.as_slice().iter()) {
+        if !a.is_nodata(z_a) && !b.is_nodata(z_b) {
+            sum += (z_a - z_b).abs();
+            n += 1f64;
+        }
+    }
+    if n > 0f64 { sum / n } else { 0f64 }
+}
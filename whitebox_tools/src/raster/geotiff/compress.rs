@@ -0,0 +1,534 @@
+//! The codecs of compressed GeoTIFFs: TIFF-flavoured LZW, zlib-wrapped DEFLATE, and the
+//! horizontal differencing predictors that are applied to each row before compression.
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use io_utils::byte_order_reader::Endianness;
+
+const LENGTH_BASE: [usize; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51,
+    59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u32; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4,
+    5, 5, 5, 5, 0];
+const DISTANCE_BASE: [usize; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385,
+    513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+
+fn distance_extra(k: usize) -> u32 {
+    if k < 4 { 0 } else { k as u32 / 2 - 1 }
+}
+
+/// Replaces each sample of a row by its difference from the previous sample of the same
+/// channel (TIFF predictor 2). `row` holds `spp` interleaved channels of little-endian integer
+/// samples that are `bytes_per_sample` bytes wide; differences wrap around.
+pub fn horizontal_difference(row: &mut [u8], bytes_per_sample: usize, spp: usize) {
+    let n = row.len() / bytes_per_sample;
+    let read = |row: &[u8], i: usize| {
+        let mut v = 0u64;
+        for b in 0..bytes_per_sample {
+            v |= (row[i * bytes_per_sample + b] as u64) << (8 * b);
+        }
+        v
+    };
+    for i in (spp..n).rev() {
+        let diff = read(row, i).wrapping_sub(read(row, i - spp));
+        for b in 0..bytes_per_sample {
+            row[i * bytes_per_sample + b] = (diff >> (8 * b)) as u8;
+        }
+    }
+}
+
+/// The floating-point predictor (TIFF predictor 3): the bytes of a row's little-endian
+/// samples are regrouped from most to least significant, so that the slowly changing sign and
+/// exponent bytes of neighbouring values line up, and then differenced byte by byte.
+pub fn floating_point_difference(row: &mut [u8], bytes_per_sample: usize, spp: usize) {
+    let n = row.len() / bytes_per_sample;
+    let original = row.to_vec();
+    for i in 0..n {
+        for b in 0..bytes_per_sample {
+            row[(bytes_per_sample - b - 1) * n + i] = original[i * bytes_per_sample + b];
+        }
+    }
+    for i in (spp..row.len()).rev() {
+        row[i] = row[i].wrapping_sub(row[i - spp]);
+    }
+}
+
+/// Compresses `data` with the LZW variant of the TIFF specification: codes are packed most
+/// significant bit first, start at 9 bits, and the table is reset before it exceeds 12 bits.
+pub fn lzw_encode(data: &[u8]) -> Vec<u8> {
+    const CLEAR: u32 = 256;
+    const EOI: u32 = 257;
+    let mut output = BitWriter::msb_first();
+    let mut table: HashMap<(u32, u8), u32> = HashMap::new();
+    let mut next_code = 258u32;
+    let mut width = 9;
+    output.write(CLEAR, width);
+    let mut prefix = match data.first() {
+        Some(&b) => b as u32,
+        None => {
+            output.write(EOI, width);
+            return output.finish();
+        }
+    };
+    for &b in &data[1..] {
+        if let Some(&code) = table.get(&(prefix, b)) {
+            prefix = code;
+            continue;
+        }
+        output.write(prefix, width);
+        table.insert((prefix, b), next_code);
+        next_code += 1;
+        // Decoders add each entry one code later than the encoder, and so switch widths one
+        // code earlier; 4094 entries is the most a 12-bit decoder can safely hold.
+        if next_code == 4094 {
+            output.write(CLEAR, width);
+            table.clear();
+            next_code = 258;
+            width = 9;
+        } else if next_code == 1 << width {
+            width += 1;
+        }
+        prefix = b as u32;
+    }
+    output.write(prefix, width);
+    // The decoder has now added one more entry, which may push it to the next width.
+    if next_code + 1 == 1 << width {
+        width += 1;
+    }
+    output.write(EOI, width);
+    output.finish()
+}
+
+/// Compresses `data` as a zlib stream (RFC 1950) holding a single DEFLATE block (RFC 1951)
+/// with the fixed Huffman codes. Matches are found with hash chains over a 32 KB window.
+pub fn zlib_encode(data: &[u8]) -> Vec<u8> {
+    const WINDOW: usize = 32768;
+    const MIN_MATCH: usize = 3;
+    const MAX_MATCH: usize = 258;
+    const MAX_CHAIN: usize = 64;
+    const HASH_SIZE: usize = 1 << 15;
+
+    let mut output = BitWriter::lsb_first();
+    output.write(1, 1); // final block
+    output.write(1, 2); // fixed Huffman codes
+
+    let hash = |i: usize| {
+        ((data[i] as usize) << 10 ^ (data[i + 1] as usize) << 5 ^ data[i + 2] as usize) & (HASH_SIZE - 1)
+    };
+    let mut head = vec![usize::max_value(); HASH_SIZE];
+    let mut prev = vec![usize::max_value(); data.len()];
+    let insert = |i: usize, head: &mut Vec<usize>, prev: &mut Vec<usize>| {
+        if i + MIN_MATCH <= data.len() {
+            let h = hash(i);
+            prev[i] = head[h];
+            head[h] = i;
+        }
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let mut best_len = 0;
+        let mut best_dist = 0;
+        if i + MIN_MATCH <= data.len() {
+            let max_len = MAX_MATCH.min(data.len() - i);
+            let mut candidate = head[hash(i)];
+            let mut chain = 0;
+            while candidate != usize::max_value() && i - candidate <= WINDOW && chain < MAX_CHAIN {
+                let mut len = 0;
+                while len < max_len && data[candidate + len] == data[i + len] {
+                    len += 1;
+                }
+                if len > best_len {
+                    best_len = len;
+                    best_dist = i - candidate;
+                    if len == max_len {
+                        break;
+                    }
+                }
+                candidate = prev[candidate];
+                chain += 1;
+            }
+        }
+        if best_len >= MIN_MATCH {
+            write_length(&mut output, best_len);
+            write_distance(&mut output, best_dist);
+            for j in i..i + best_len {
+                insert(j, &mut head, &mut prev);
+            }
+            i += best_len;
+        } else {
+            write_literal(&mut output, data[i] as u32);
+            insert(i, &mut head, &mut prev);
+            i += 1;
+        }
+    }
+    write_literal(&mut output, 256); // end of block
+
+    let mut zlib = vec![0x78, 0x9c];
+    zlib.extend(output.finish());
+    let checksum = adler32(data);
+    zlib.extend_from_slice(&[(checksum >> 24) as u8, (checksum >> 16) as u8, (checksum >> 8) as u8, checksum as u8]);
+    zlib
+}
+
+/// Writes a literal/length symbol with its fixed Huffman code.
+fn write_literal(output: &mut BitWriter, symbol: u32) {
+    let (code, len) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xc0 + symbol - 280, 8),
+    };
+    output.write_huffman(code, len);
+}
+
+fn write_length(output: &mut BitWriter, length: usize) {
+    let k = LENGTH_BASE.iter().rposition(|&b| b <= length).unwrap();
+    write_literal(output, 257 + k as u32);
+    output.write((length - LENGTH_BASE[k]) as u32, LENGTH_EXTRA[k]);
+}
+
+fn write_distance(output: &mut BitWriter, distance: usize) {
+    let k = DISTANCE_BASE.iter().rposition(|&b| b <= distance).unwrap();
+    output.write_huffman(k as u32, 5);
+    output.write((distance - DISTANCE_BASE[k]) as u32, distance_extra(k));
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &d in chunk {
+            a += d as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+/// Packs codes of up to 32 bits into bytes, either most significant bit first (LZW) or least
+/// significant bit first (DEFLATE).
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    num_bits: u32,
+    msb_first: bool,
+}
+
+impl BitWriter {
+    fn msb_first() -> BitWriter {
+        BitWriter { bytes: vec![], buffer: 0, num_bits: 0, msb_first: true }
+    }
+
+    fn lsb_first() -> BitWriter {
+        BitWriter { bytes: vec![], buffer: 0, num_bits: 0, msb_first: false }
+    }
+
+    fn write(&mut self, value: u32, len: u32) {
+        if self.msb_first {
+            self.buffer = (self.buffer << len) | value as u64;
+            self.num_bits += len;
+            while self.num_bits >= 8 {
+                self.num_bits -= 8;
+                self.bytes.push((self.buffer >> self.num_bits) as u8);
+            }
+        } else {
+            self.buffer |= (value as u64) << self.num_bits;
+            self.num_bits += len;
+            while self.num_bits >= 8 {
+                self.bytes.push(self.buffer as u8);
+                self.buffer >>= 8;
+                self.num_bits -= 8;
+            }
+        }
+    }
+
+    /// DEFLATE stores Huffman codes starting from their most significant bit.
+    fn write_huffman(&mut self, code: u32, len: u32) {
+        let mut reversed = 0;
+        for b in 0..len {
+            reversed |= ((code >> b) & 1) << (len - 1 - b);
+        }
+        self.write(reversed, len);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.num_bits > 0 {
+            let byte = if self.msb_first { self.buffer << (8 - self.num_bits) } else { self.buffer };
+            self.bytes.push(byte as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Reverses `horizontal_difference` for a row of samples in the given byte order.
+pub fn undo_horizontal_difference(row: &mut [u8], bytes_per_sample: usize, spp: usize, endian: Endianness) {
+    let n = row.len() / bytes_per_sample;
+    let shift = |b: usize| if endian == Endianness::BigEndian { 8 * (bytes_per_sample - 1 - b) } else { 8 * b };
+    let read = |row: &[u8], i: usize| {
+        let mut v = 0u64;
+        for b in 0..bytes_per_sample {
+            v |= (row[i * bytes_per_sample + b] as u64) << shift(b);
+        }
+        v
+    };
+    for i in spp..n {
+        let sum = read(row, i).wrapping_add(read(row, i - spp));
+        for b in 0..bytes_per_sample {
+            row[i * bytes_per_sample + b] = (sum >> shift(b)) as u8;
+        }
+    }
+}
+
+/// Reverses `floating_point_difference`, returning the samples in the given byte order.
+pub fn undo_floating_point_difference(row: &mut [u8], bytes_per_sample: usize, spp: usize, endian: Endianness) {
+    for i in spp..row.len() {
+        row[i] = row[i].wrapping_add(row[i - spp]);
+    }
+    let n = row.len() / bytes_per_sample;
+    let shuffled = row.to_vec();
+    for i in 0..n {
+        // byte plane k holds the k-th most significant byte of every sample
+        for k in 0..bytes_per_sample {
+            let b = if endian == Endianness::BigEndian { k } else { bytes_per_sample - 1 - k };
+            row[i * bytes_per_sample + b] = shuffled[k * n + i];
+        }
+    }
+}
+
+/// Decompresses TIFF LZW data (see `lzw_encode`).
+pub fn lzw_decode(data: &[u8]) -> Result<Vec<u8>, Error> {
+    const CLEAR: usize = 256;
+    const EOI: usize = 257;
+    let mut input = BitReader::new(data);
+    let mut output = vec![];
+    let mut table: Vec<Vec<u8>> = vec![];
+    let mut width = 9;
+    let mut previous: Option<usize> = None;
+    while let Some(code) = input.read_msb_first(width) {
+        let code = code as usize;
+        if code == EOI {
+            break;
+        }
+        if code == CLEAR {
+            table = (0..256).map(|b| vec![b as u8]).collect();
+            table.push(vec![]);
+            table.push(vec![]);
+            width = 9;
+            previous = None;
+            continue;
+        }
+        let entry = match previous {
+            None => {
+                if code >= table.len() {
+                    return Err(Error::new(ErrorKind::InvalidData, "Invalid LZW code."));
+                }
+                table[code].clone()
+            },
+            Some(p) => {
+                let entry = if code < table.len() {
+                    table[code].clone()
+                } else if code == table.len() {
+                    let mut e = table[p].clone();
+                    e.push(table[p][0]);
+                    e
+                } else {
+                    return Err(Error::new(ErrorKind::InvalidData, "Invalid LZW code."));
+                };
+                let mut new_entry = table[p].clone();
+                new_entry.push(entry[0]);
+                table.push(new_entry);
+                entry
+            },
+        };
+        output.extend_from_slice(&entry);
+        previous = Some(code);
+        // switch widths one code early, as TIFF LZW encoders do
+        if table.len() + 1 >= 1 << width && width < 12 {
+            width += 1;
+        }
+    }
+    Ok(output)
+}
+
+/// Decompresses a zlib stream (RFC 1950) of stored, fixed or dynamic Huffman DEFLATE blocks.
+pub fn zlib_decode(data: &[u8]) -> Result<Vec<u8>, Error> {
+    if data.len() < 2 || data[0] & 0x0f != 8 || ((data[0] as u32) << 8 | data[1] as u32) % 31 != 0 {
+        return Err(Error::new(ErrorKind::InvalidData, "Invalid zlib header."));
+    }
+    if data[1] & 0x20 != 0 {
+        return Err(Error::new(ErrorKind::InvalidData, "zlib preset dictionaries are not supported."));
+    }
+    let bad_data = || Error::new(ErrorKind::InvalidData, "Invalid DEFLATE data.");
+    let mut input = BitReader::new(&data[2..]);
+    let mut output: Vec<u8> = vec![];
+    loop {
+        let last_block = input.read(1).ok_or_else(&bad_data)? == 1;
+        match input.read(2).ok_or_else(&bad_data)? {
+            0 => {
+                input.align();
+                let len = input.read(16).ok_or_else(&bad_data)?;
+                let nlen = input.read(16).ok_or_else(&bad_data)?;
+                if len != !nlen & 0xffff {
+                    return Err(bad_data());
+                }
+                for _ in 0..len {
+                    output.push(input.read(8).ok_or_else(&bad_data)? as u8);
+                }
+            },
+            1 => {
+                let mut lengths = [0u8; 288];
+                for s in 0..288 {
+                    lengths[s] = match s { 0..=143 => 8, 144..=255 => 9, 256..=279 => 7, _ => 8 };
+                }
+                let literals = Huffman::new(&lengths);
+                let distances = Huffman::new(&[5u8; 30]);
+                inflate_block(&mut input, &mut output, &literals, &distances).ok_or_else(&bad_data)?;
+            },
+            2 => {
+                let (literals, distances) = read_dynamic_tables(&mut input).ok_or_else(&bad_data)?;
+                inflate_block(&mut input, &mut output, &literals, &distances).ok_or_else(&bad_data)?;
+            },
+            _ => return Err(bad_data()),
+        }
+        if last_block {
+            break;
+        }
+    }
+    Ok(output)
+}
+
+fn read_dynamic_tables(input: &mut BitReader) -> Option<(Huffman, Huffman)> {
+    const ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+    let num_literals = input.read(5)? as usize + 257;
+    let num_distances = input.read(5)? as usize + 1;
+    let num_code_lengths = input.read(4)? as usize + 4;
+    let mut code_lengths = [0u8; 19];
+    for k in 0..num_code_lengths {
+        code_lengths[ORDER[k]] = input.read(3)? as u8;
+    }
+    let code_length_codes = Huffman::new(&code_lengths);
+    let mut lengths: Vec<u8> = vec![];
+    while lengths.len() < num_literals + num_distances {
+        let (value, repeat) = match code_length_codes.decode(input)? {
+            symbol @ 0..=15 => (symbol as u8, 1),
+            16 => (*lengths.last()?, 3 + input.read(2)?),
+            17 => (0, 3 + input.read(3)?),
+            _ => (0, 11 + input.read(7)?),
+        };
+        for _ in 0..repeat {
+            lengths.push(value);
+        }
+    }
+    if lengths.len() != num_literals + num_distances {
+        return None;
+    }
+    Some((Huffman::new(&lengths[..num_literals]), Huffman::new(&lengths[num_literals..])))
+}
+
+fn inflate_block(input: &mut BitReader, output: &mut Vec<u8>, literals: &Huffman, distances: &Huffman) -> Option<()> {
+    loop {
+        let symbol = literals.decode(input)? as usize;
+        if symbol < 256 {
+            output.push(symbol as u8);
+        } else if symbol == 256 {
+            return Some(());
+        } else {
+            let k = symbol - 257;
+            if k >= 29 {
+                return None;
+            }
+            let length = LENGTH_BASE[k] + input.read(LENGTH_EXTRA[k])? as usize;
+            let d = distances.decode(input)? as usize;
+            if d >= 30 {
+                return None;
+            }
+            let distance = DISTANCE_BASE[d] + input.read(distance_extra(d))? as usize;
+            if distance > output.len() {
+                return None;
+            }
+            let start = output.len() - distance;
+            for j in 0..length {
+                let b = output[start + j];
+                output.push(b);
+            }
+        }
+    }
+}
+
+/// A canonical Huffman code, decoded one bit at a time from the code lengths of its symbols.
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Huffman {
+        let mut counts = [0u16; 16];
+        for &len in lengths {
+            counts[len as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut symbols = vec![];
+        for len in 1..16 {
+            for (s, &l) in lengths.iter().enumerate() {
+                if l as usize == len {
+                    symbols.push(s as u16);
+                }
+            }
+        }
+        Huffman { counts: counts, symbols: symbols }
+    }
+
+    fn decode(&self, input: &mut BitReader) -> Option<u16> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for len in 1..16 {
+            code |= input.read(1)? as i32;
+            let count = self.counts[len] as i32;
+            if code - first < count {
+                return self.symbols.get((index + code - first) as usize).cloned();
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        None
+    }
+}
+
+/// Reads codes from a byte stream, least significant bit first (DEFLATE) or most significant
+/// bit first (LZW).
+struct BitReader<'a> {
+    data: &'a [u8],
+    bit_pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> BitReader<'a> {
+        BitReader { data: data, bit_pos: 0 }
+    }
+
+    fn read(&mut self, len: u32) -> Option<u32> {
+        let mut value = 0u32;
+        for b in 0..len {
+            let byte = *self.data.get(self.bit_pos / 8)?;
+            value |= (((byte >> (self.bit_pos % 8)) & 1) as u32) << b;
+            self.bit_pos += 1;
+        }
+        Some(value)
+    }
+
+    fn read_msb_first(&mut self, len: u32) -> Option<u32> {
+        let mut value = 0u32;
+        for _ in 0..len {
+            let byte = *self.data.get(self.bit_pos / 8)?;
+            value = (value << 1) | ((byte >> (7 - self.bit_pos % 8)) & 1) as u32;
+            self.bit_pos += 1;
+        }
+        Some(value)
+    }
+
+    /// Skips to the next byte boundary.
+    fn align(&mut self) {
+        self.bit_pos = (self.bit_pos + 7) / 8 * 8;
+    }
+}
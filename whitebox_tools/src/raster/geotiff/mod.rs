@@ -1,4 +1,5 @@
 #![allow(unused_assignments, dead_code)]
+pub mod compress;
 pub mod geokeys;
 
 use std::collections::HashMap;
@@ -6,14 +7,14 @@ use std::io::Error;
 use std::io::ErrorKind;
 use std::fmt;
 use std::default::Default;
-use std::cmp::min;
+use std::cmp::{max, min};
 use std::io::BufWriter;
 use std::io::prelude::*;
 use std::f64;
 use std::fs::File;
 use std::fs;
-use std::mem;
 use raster::*;
+use raster::geotiff::compress::*;
 use raster::geotiff::geokeys::*;
 use io_utils::byte_order_reader::ByteOrderReader;
 use io_utils::byte_order_reader::Endianness;
@@ -139,6 +140,14 @@ pub fn read_geotiff<'a>(file_name: &'a String, configs: &'a mut RasterConfigs, d
         _ => return Err(Error::new(ErrorKind::InvalidData, "The raster Compression method value was not read correctly")),
     };
 
+    let predictor = match ifd_map.get(&317) {
+        Some(ifd) => ifd.interpret_as_u16()[0],
+        _ => 1,
+    };
+    if predictor > 3 || (predictor != 1 && bits_per_sample[0] < 8) {
+        return Err(Error::new(ErrorKind::InvalidData, format!("Unsupported TIFF predictor ({}).", predictor)));
+    }
+
     let photometric_interp = match ifd_map.get(&262) {
         Some(ifd) => ifd.interpret_as_u16()[0],
         _ => return Err(Error::new(ErrorKind::InvalidData, "The raster PhotometricInterpretation value was not read correctly")),
@@ -261,6 +270,30 @@ pub fn read_geotiff<'a>(file_name: &'a String, configs: &'a mut RasterConfigs, d
     } else {
         return Err(Error::new(ErrorKind::InvalidData, "Unsupported image format."))
     }
+    // The colour channels are packed into one value per cell below.
+    match mode {
+        ImageMode::RGB | ImageMode::RGBA | ImageMode::NRGBA => {
+            configs.photometric_interp = PhotometricInterpretation::RGB;
+            configs.data_type = DataType::RGBA32;
+        },
+        ImageMode::Gray | ImageMode::GrayInvert => {
+            // SampleFormat defaults to unsigned integer data when the tag is absent
+            configs.data_type = match (max(sample_format[0], 1), bits_per_sample[0]) {
+                (1, 8) => DataType::U8,
+                (1, 16) => DataType::U16,
+                (1, 32) => DataType::U32,
+                (1, 64) => DataType::U64,
+                (2, 8) => DataType::I8,
+                (2, 16) => DataType::I16,
+                (2, 32) => DataType::I32,
+                (2, 64) => DataType::I64,
+                (3, 32) => DataType::F32,
+                (3, 64) => DataType::F64,
+                _ => DataType::Unknown,
+            };
+        },
+        _ => {},
+    }
 
     let width = configs.columns;
     let height = configs.rows;
@@ -395,8 +428,24 @@ pub fn read_geotiff<'a>(file_name: &'a String, configs: &'a mut RasterConfigs, d
                 COMPRESS_PACKBITS => {
                     buf = packbits_decoder(th.buffer[offset..(offset+n)].to_vec());
                 },
-                _ => {
-
+                COMPRESS_DEFLATE | COMPRESS_DEFLATEOLD => {
+                    buf = zlib_decode(&th.buffer[offset..(offset+n)])?;
+                },
+                COMPRESS_LZW => {
+                    buf = lzw_decode(&th.buffer[offset..(offset+n)])?;
+                },
+                _ => return Err(Error::new(ErrorKind::InvalidData, format!("Unsupported TIFF compression method ({}).", compression))),
+            }
+            if predictor != 1 {
+                let bytes_per_sample = bits_per_sample[0] as usize / 8;
+                let row_bytes = block_width * bits_per_sample.len() * bytes_per_sample;
+                for row in buf.chunks_mut(row_bytes) {
+                    if row.len() < row_bytes { break; }
+                    if predictor == 2 {
+                        undo_horizontal_difference(row, bytes_per_sample, bits_per_sample.len(), configs.endian);
+                    } else {
+                        undo_floating_point_difference(row, bytes_per_sample, bits_per_sample.len(), configs.endian);
+                    }
                 }
             }
             let mut bor = ByteOrderReader::new(buf, configs.endian);
@@ -661,131 +710,66 @@ pub fn read_geotiff<'a>(file_name: &'a String, configs: &'a mut RasterConfigs, d
 }
 
 pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
-    let f = File::create(r.file_name.clone())?;
-    let mut writer = BufWriter::new(f);
-
-    let mut u16_bytes: [u8; 2];
-    let mut u32_bytes: [u8; 4];
-    let mut u64_bytes: [u8; 8];
-
-    // This will write a geotiff file in LittleEndian byte order regardless of the native endianness
-    writer.write("II".as_bytes())?;
-
-    u16_bytes = unsafe { mem::transmute(42u16.to_le()) };
-    writer.write(&u16_bytes)?;
-
-    // output the offset to the IFD
 	let total_bytes_per_pixel = r.configs.data_type.get_data_size();
     if total_bytes_per_pixel == 0 {
         return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
     }
 
-    let image_len = (r.configs.rows as usize * r.configs.columns as usize * total_bytes_per_pixel + 8usize) as u32;
-    u32_bytes = unsafe { mem::transmute(image_len.to_le()) };
-    writer.write(&u32_bytes)?;
-
-    // output the data; compression is not currently supported for output
+    // gather the little-endian image data, northernmost row first
+    let mut image: Vec<u8> = Vec::with_capacity(r.configs.rows * r.configs.columns * total_bytes_per_pixel);
     match r.configs.photometric_interp {
         PhotometricInterpretation::Continuous |
         PhotometricInterpretation::Categorical |
         PhotometricInterpretation::Boolean => {
             match r.configs.data_type {
                 DataType::F64 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u64_bytes = unsafe { mem::transmute(r.data[i]) };
-                            writer.write(&u64_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&r.data[i].to_bits().to_le_bytes());
                     }
                 },
                 DataType::F32 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
-                            writer.write(&u32_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as f32).to_bits().to_le_bytes());
                     }
                 },
                 DataType::U64 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u64_bytes = unsafe { mem::transmute(r.data[i] as u64) };
-                            writer.write(&u64_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as u64).to_le_bytes());
                     }
                 },
                 DataType::U32 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u32_bytes = unsafe { mem::transmute(r.data[i] as u32) };
-                            writer.write(&u32_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as u32).to_le_bytes());
                     }
                 },
                 DataType::U16 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u16_bytes = unsafe { mem::transmute(r.data[i] as u16) };
-                            writer.write(&u16_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as u16).to_le_bytes());
                     }
                 },
                 DataType::U8 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write(&[r.data[i] as u8])?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.push(r.data[i] as u8);
                     }
                 },
                 DataType::I64 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u64_bytes = unsafe { mem::transmute(r.data[i] as i64) };
-                            writer.write(&u64_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as i64).to_le_bytes());
                     }
                 },
                 DataType::I32 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u32_bytes = unsafe { mem::transmute(r.data[i] as i32) };
-                            writer.write(&u32_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as i32).to_le_bytes());
                     }
                 },
                 DataType::I16 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            u16_bytes = unsafe { mem::transmute(r.data[i] as i16) };
-                            writer.write(&u16_bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.extend_from_slice(&(r.data[i] as i16).to_le_bytes());
                     }
                 },
                 DataType::I8 => {
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            writer.write(&[r.data[i] as u8])?;
-                        }
+                    for i in 0..r.data.len() {
+                        image.push(r.data[i] as i8 as u8);
                     }
                 },
                 _ => { return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")); },
@@ -794,32 +778,20 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         PhotometricInterpretation::RGB => {
             match r.configs.data_type {
                 DataType::RGB24 => {
-                    let mut bytes: [u8; 3] = [0u8; 3];
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            let val = r.data[i] as u32;
-                            bytes[0] = ((val >> 16u32) & 0xFF) as u8; // red
-                            bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
-                            bytes[2] = (val & 0xFF) as u8; // blue
-                            writer.write(&bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        let val = r.data[i] as u32;
+                        image.push(((val >> 16u32) & 0xFF) as u8); // red
+                        image.push(((val >> 8u32) & 0xFF) as u8); // green
+                        image.push((val & 0xFF) as u8); // blue
                     }
                 },
                 DataType::RGBA32 => {
-                    let mut bytes: [u8; 4] = [0u8; 4];
-                    let mut i: usize;
-                    for row in (0..r.configs.rows).rev() {
-                        for col in 0..r.configs.columns {
-                            i = row * r.configs.columns + col;
-                            let val = r.data[i] as u32;
-                            bytes[3] = ((val >> 24u32) & 0xFF) as u8; // alpha
-                            bytes[0] = ((val >> 16u32) & 0xFF) as u8; // red
-                            bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
-                            bytes[2] = (val & 0xFF) as u8; // blue
-                            writer.write(&bytes)?;
-                        }
+                    for i in 0..r.data.len() {
+                        let val = r.data[i] as u32;
+                        image.push(((val >> 16u32) & 0xFF) as u8); // red
+                        image.push(((val >> 8u32) & 0xFF) as u8); // green
+                        image.push((val & 0xFF) as u8); // blue
+                        image.push(((val >> 24u32) & 0xFF) as u8); // alpha
                     }
                 },
                 _ => { return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")); },
@@ -833,149 +805,175 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         },
     }
 
-    // create the ifd's
-    let mut ifd: Vec<IfdDirectory> = vec![];
-
-    u32_bytes = unsafe { mem::transmute((r.configs.columns as u32).to_le()) };
-    ifd.push(IfdDirectory::new( TAG_IMAGEWIDTH, DT_SHORT, 1u32, r.configs.columns as u32,
-        u32_bytes.iter().cloned().collect(), Endianness::LittleEndian ));
-
-    u32_bytes = unsafe { mem::transmute((r.configs.rows as u32).to_le()) };
-    ifd.push(IfdDirectory::new( TAG_IMAGELENGTH, DT_SHORT, 1u32, r.configs.columns as u32,
-        u32_bytes.iter().cloned().collect(), Endianness::LittleEndian ));
-
-    let bps = match r.configs.photometric_interp {
-        PhotometricInterpretation::RGB => {
-            match r.configs.data_type {
-                DataType::RGB24 => vec![8u16, 8u16, 8u16],
-                DataType::RGBA32 => vec![8u16, 8u16, 8u16, 8u16],
-                DataType::RGB48 => vec![16u16, 16u16, 16u16],
-                _ => { return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")); }
-            }
-        },
-        _ => {
-            match r.configs.data_type {
-                DataType::I8 | DataType::U8 => vec![8u16],
-                DataType::I16 | DataType::U16 => vec![16u16],
-                DataType::I32 | DataType::U32 | DataType::F32 => vec![32u16],
-                DataType::I64 | DataType::U64 | DataType::F64 => vec![64u16],
-                _ => { return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")); }
+    let samples_per_pixel = match r.configs.data_type {
+        DataType::RGB24 => 3usize,
+        DataType::RGBA32 => 4usize,
+        _ => 1usize
+    };
+    let bytes_per_sample = total_bytes_per_pixel / samples_per_pixel;
+    let is_float = r.configs.data_type == DataType::F32 || r.configs.data_type == DataType::F64;
+
+    // Compressed strips are predicted row by row first: horizontal differencing for integer
+    // data and the floating-point predictor for real data.
+    let (compression, predictor) = match r.configs.compression {
+        Compression::Deflate => (COMPRESS_DEFLATE, if is_float { 3u16 } else { 2u16 }),
+        Compression::Lzw => (COMPRESS_LZW, if is_float { 3u16 } else { 2u16 }),
+        _ => (COMPRESS_NONE, 1u16),
+    };
+    let row_length = r.configs.columns * total_bytes_per_pixel;
+    let rows_per_strip = max(1, min(r.configs.rows, 8192 / max(1, row_length)));
+    let mut strips: Vec<Vec<u8>> = vec![];
+    for strip in image.chunks_mut(max(1, rows_per_strip * row_length)) {
+        if compression == COMPRESS_NONE {
+            strips.push(strip.to_vec());
+            continue;
+        }
+        for row in strip.chunks_mut(row_length) {
+            if is_float {
+                floating_point_difference(row, bytes_per_sample, samples_per_pixel);
+            } else {
+                horizontal_difference(row, bytes_per_sample, samples_per_pixel);
             }
         }
-    };
-    let _byte_size = bps.len() * 2 as usize;
-
-
-    //let raw_bytes : [u8; byte_size] = unsafe {mem::transmute(bps)};
-    // var bps = make([]uint16, g.samplesPerPixel)
-    // for i := 0; i < int(g.samplesPerPixel); i++ {
-    //     bps[i] = uint16(g.BitsPerSample[i])
-    // }
-    // ifd = append(ifd, CreateIfdEntry(tBitsPerSample, dtShort, uint32(g.samplesPerPixel), bps, g.ByteOrder))
+        strips.push(if compression == COMPRESS_DEFLATE { zlib_encode(strip) } else { lzw_encode(strip) });
+    }
 
-    // fn new(tag: u16, ifd_type: u16, num_values: u32, offset: u32, data: Vec<u8>, byte_order: RasterByteOrder)
-    ifd.push(IfdDirectory::new( TAG_COMPRESSION, DT_SHORT, 1u32, COMPRESS_NONE as u32,
-        vec![1u8], Endianness::LittleEndian ));
+    // The strips follow the 8-byte header and are followed by the IFD, which must start on a
+    // word boundary.
+    let mut strip_offsets: Vec<u32> = vec![];
+    let mut strip_byte_counts: Vec<u32> = vec![];
+    let mut offset = 8usize;
+    for strip in &strips {
+        strip_offsets.push(offset as u32);
+        strip_byte_counts.push(strip.len() as u32);
+        offset += strip.len();
+    }
+    let ifd_offset = offset + offset % 2;
 
+    // create the ifd's, in ascending order of tag
     let out_pi = match r.configs.photometric_interp {
-        PhotometricInterpretation::Continuous | PhotometricInterpretation::Categorical => PI_BLACKISZERO,
+        PhotometricInterpretation::Continuous |
+        PhotometricInterpretation::Categorical |
+        PhotometricInterpretation::Boolean => PI_BLACKISZERO,
         PhotometricInterpretation::RGB => PI_RGB,
-        PhotometricInterpretation::Paletted => PI_PALETTED,
         _ => { return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")); }
     };
-    ifd.push(IfdDirectory::new( TAG_PHOTOMETRICINTERPRETATION, DT_SHORT, 1u32, out_pi as u32,
-        vec![out_pi as u8], Endianness::LittleEndian ));
-
-    //let mut strip_offsets: Vec<u32> = vec![];
-    let total_bytes_per_pixel = match r.configs.data_type {
-        DataType::I8 | DataType::U8 => 1u32,
-        DataType::I16 | DataType::U16 => 2u32,
-        DataType::I32 | DataType::U32 | DataType::F32 => 4u32,
-        DataType::I64 | DataType::U64 | DataType::F64 => 8u32,
-        DataType::RGB24 => 3u32,
-        DataType::RGBA32 => 4u32,
-        DataType::RGB48 => 6u32,
-        _ => { return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")); }
+    let sample_format = match r.configs.data_type {
+        DataType::F32 | DataType::F64 => 3u16,
+        DataType::I8 | DataType::I16 | DataType::I32 | DataType::I64 => 2u16,
+        _ => 1u16,
     };
-    let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel;
-    //let strip_byte_count: Vec<u32> = vec![row_length_in_bytes; r.configs.rows as usize];
-    let mut strip_offsets_as_bytes: Vec<u8> = vec![];
-    let mut strip_byte_count_as_bytes: Vec<u8> = vec![];
-    for i in 0..r.configs.rows as u32 {
-        let val = 8u32 + row_length_in_bytes * i;
-        //strip_offsets.push(val);
-        u32_bytes = unsafe { mem::transmute(val.to_le()) };
-        strip_offsets_as_bytes.push(u32_bytes[0]);
-        strip_offsets_as_bytes.push(u32_bytes[1]);
-        strip_offsets_as_bytes.push(u32_bytes[2]);
-        strip_offsets_as_bytes.push(u32_bytes[3]);
-
-        u32_bytes = unsafe { mem::transmute(row_length_in_bytes.to_le()) };
-        strip_byte_count_as_bytes.push(u32_bytes[0]);
-        strip_byte_count_as_bytes.push(u32_bytes[1]);
-        strip_byte_count_as_bytes.push(u32_bytes[2]);
-        strip_byte_count_as_bytes.push(u32_bytes[3]);
+    let mut ifd: Vec<IfdDirectory> = vec![];
+    ifd.push(ifd_long(TAG_IMAGEWIDTH, &[r.configs.columns as u32]));
+    ifd.push(ifd_long(TAG_IMAGELENGTH, &[r.configs.rows as u32]));
+    ifd.push(ifd_short(TAG_BITSPERSAMPLE, &vec![8 * bytes_per_sample as u16; samples_per_pixel]));
+    ifd.push(ifd_short(TAG_COMPRESSION, &[compression]));
+    ifd.push(ifd_short(TAG_PHOTOMETRICINTERPRETATION, &[out_pi]));
+    ifd.push(ifd_long(TAG_STRIPOFFSETS, &strip_offsets));
+    ifd.push(ifd_short(TAG_SAMPLESPERPIXEL, &[samples_per_pixel as u16]));
+    ifd.push(ifd_long(TAG_ROWSPERSTRIP, &[rows_per_strip as u32]));
+    ifd.push(ifd_long(TAG_STRIPBYTECOUNTS, &strip_byte_counts));
+    ifd.push(ifd_short(TAG_PLANARCONFIGURATION, &[1u16]));
+    ifd.push(ifd_ascii(TAG_SOFTWARE, "whitebox-tools"));
+    if predictor != 1 {
+        ifd.push(ifd_short(TAG_PREDICTOR, &[predictor]));
+    }
+    if samples_per_pixel == 4 {
+        ifd.push(ifd_short(TAG_EXTRASAMPLES, &[2u16])); // unassociated alpha
+    }
+    ifd.push(ifd_short(TAG_SAMPLEFORMAT, &vec![sample_format; samples_per_pixel]));
+    ifd.push(ifd_double(TAG_MODELPIXELSCALETAG, &[r.configs.resolution_x, r.configs.resolution_y, 0f64]));
+    ifd.push(ifd_double(TAG_MODELTIEPOINTTAG, &[0f64, 0f64, 0f64, r.configs.west, r.configs.north, 0f64]));
+
+    // The grid's tie point is the corner of its north-west cell, i.e. the raster is
+    // PixelIsArea; EPSG codes 4000-4999 are geographic coordinate systems.
+    let geographic = r.configs.epsg_code >= 4000 && r.configs.epsg_code < 5000;
+    let mut geokeys = vec![1u16, 1, 0, 2,
+        TAG_GTMODELTYPEGEOKEY, 0, 1, if geographic { 2 } else { 1 },
+        TAG_GTRASTERTYPEGEOKEY, 0, 1, 1];
+    if r.configs.epsg_code != 0 {
+        geokeys[3] += 1;
+        geokeys.extend_from_slice(&[if geographic { TAG_GEOGRAPHICTYPEGEOKEY } else { TAG_PROJECTEDCSTYPEGEOKEY }, 0, 1, r.configs.epsg_code]);
+    }
+    ifd.push(ifd_short(TAG_GEOKEYDIRECTORYTAG, &geokeys));
+    if out_pi != PI_RGB {
+        ifd.push(ifd_ascii(TAG_GDAL_NODATA, &format!("{}", r.configs.nodata)));
     }
 
-    ifd.push(IfdDirectory::new( TAG_STRIPOFFSETS, DT_LONG, r.configs.rows as u32, 0u32,
-        strip_offsets_as_bytes, Endianness::LittleEndian ));
+    let f = File::create(r.file_name.clone())?;
+    let mut writer = BufWriter::new(f);
 
-    let samples_per_pixel = match r.configs.data_type {
-        DataType::RGB24 => 3u16,
-        DataType::RGBA32 => 4u16,
-        DataType::RGB48 => 3u16,
-        _ => 1u16
-    };
-    u16_bytes = unsafe { mem::transmute(samples_per_pixel.to_le()) };
-    ifd.push(IfdDirectory::new( TAG_SAMPLESPERPIXEL, DT_SHORT, 1u32, 0u32,
-        u16_bytes.iter().cloned().collect(), Endianness::LittleEndian ));
-
-    u16_bytes = unsafe { mem::transmute(1u16.to_le()) };
-    ifd.push(IfdDirectory::new( TAG_ROWSPERSTRIP, DT_SHORT, 1u32, 1u32,
-        u16_bytes.iter().cloned().collect(), Endianness::LittleEndian ));
-
-    ifd.push(IfdDirectory::new( TAG_STRIPBYTECOUNTS, DT_LONG, r.configs.rows as u32, 0u32,
-        strip_byte_count_as_bytes, Endianness::LittleEndian ));
-
-    let software: String = String::from("whitebox-tools");
-    let software_len = software.len() as u32;
-    ifd.push(IfdDirectory::new( TAG_SOFTWARE, DT_ASCII, software_len, 0u32,
-        software.into_bytes(), Endianness::LittleEndian ));
-
-    // sf := make([]uint16, g.samplesPerPixel)
-    // for i := 0; i < int(g.samplesPerPixel); i++ {
-    //     sf[i] = uint16(g.SampleFormat)
-    // }
-    // ifd = append(ifd, CreateIfdEntry(tSampleFormat, dtShort, uint32(g.samplesPerPixel), sf, g.ByteOrder))
-    //
-    // if g.samplesPerPixel > 1 {
-    //     ifd = append(ifd, CreateIfdEntry(tPlanarConfiguration, dtShort, 1, uint16(1), g.ByteOrder))
-    // }
-    //
-    // if g.PhotometricInterp == PI_RGB && g.samplesPerPixel == 4 {
-    //     ifd = append(ifd, CreateIfdEntry(tExtraSamples, dtShort, 1, uint16(1), g.ByteOrder))
-    // }
-    //
-    // // There is currently no support for storing the image
-    // // resolution, so give a bogus value of 72x72 dpi.
-    // ifd = append(ifd, CreateIfdEntry(tXResolution, dtRational, 1, []uint32{72, 1}, g.ByteOrder))
-    // ifd = append(ifd, CreateIfdEntry(tYResolution, dtRational, 1, []uint32{72, 1}, g.ByteOrder))
-    // ifd = append(ifd, CreateIfdEntry(tResolutionUnit, dtShort, 1, []uint32{resPerInch}, g.ByteOrder))
-    //
-    // // Add the ModelPixelScaleTag and ModelTiepointTag tags
-    // ifd = append(ifd, CreateIfdEntry(tModelTiepointTag, dtDouble, 6, g.TiepointData.getModelTiepointTagData(), g.ByteOrder))
-    // ifd = append(ifd, CreateIfdEntry(tModelPixelScaleTag, dtDouble, 3, g.TiepointData.getModelPixelScaleTagData(), g.ByteOrder))
-    //
-    // if g.NodataValue != "" {
-    //     ifd = append(ifd, CreateIfdEntry(tGDAL_NODATA, dtASCII, uint32(len(g.NodataValue)), g.NodataValue, g.ByteOrder))
-    // }
-
-
-    let _ = writer.flush();
+    // This will write a geotiff file in LittleEndian byte order regardless of the native endianness
+    writer.write_all("II".as_bytes())?;
+    writer.write_all(&42u16.to_le_bytes())?;
+    writer.write_all(&(ifd_offset as u32).to_le_bytes())?;
+    for strip in &strips {
+        writer.write_all(strip)?;
+    }
+    if ifd_offset > offset {
+        writer.write_all(&[0u8])?;
+    }
+
+    // Values longer than four bytes are stored after the IFD, each on a word boundary.
+    let mut extra_offset = ifd_offset + 2 + 12 * ifd.len() + 4;
+    let mut extra_data: Vec<u8> = vec![];
+    writer.write_all(&(ifd.len() as u16).to_le_bytes())?;
+    for entry in &ifd {
+        writer.write_all(&entry.tag.to_le_bytes())?;
+        writer.write_all(&entry.ifd_type.to_le_bytes())?;
+        writer.write_all(&entry.num_values.to_le_bytes())?;
+        if entry.data.len() <= 4 {
+            let mut value = entry.data.clone();
+            value.resize(4, 0u8);
+            writer.write_all(&value)?;
+        } else {
+            writer.write_all(&(extra_offset as u32).to_le_bytes())?;
+            extra_data.extend_from_slice(&entry.data);
+            if entry.data.len() % 2 == 1 {
+                extra_data.push(0u8);
+            }
+            extra_offset = ifd_offset + 2 + 12 * ifd.len() + 4 + extra_data.len();
+        }
+    }
+    writer.write_all(&[0u8; 4])?; // there is no next IFD
+    writer.write_all(&extra_data)?;
+
+    writer.flush()?;
 
     Ok(())
 }
 
+fn ifd_short(tag: u16, values: &[u16]) -> IfdDirectory {
+    let mut data = vec![];
+    for v in values {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    IfdDirectory::new(tag, DT_SHORT, values.len() as u32, 0u32, data, Endianness::LittleEndian)
+}
+
+fn ifd_long(tag: u16, values: &[u32]) -> IfdDirectory {
+    let mut data = vec![];
+    for v in values {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    IfdDirectory::new(tag, DT_LONG, values.len() as u32, 0u32, data, Endianness::LittleEndian)
+}
+
+fn ifd_double(tag: u16, values: &[f64]) -> IfdDirectory {
+    let mut data = vec![];
+    for v in values {
+        data.extend_from_slice(&v.to_bits().to_le_bytes());
+    }
+    IfdDirectory::new(tag, DT_DOUBLE, values.len() as u32, 0u32, data, Endianness::LittleEndian)
+}
+
+/// ASCII values are NUL-terminated.
+fn ifd_ascii(tag: u16, value: &str) -> IfdDirectory {
+    let mut data = value.as_bytes().to_vec();
+    data.push(0u8);
+    IfdDirectory::new(tag, DT_ASCII, data.len() as u32, 0u32, data, Endianness::LittleEndian)
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct IfdDirectory {
     pub tag: u16,
@@ -16,27 +16,27 @@ pub fn read_grass_raster(file_name: &String, configs: &mut RasterConfigs, data:
     let mut null_str = String::from("");
     let mut null_is_str = false;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         let line_split = line_unwrapped.split(":");
         let vec = line_split.collect::<Vec<&str>>();
         if vec[0].to_lowercase().contains("rows") {
-            configs.rows = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.rows = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("cols") {
-            configs.columns = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("north") {
-            configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.north = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("south") {
-            configs.south = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.south = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("east") {
-            configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.east = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("west") {
-            configs.west = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.west = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("cellsize") {
-            configs.resolution_x = vec[1].trim().to_string().parse::<f64>().unwrap();
-            configs.resolution_y = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.resolution_x = parse_field::<f64>(vec[0], vec[1])?;
+            configs.resolution_y = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("null") {
             if is_number(vec[1].trim().to_string()) {
-                configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
+                configs.nodata = parse_field::<f64>(vec[0], vec[1])?;
                 if vec[1].contains(".") {
                     //likely_float = true;
                     configs.data_type = DataType::F32;
@@ -58,14 +58,14 @@ pub fn read_grass_raster(file_name: &String, configs: &mut RasterConfigs, data:
             } else {
                 configs.data_type = DataType::I32;
             }
-            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.nodata = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("multiplier") {
-            multiplier = vec[1].trim().to_string().parse::<f64>().unwrap();
+            multiplier = parse_field::<f64>(vec[0], vec[1])?;
         } else { // it's a data line
             if !null_is_str {
                 let mut val_num;
                 for val in vec {
-                    val_num = val.trim().to_string().parse::<f64>().unwrap();
+                    val_num = parse_field::<f64>("data", val)?;
                     if val_num != configs.nodata {
                         data.push(val_num * multiplier);
                     } else {
@@ -77,7 +77,7 @@ pub fn read_grass_raster(file_name: &String, configs: &mut RasterConfigs, data:
                 for val in vec {
                     val_string = val.trim().to_string();
                     if val_string != null_str {
-                        data.push(val.trim().to_string().parse::<f64>().unwrap() * multiplier);
+                        data.push(parse_field::<f64>("data", val)? * multiplier);
                     } else {
                         data.push(configs.nodata);
                     }
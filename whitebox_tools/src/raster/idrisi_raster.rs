@@ -16,30 +16,30 @@ pub fn read_idrisi(file_name: &String, configs: &mut RasterConfigs, data: &mut V
     let f = BufReader::new(f);
 
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         configs.photometric_interp = PhotometricInterpretation::Continuous;
         let line_split = line_unwrapped.split(":");
         let vec = line_split.collect::<Vec<&str>>();
         if vec[0].to_lowercase().contains("min. value") && !vec[0].to_lowercase().contains("lineage") {
-            configs.minimum = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.minimum = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("max. value") && !vec[0].to_lowercase().contains("lineage") {
-            configs.maximum = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.maximum = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("display min") && !vec[0].to_lowercase().contains("lineage") {
-            configs.display_min = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.display_min = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("display max") && !vec[0].to_lowercase().contains("lineage") {
-            configs.display_max = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.display_max = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("max. y") && !vec[0].to_lowercase().contains("lineage") {
-            configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.north = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("min. y") && !vec[0].to_lowercase().contains("lineage") {
-            configs.south = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.south = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("max. x") && !vec[0].to_lowercase().contains("lineage") {
-            configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.east = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("min. x") && !vec[0].to_lowercase().contains("lineage") {
-            configs.west = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.west = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("columns") && !vec[0].to_lowercase().contains("lineage") {
-            configs.columns = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("rows") && !vec[0].to_lowercase().contains("lineage") {
-            configs.rows = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.rows = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("data type") && !vec[0].to_lowercase().contains("lineage") {
             if vec[1].trim().to_lowercase().to_string().contains("real") {
                 configs.data_type = DataType::F32;
@@ -154,7 +154,7 @@ pub fn write_idrisi<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
         let v = *val;
-        if v != r.configs.nodata {
+        if !r.is_nodata(v) {
             if v < r.configs.minimum { r.configs.minimum = v; }
             if v > r.configs.maximum { r.configs.maximum = v; }
         }
@@ -2,9 +2,14 @@
 
 pub mod arcascii_raster;
 pub mod arcbinary_raster;
+pub mod filter;
 pub mod geotiff;
 pub mod grass_raster;
 pub mod idrisi_raster;
+pub mod multiband;
+pub mod neighborhood;
+pub mod resample;
+pub mod rgb_image;
 pub mod saga_raster;
 pub mod surfer7_raster;
 pub mod surfer_ascii_raster;
@@ -18,6 +23,7 @@ use std::io::BufReader;
 use std::default::Default;
 use std::fs::File;
 use std::path::Path;
+use std::str::FromStr;
 use std::f64;
 use raster::arcascii_raster::*;
 use raster::arcbinary_raster::*;
@@ -29,6 +35,7 @@ use raster::surfer7_raster::*;
 use raster::surfer_ascii_raster::*;
 use raster::whitebox_raster::*;
 use io_utils::byte_order_reader::*;
+use raster::resample::{sample, Resampling};
 use std::ops::{Index, IndexMut};
 
 #[derive(Default, Clone)]
@@ -85,45 +92,43 @@ impl Raster {
             ..Default::default()
         };
         if r.file_mode == "r" {
+            if !Path::new(file_name).exists() {
+                return Err(Error::new(ErrorKind::NotFound, format!("The raster file {} does not exist; check the file name and working directory.", file_name)));
+            }
             match get_raster_type_from_file(file_name.to_string(), fm) {
                 RasterType::ArcBinary => {
-                    let _ = read_arcbinary(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_arcbinary(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::ArcAscii => {
-                    let _ = read_arcascii(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_arcascii(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::GeoTiff => {
-                    let _ = read_geotiff(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_geotiff(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::GrassAscii => {
-                    let _ = read_grass_raster(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_grass_raster(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::IdrisiBinary => {
-                    let _ = read_idrisi(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_idrisi(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::SagaBinary => {
-                    let _ = read_saga(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_saga(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::Surfer7Binary => {
-                    let _ = read_surfer7(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_surfer7(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::SurferAscii => {
-                    let _ = read_surfer_ascii_raster(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_surfer_ascii_raster(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
                 RasterType::Whitebox => {
-                    let _ = read_whitebox(&r.file_name, &mut r.configs, &mut r.data).unwrap();
-                    return Ok(r);
+                    read_whitebox(&r.file_name, &mut r.configs, &mut r.data).map_err(|e| read_error(file_name, e))?;
                 },
-                RasterType::Unknown => { return Err(Error::new(ErrorKind::Other, "Unrecognized raster type")); },
+                RasterType::Unknown => { return Err(Error::new(ErrorKind::Other, format!("The raster file {} is of an unrecognized type.", file_name))); },
+            }
+            if r.configs.rows == 0 || r.configs.columns == 0 {
+                return Err(Error::new(ErrorKind::InvalidData, format!("The header of raster file {} does not give the grid's rows and columns.", file_name)));
             }
+            return Ok(r);
         } else { // write
 
         }
@@ -175,6 +180,61 @@ impl Raster {
         output
     }
 
+    /// Like `initialize_using_file`, but the output grid has the given dimensions, north-west
+    /// corner and resolution rather than those of the input.
+    pub fn initialize_using_extent<'a>(file_name: &'a str, input: &'a Raster, rows: usize, columns: usize,
+        north: f64, west: f64, resolution_x: f64, resolution_y: f64) -> Raster {
+        let mut output = Raster { file_name: file_name.to_string(), ..Default::default() };
+        output.file_mode = "w".to_string();
+        output.raster_type = get_raster_type_from_file(file_name.to_string(), "w".to_string());
+        output.configs.rows = rows;
+        output.configs.columns = columns;
+        output.configs.north = north;
+        output.configs.south = north - rows as f64 * resolution_y;
+        output.configs.east = west + columns as f64 * resolution_x;
+        output.configs.west = west;
+        output.configs.resolution_x = resolution_x;
+        output.configs.resolution_y = resolution_y;
+        output.configs.nodata = input.configs.nodata;
+        output.configs.data_type = input.configs.data_type;
+        output.configs.photometric_interp = input.configs.photometric_interp;
+        output.configs.palette = input.configs.palette.clone();
+        output.configs.projection = input.configs.projection.clone();
+        output.configs.xy_units = input.configs.xy_units.clone();
+        output.configs.z_units = input.configs.z_units.clone();
+        output.configs.endian = input.configs.endian.clone();
+        output.configs.palette_nonlinearity = input.configs.palette_nonlinearity;
+        output.configs.pixel_is_area = input.configs.pixel_is_area;
+        output.configs.epsg_code = input.configs.epsg_code;
+        output.configs.coordinate_ref_system_wkt = input.configs.coordinate_ref_system_wkt.clone();
+
+        if output.raster_type == RasterType::SurferAscii ||
+            output.raster_type == RasterType::Surfer7Binary {
+            output.configs.nodata = 1.71041e38;
+        }
+
+        output.data = vec![output.configs.nodata; output.configs.rows * output.configs.columns];
+
+        output
+    }
+
+    /// Creates an in-memory raster from a row-major grid of values, e.g. the result of a
+    /// calculation in another numerical library. The data must hold exactly rows x columns
+    /// values. The raster has no file name; call `set_file_name` before writing it.
+    pub fn from_data(configs: RasterConfigs, data: Vec<f64>) -> Result<Raster, Error> {
+        if data.len() != configs.rows * configs.columns {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("The data contain {} values but the raster has {} rows and {} columns.",
+                data.len(), configs.rows, configs.columns)));
+        }
+        Ok(Raster { file_mode: "w".to_string(), configs: configs, data: data, ..Default::default() })
+    }
+
+    pub fn set_file_name<'a>(&mut self, file_name: &'a str) {
+        self.file_name = file_name.to_string();
+        self.file_mode = "w".to_string();
+        self.raster_type = get_raster_type_from_file(file_name.to_string(), "w".to_string());
+    }
+
     pub fn get_value(&self, row: isize, column: isize) -> f64 {
         if column < 0 { return self.configs.nodata; }
         if row < 0 { return self.configs.nodata; }
@@ -188,6 +248,55 @@ impl Raster {
         self.data[idx]
     }
 
+    /// Returns true if `value` is the raster's nodata value, or is 0 when zero is treated as
+    /// nodata (see `RasterConfigs::zero_is_nodata`). Use this rather than comparing with
+    /// `configs.nodata` directly: a NaN nodata value is never equal to anything, itself included,
+    /// but here it matches any NaN value.
+    pub fn is_nodata(&self, value: f64) -> bool {
+        let nodata = self.configs.nodata;
+        value == nodata || (value.is_nan() && nodata.is_nan()) || (self.configs.zero_is_nodata && value == 0f64)
+    }
+
+    /// Returns the value at the world coordinate (x, y), estimated with the given interpolation
+    /// method from the cell centres around it (see `resample::sample`). Returns nodata if
+    /// the point lies outside the grid or any contributing cell is nodata.
+    pub fn value_at(&self, x: f64, y: f64, interp: Resampling) -> f64 {
+        if x < self.configs.west || x > self.configs.east || y < self.configs.south || y > self.configs.north {
+            return self.configs.nodata;
+        }
+        let row = (self.configs.north - y) / self.configs.resolution_y - 0.5;
+        let column = (x - self.configs.west) / self.configs.resolution_x - 0.5;
+        sample(self, row, column, interp)
+    }
+
+    /// Returns the grid's bounding box as (min_x, min_y, max_x, max_y), i.e. the outer edges
+    /// of the edge cells.
+    pub fn extent(&self) -> (f64, f64, f64, f64) {
+        (self.configs.west, self.configs.south, self.configs.east, self.configs.north)
+    }
+
+    /// Returns the x coordinate of the centre of cells in the given column.
+    pub fn get_x_from_column(&self, column: isize) -> f64 {
+        self.configs.west + (column as f64 + 0.5) * self.configs.resolution_x
+    }
+
+    /// Returns the y coordinate of the centre of cells in the given row.
+    pub fn get_y_from_row(&self, row: isize) -> f64 {
+        self.configs.north - (row as f64 + 0.5) * self.configs.resolution_y
+    }
+
+    /// Returns the column of the cells containing the x coordinate. Coordinates beyond the
+    /// grid give columns outside of 0..columns, which index as nodata.
+    pub fn get_column_from_x(&self, x: f64) -> isize {
+        ((x - self.configs.west) / self.configs.resolution_x).floor() as isize
+    }
+
+    /// Returns the row of the cells containing the y coordinate. Coordinates beyond the grid
+    /// give rows outside of 0..rows, which index as nodata.
+    pub fn get_row_from_y(&self, y: f64) -> isize {
+        ((self.configs.north - y) / self.configs.resolution_y).floor() as isize
+    }
+
     pub fn set_value(&mut self, row: isize, column: isize, value: f64) {
         if column >= 0 && row >= 0 {
             let c: usize = column as usize;
@@ -199,7 +308,195 @@ impl Raster {
         }
     }
 
+    /// Sets the data type in which the raster is written. If the type can't hold the nodata
+    /// value, e.g. a float raster's -3.4e38 or NaN in an I16 raster, the writer would corrupt
+    /// it, so the nodata value and the nodata cells are changed to the type's default (see
+    /// `DataType::default_nodata`). Surfer rasters keep their fixed nodata value.
+    pub fn set_data_type(&mut self, data_type: DataType) {
+        self.configs.data_type = data_type;
+        if data_type.can_hold(self.configs.nodata) ||
+            self.raster_type == RasterType::SurferAscii || self.raster_type == RasterType::Surfer7Binary {
+            return;
+        }
+        let new_nodata = data_type.default_nodata();
+        for i in 0..self.data.len() {
+            if self.is_nodata(self.data[i]) {
+                self.data[i] = new_nodata;
+            }
+        }
+        self.configs.nodata = new_nodata;
+    }
+
+    /// Returns a copy of the values in the given row, or an empty vector if the row is beyond
+    /// the grid.
+    pub fn get_row_data(&self, row: isize) -> Vec<f64> {
+        if row < 0 || row as usize >= self.configs.rows {
+            return vec![];
+        }
+        let start = row as usize * self.configs.columns;
+        self.data[start..start + self.configs.columns].to_vec()
+    }
+
+    /// Replaces the values in the given row; like `set_value`, rows beyond the grid are
+    /// ignored. Panics if `data` doesn't hold one value per column.
+    pub fn set_row_data(&mut self, row: isize, data: &[f64]) {
+        if row < 0 || row as usize >= self.configs.rows {
+            return;
+        }
+        let start = row as usize * self.configs.columns;
+        self.data[start..start + self.configs.columns].copy_from_slice(data);
+    }
+
+    /// Copies the `num_rows` x `num_cols` block of cells starting at (`start_row`, `start_col`)
+    /// into a new raster, with its edges shifted so that the cells keep their world coordinates.
+    /// The block is clamped to the grid, i.e. the parts of it beyond the edges are dropped,
+    /// and a block lying entirely beyond the grid gives a raster with no rows or columns. The
+    /// subset shares the file name of this raster; call `set_file_name` before writing it.
+    pub fn subset(&self, start_row: isize, start_col: isize, num_rows: usize, num_cols: usize) -> Raster {
+        let rows = self.configs.rows as isize;
+        let columns = self.configs.columns as isize;
+        let row_from = start_row.max(0).min(rows);
+        let row_to = (start_row + num_rows as isize).max(row_from).min(rows);
+        let col_from = start_col.max(0).min(columns);
+        let col_to = (start_col + num_cols as isize).max(col_from).min(columns);
+        let res_x = self.configs.resolution_x;
+        let res_y = self.configs.resolution_y;
+        let mut output = Raster::initialize_using_extent(&self.file_name, self, (row_to - row_from) as usize,
+            (col_to - col_from) as usize, self.configs.north - row_from as f64 * res_y,
+            self.configs.west + col_from as f64 * res_x, res_x, res_y);
+        output.configs.nodata = self.configs.nodata;
+        for row in row_from..row_to {
+            let start = (row * columns + col_from) as usize;
+            output.set_row_data(row - row_from, &self.data[start..start + (col_to - col_from) as usize]);
+        }
+        output
+    }
+
+    /// Returns a copy of the raster with the order of its rows reversed, e.g. for grids stored
+    /// south-up. The grid keeps its extent; only the cells are moved.
+    pub fn flip_vertical(&self) -> Raster {
+        let rows = self.configs.rows;
+        let columns = self.configs.columns;
+        let mut output = self.reoriented(rows, columns, self.configs.resolution_x, self.configs.resolution_y);
+        for row in 0..rows {
+            let start = (rows - 1 - row) * columns;
+            output.set_row_data(row as isize, &self.data[start..start + columns]);
+        }
+        output
+    }
+
+    /// Returns a copy of the raster with the order of its columns reversed. The grid keeps its
+    /// extent; only the cells are moved.
+    pub fn flip_horizontal(&self) -> Raster {
+        let rows = self.configs.rows;
+        let columns = self.configs.columns;
+        let mut output = self.reoriented(rows, columns, self.configs.resolution_x, self.configs.resolution_y);
+        for row in 0..rows {
+            for col in 0..columns {
+                output.data[row * columns + col] = self.data[row * columns + columns - 1 - col];
+            }
+        }
+        output
+    }
+
+    /// Returns a copy of the raster rotated by 90 degrees, clockwise or counter-clockwise. The
+    /// rows and columns, and the x and y resolutions, are swapped and the grid is anchored at
+    /// the north-west corner of the original, i.e. its north and west edges are unchanged and
+    /// its south and east edges follow from the new dimensions.
+    pub fn rotate_90(&self, clockwise: bool) -> Raster {
+        let rows = self.configs.rows;
+        let columns = self.configs.columns;
+        let mut output = self.reoriented(columns, rows, self.configs.resolution_y, self.configs.resolution_x);
+        for row in 0..rows {
+            for col in 0..columns {
+                // The output has `rows` columns.
+                let idx = if clockwise {
+                    col * rows + rows - 1 - row
+                } else {
+                    (columns - 1 - col) * rows + row
+                };
+                output.data[idx] = self.data[row * columns + col];
+            }
+        }
+        output
+    }
+
+    // Creates a raster like this one with the given dimensions, anchored at its north-west corner.
+    fn reoriented(&self, rows: usize, columns: usize, resolution_x: f64, resolution_y: f64) -> Raster {
+        let mut output = Raster::initialize_using_extent(&self.file_name, self, rows, columns,
+            self.configs.north, self.configs.west, resolution_x, resolution_y);
+        output.configs.nodata = self.configs.nodata;
+        output
+    }
+
+    /// Returns the grid values in row-major order.
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns an iterator over the (row, column, value) of each grid cell that is not nodata
+    /// (see `is_nodata`).
+    pub fn iter_valid<'a>(&'a self) -> impl Iterator<Item=(isize, isize, f64)> + 'a {
+        let columns = self.configs.columns;
+        self.data.iter()
+            .enumerate()
+            .filter(move |&(_, v)| !self.is_nodata(*v))
+            .map(move |(i, v)| ((i / columns) as isize, (i % columns) as isize, *v))
+    }
+
+    /// Returns the world coordinates of the centre and the value of each valid cell, in
+    /// row-major order.
+    pub fn to_xyz(&self) -> Vec<(f64, f64, f64)> {
+        self.iter_valid()
+            .map(|(row, col, z)| (self.get_x_from_column(col), self.get_y_from_row(row), z))
+            .collect()
+    }
+
+    /// Returns the minimum and maximum of the valid cells, or (infinity, negative infinity)
+    /// if there are none.
+    pub fn min_max(&self) -> (f64, f64) {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for (_, _, z) in self.iter_valid() {
+            if z < min { min = z; }
+            if z > max { max = z; }
+        }
+        (min, max)
+    }
+
+    /// Counts the valid cells in `num_bins` equal-width bins spanning the range of the data.
+    /// Returns the data minimum, the bin width and the bin counts; a raster with no valid cells
+    /// has an empty histogram.
+    pub fn histogram(&self, num_bins: usize) -> (f64, f64, Vec<usize>) {
+        let (min, max) = self.min_max();
+        let mut counts = vec![0usize; num_bins];
+        if num_bins == 0 || min > max {
+            return (min, 0f64, counts);
+        }
+        let bin_width = (max - min) / num_bins as f64;
+        let mut bin: usize;
+        for (_, _, z) in self.iter_valid() {
+            bin = if bin_width > 0f64 { ((z - min) / bin_width).floor() as usize } else { 0 };
+            if bin >= num_bins { bin = num_bins - 1; }
+            counts[bin] += 1;
+        }
+        (min, bin_width, counts)
+    }
+
+    /// Writes the raster with the given compression of its cell data. Compressed rasters are
+    /// decompressed transparently by `Raster::new`.
+    pub fn write_compressed(&mut self, compression: Compression) -> Result<(), Error> {
+        self.configs.compression = compression;
+        self.write()
+    }
+
     pub fn write(&mut self) -> Result<(), Error> {
+        match self.configs.compression {
+            Compression::Deflate | Compression::Lzw if self.raster_type != RasterType::GeoTiff => {
+                return Err(Error::new(ErrorKind::InvalidInput, format!("{:?} compression is only supported for GeoTIFF output, not {}.", self.configs.compression, self.file_name)));
+            },
+            _ => {},
+        }
         match self.raster_type {
             RasterType::ArcAscii => {
                 let _ = match write_arcascii(self) {
@@ -287,6 +584,61 @@ impl Raster {
     }
 }
 
+// Adds the name of the raster file to an error raised while reading it.
+fn read_error(file_name: &str, e: Error) -> Error {
+    Error::new(e.kind(), format!("Error reading raster file {}: {}", file_name, e))
+}
+
+/// Parses a header field or data value read from a raster file, returning an `InvalidData`
+/// error that names the field and the offending text instead of panicking.
+pub fn parse_field<T: FromStr>(field: &str, value: &str) -> Result<T, Error> {
+    value.trim().parse::<T>().map_err(|_| {
+        Error::new(ErrorKind::InvalidData, format!("Could not parse the {} value '{}'.", field.trim(), value.trim()))
+    })
+}
+
+/// Compares two rasters cell by cell, e.g. a tool's output against a reference file. The
+/// rasters must share dimensions and, within a thousandth of a cell, georeferencing; cells are
+/// equal if both are nodata (each according to its own nodata value) or if they differ by no
+/// more than `tolerance`. The error message describes the first difference found.
+pub fn rasters_equal(a: &Raster, b: &Raster, tolerance: f64) -> Result<(), String> {
+    if a.configs.rows != b.configs.rows || a.configs.columns != b.configs.columns {
+        return Err(format!("Dimensions differ: {} x {} vs. {} x {} (rows x columns).",
+            a.configs.rows, a.configs.columns, b.configs.rows, b.configs.columns));
+    }
+    let tol_x = a.configs.resolution_x * 0.001;
+    let tol_y = a.configs.resolution_y * 0.001;
+    if (a.configs.resolution_x - b.configs.resolution_x).abs() > tol_x ||
+        (a.configs.resolution_y - b.configs.resolution_y).abs() > tol_y {
+        return Err(format!("Cell sizes differ: {} x {} vs. {} x {}.",
+            a.configs.resolution_x, a.configs.resolution_y, b.configs.resolution_x, b.configs.resolution_y));
+    }
+    if (a.configs.west - b.configs.west).abs() > tol_x || (a.configs.north - b.configs.north).abs() > tol_y {
+        return Err(format!("Origins differ: ({}, {}) vs. ({}, {}) (west, north).",
+            a.configs.west, a.configs.north, b.configs.west, b.configs.north));
+    }
+
+    let (mut z_a, mut z_b): (f64, f64);
+    for row in 0..a.configs.rows as isize {
+        for col in 0..a.configs.columns as isize {
+            z_a = a[(row, col)];
+            z_b = b[(row, col)];
+            let nodata_a = a.is_nodata(z_a);
+            let nodata_b = b.is_nodata(z_b);
+            if nodata_a && nodata_b {
+                continue;
+            }
+            if nodata_a != nodata_b || (z_a - z_b).abs() > tolerance {
+                return Err(format!("Cell (row {}, column {}) differs: {} vs. {}.", row, col,
+                    if nodata_a { "nodata".to_string() } else { z_a.to_string() },
+                    if nodata_b { "nodata".to_string() } else { z_b.to_string() }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct RasterConfigs {
     pub title: String,
@@ -313,9 +665,13 @@ pub struct RasterConfigs {
     pub z_units: String,
     pub xy_units: String,
     pub reflect_at_edges: bool,
+    /// Whether zero-valued cells are treated as nodata by `Raster::is_nodata`, for rasters that
+    /// use 0 rather than a declared nodata value. This is a processing option and is not saved.
+    pub zero_is_nodata: bool,
 	pub pixel_is_area: bool,
 	pub epsg_code: u16,
     pub coordinate_ref_system_wkt: String,
+    pub compression: Compression,
     pub metadata: Vec<String>,
 }
 
@@ -346,9 +702,11 @@ impl Default for RasterConfigs {
             z_units: "not specified".to_string(),
             xy_units: "not specified".to_string(),
             reflect_at_edges: false,
+            zero_is_nodata: false,
             pixel_is_area: true,
             epsg_code: 0u16,
             coordinate_ref_system_wkt: "not specified".to_string(),
+            compression: Compression::None,
             metadata: vec![],
         }
     }
@@ -402,6 +760,36 @@ impl DataType {
             DataType::Unknown => 0usize,
         }
     }
+
+    /// Returns true if a value of this type can represent `value` exactly; the float types
+    /// also hold NaN and the infinities.
+    pub fn can_hold(&self, value: f64) -> bool {
+        let (min, max) = match *self {
+            DataType::F64 => return true,
+            DataType::F32 => return !value.is_finite() || value.abs() <= f32::MAX as f64,
+            DataType::I64 => (i64::min_value() as f64, i64::max_value() as f64),
+            DataType::I32 => (i32::min_value() as f64, i32::max_value() as f64),
+            DataType::I16 => (i16::min_value() as f64, i16::max_value() as f64),
+            DataType::I8 => (i8::min_value() as f64, i8::max_value() as f64),
+            DataType::U64 => (0f64, u64::max_value() as f64),
+            DataType::U32 => (0f64, u32::max_value() as f64),
+            DataType::U16 | DataType::RGB48 => (0f64, u16::max_value() as f64),
+            DataType::U8 | DataType::RGB24 | DataType::RGBA32 => (0f64, u8::max_value() as f64),
+            DataType::Unknown => return true,
+        };
+        value.fract() == 0f64 && value >= min && value <= max
+    }
+
+    /// The nodata value used for this type when a raster's own can't be held: -32768 where it
+    /// fits, otherwise the type's most negative value, or its maximum for unsigned types.
+    pub fn default_nodata(&self) -> f64 {
+        match *self {
+            DataType::I8 => i8::min_value() as f64,
+            DataType::U64 | DataType::U32 | DataType::U16 | DataType::RGB48 => u16::max_value() as f64,
+            DataType::U8 | DataType::RGB24 | DataType::RGBA32 => u8::max_value() as f64,
+            _ => -32768f64,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -420,9 +808,42 @@ impl Default for PhotometricInterpretation {
     fn default() -> PhotometricInterpretation { PhotometricInterpretation::Unknown }
 }
 
+/// Compression applied to the cell data when a raster is written. The Whitebox format supports
+/// RLE, which other formats ignore, and only GeoTIFF supports DEFLATE and LZW.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Compression {
+    None,
+    /// Run-length encoding, storing each run of equal values as a count and a single value.
+    /// Very effective for masked rasters with large nodata areas and for categorical data.
+    Rle,
+    /// zlib-wrapped DEFLATE, applied after a horizontal differencing predictor.
+    Deflate,
+    /// Lempel-Ziv-Welch, applied after a horizontal differencing predictor.
+    Lzw,
+}
+
+impl Default for Compression {
+    fn default() -> Compression { Compression::None }
+}
+
+impl FromStr for Compression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Compression, Error> {
+        match s.trim().to_lowercase().as_ref() {
+            "none" => Ok(Compression::None),
+            "rle" => Ok(Compression::Rle),
+            "deflate" | "zlib" => Ok(Compression::Deflate),
+            "lzw" => Ok(Compression::Lzw),
+            _ => Err(Error::new(ErrorKind::InvalidInput, format!("Unrecognized compression '{}'; use none, rle, deflate or lzw.", s))),
+        }
+    }
+}
+
 fn get_raster_type_from_file(file_name: String, file_mode: String) -> RasterType {
     // get the file extension
-    let extension: String = match Path::new(&file_name).extension().unwrap().to_str() {
+    // in-memory rasters, e.g. from Raster::from_data, have no file name and so no extension
+    let extension: String = match Path::new(&file_name).extension().and_then(|e| e.to_str()) {
         Some(n) => n.to_string().to_lowercase(),
         None => "".to_string(),
     };
@@ -431,7 +852,7 @@ fn get_raster_type_from_file(file_name: String, file_mode: String) -> RasterType
         return RasterType::Whitebox;
     } else if extension == "tif" || extension == "tiff" {
         return RasterType::GeoTiff;
-    } else if extension == "flt" {
+    } else if extension == "flt" || extension == "hdr" {
         return RasterType::ArcBinary;
     } else if extension == "rdc" || extension == "rst" {
         return RasterType::IdrisiBinary;
@@ -520,3 +941,93 @@ fn get_raster_type_from_file(file_name: String, file_mode: String) -> RasterType
 //     }
 //
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3 x 4 raster of unit cells whose north-west corner is at (0, 3), holding `data`.
+    fn raster(data: Vec<f64>, nodata: f64) -> Raster {
+        let configs = RasterConfigs {
+            rows: 3,
+            columns: 4,
+            north: 3f64,
+            south: 0f64,
+            east: 4f64,
+            west: 0f64,
+            resolution_x: 1f64,
+            resolution_y: 1f64,
+            nodata: nodata,
+            ..Default::default()
+        };
+        Raster::from_data(configs, data).unwrap()
+    }
+
+    fn ramp() -> Vec<f64> {
+        (0..12).map(|i| i as f64).collect()
+    }
+
+    #[test]
+    fn identical_rasters_are_equal() {
+        assert_eq!(rasters_equal(&raster(ramp(), -32768f64), &raster(ramp(), -32768f64), 0f64), Ok(()));
+    }
+
+    #[test]
+    fn values_are_compared_within_the_tolerance() {
+        let mut data = ramp();
+        data[6] += 0.01;
+        let (a, b) = (raster(ramp(), -32768f64), raster(data, -32768f64));
+        assert_eq!(rasters_equal(&a, &b, 0.1), Ok(()));
+        let err = rasters_equal(&a, &b, 0.001).unwrap_err();
+        assert!(err.contains("row 1, column 2"), "{}", err);
+    }
+
+    #[test]
+    fn nodata_cells_match_regardless_of_the_nodata_value() {
+        let (mut data_a, mut data_b) = (ramp(), ramp());
+        data_a[3] = -32768f64;
+        data_b[3] = -9999f64;
+        assert_eq!(rasters_equal(&raster(data_a, -32768f64), &raster(data_b, -9999f64), 0f64), Ok(()));
+    }
+
+    #[test]
+    fn nodata_does_not_match_a_value() {
+        let mut data = ramp();
+        data[0] = -32768f64;
+        let err = rasters_equal(&raster(data, -32768f64), &raster(ramp(), -32768f64), 1e6).unwrap_err();
+        assert!(err.contains("nodata vs. 0"), "{}", err);
+    }
+
+    #[test]
+    fn grids_must_match() {
+        let a = raster(ramp(), -32768f64);
+        let mut b = raster(ramp(), -32768f64);
+        b.configs.west += 0.0001;
+        assert_eq!(rasters_equal(&a, &b, 0f64), Ok(()));
+        b.configs.west += 0.5;
+        assert!(rasters_equal(&a, &b, 0f64).unwrap_err().starts_with("Origins differ"));
+
+        let mut c = raster(ramp(), -32768f64);
+        c.configs.rows = 4;
+        assert!(rasters_equal(&a, &c, 0f64).unwrap_err().starts_with("Dimensions differ"));
+    }
+
+    #[test]
+    fn set_data_type_replaces_nodata_the_type_cannot_hold() {
+        let mut data = ramp();
+        data[5] = -3.4e38;
+        let mut r = raster(data, -3.4e38);
+        r.set_data_type(DataType::I16);
+        assert_eq!(r.configs.nodata, -32768f64);
+        assert_eq!(r[(1, 1)], -32768f64);
+        assert_eq!(r[(1, 2)], 6f64);
+
+        let mut r = raster(ramp(), f64::NAN);
+        r.set_data_type(DataType::I16);
+        assert_eq!(r.configs.nodata, -32768f64);
+
+        let mut r = raster(ramp(), -9999f64);
+        r.set_data_type(DataType::I16);
+        assert_eq!(r.configs.nodata, -9999f64);
+    }
+}
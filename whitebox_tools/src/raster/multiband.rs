@@ -0,0 +1,103 @@
+use std::io::{Error, ErrorKind};
+use std::f64;
+use std::path::Path;
+use raster::*;
+use raster::whitebox_raster::*;
+
+/// A stack of co-registered raster bands sharing one grid, e.g. the bands of a multispectral
+/// image. The bands are stored as a Whitebox raster with a `Stacks` count greater than one,
+/// band after band in the data file; reading such a file with `Raster::new` returns its first
+/// band.
+#[derive(Default, Clone)]
+pub struct MultiBandRaster {
+    pub file_name: String,
+    pub configs: RasterConfigs,
+    bands: Vec<Raster>,
+}
+
+impl MultiBandRaster {
+    /// Reads every band of a Whitebox raster file.
+    pub fn new<'a>(file_name: &'a str) -> Result<MultiBandRaster, Error> {
+        if !Path::new(file_name).exists() {
+            return Err(Error::new(ErrorKind::NotFound, format!("The raster file {} does not exist; check the file name and working directory.", file_name)));
+        }
+        if get_raster_type_from_file(file_name.to_string(), "r".to_string()) != RasterType::Whitebox {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Multi-band rasters must be Whitebox (.dep) files; {} is not.", file_name)));
+        }
+        let mut configs = RasterConfigs { ..Default::default() };
+        let mut data = vec![];
+        read_whitebox_stack(&file_name.to_string(), &mut configs, &mut data).map_err(|e| read_error(file_name, e))?;
+        if configs.rows == 0 || configs.columns == 0 {
+            return Err(Error::new(ErrorKind::InvalidData, format!("The header of raster file {} does not give the grid's rows and columns.", file_name)));
+        }
+        let num_cells = configs.rows * configs.columns;
+        let num_bands = (configs.bands as usize).max(1);
+        let mut band_configs = configs.clone();
+        band_configs.bands = 1;
+        let mut bands = Vec::with_capacity(num_bands);
+        for b in 0..num_bands {
+            let mut band = Raster::initialize_using_config(file_name, &band_configs);
+            band.data = data[b * num_cells..(b + 1) * num_cells].to_vec();
+            bands.push(band);
+        }
+        Ok(MultiBandRaster { file_name: file_name.to_string(), configs: configs, bands: bands })
+    }
+
+    /// Creates a multi-band raster from bands that are known to share a grid, taking the grid,
+    /// data type and nodata value from the first band. Band cells equal to their own nodata
+    /// value are converted to the stack's nodata value.
+    pub fn from_bands<'a>(file_name: &'a str, bands: Vec<Raster>) -> MultiBandRaster {
+        let mut configs = bands[0].configs.clone();
+        configs.bands = bands.len() as u8;
+        configs.metadata = vec![];
+        configs.minimum = f64::INFINITY;
+        configs.maximum = f64::NEG_INFINITY;
+        configs.display_min = f64::INFINITY;
+        configs.display_max = f64::NEG_INFINITY;
+        let nodata = configs.nodata;
+        let mut stack = MultiBandRaster { file_name: file_name.to_string(), configs: configs, bands: bands };
+        for band in &mut stack.bands {
+            if !band.is_nodata(nodata) {
+                for i in 0..band.data.len() {
+                    if band.is_nodata(band.data[i]) { band.data[i] = nodata; }
+                }
+                band.configs.nodata = nodata;
+            }
+        }
+        stack
+    }
+
+    pub fn num_bands(&self) -> usize {
+        self.bands.len()
+    }
+
+    /// Returns band `b`, counting from zero.
+    pub fn band(&self, b: usize) -> &Raster {
+        &self.bands[b]
+    }
+
+    pub fn into_bands(self) -> Vec<Raster> {
+        self.bands
+    }
+
+    pub fn set_file_name<'a>(&mut self, file_name: &'a str) {
+        self.file_name = file_name.to_string();
+    }
+
+    pub fn add_metadata_entry(&mut self, value: String) {
+        self.configs.metadata.push(value);
+    }
+
+    pub fn write(&mut self) -> Result<(), Error> {
+        if get_raster_type_from_file(self.file_name.clone(), "w".to_string()) != RasterType::Whitebox {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Multi-band rasters can only be written as Whitebox (.dep) files; {} is not one.", self.file_name)));
+        }
+        let mut stacked = Raster::initialize_using_config(&self.file_name, &self.configs);
+        stacked.configs.bands = self.bands.len() as u8;
+        stacked.data = Vec::with_capacity(self.bands.len() * self.configs.rows * self.configs.columns);
+        for band in &self.bands {
+            stacked.data.extend_from_slice(&band.data);
+        }
+        write_whitebox(&mut stacked)
+    }
+}
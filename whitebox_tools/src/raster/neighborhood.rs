@@ -0,0 +1,63 @@
+use raster::Raster;
+
+/// Calls `f(row, col, window)` for every grid cell that is not nodata, where `window` holds the
+/// cell's 3x3 neighbourhood in row-major order, i.e. `window[4]` is the cell itself,
+/// `window[1]` its northern neighbour and `window[5]` its eastern neighbour. Neighbours that are
+/// nodata (see `Raster::is_nodata`) or beyond the edge of the grid are set to the raster's
+/// nodata value.
+///
+/// Interior cells are gathered directly from three row slices of the raster's data, without
+/// per-cell bounds checks; only the 1-cell border goes through the bounds-checked indexer.
+pub fn for_each_3x3<F>(input: &Raster, mut f: F) where F: FnMut(isize, isize, &[f64; 9]) {
+    let rows = input.configs.rows;
+    let columns = input.configs.columns;
+    let nodata = input.configs.nodata;
+    let zero_is_nodata = input.configs.zero_is_nodata;
+    let data = input.as_slice();
+    let mut window = [nodata; 9];
+    for row in 0..rows {
+        let interior_row = row > 0 && row + 1 < rows;
+        for col in 0..columns {
+            if input.is_nodata(data[row * columns + col]) {
+                continue;
+            }
+            if interior_row && col > 0 && col + 1 < columns {
+                let above = (row - 1) * columns + col - 1;
+                let middle = above + columns;
+                let below = middle + columns;
+                window[0..3].copy_from_slice(&data[above..above + 3]);
+                window[3..6].copy_from_slice(&data[middle..middle + 3]);
+                window[6..9].copy_from_slice(&data[below..below + 3]);
+            } else {
+                gather_checked(input, row as isize, col as isize, &mut window);
+            }
+            if zero_is_nodata {
+                for w in window.iter_mut() {
+                    if *w == 0f64 { *w = nodata; }
+                }
+            }
+            f(row as isize, col as isize, &window);
+        }
+    }
+}
+
+fn gather_checked(input: &Raster, row: isize, col: isize, window: &mut [f64; 9]) {
+    for i in 0..9 {
+        window[i] = input[(row + i as isize / 3 - 1, col + i as isize % 3 - 1)];
+    }
+}
+
+/// Returns the surface gradient (dz/dx, dz/dy) of a 3x3 window from `for_each_3x3` over `dem`
+/// using Horn's (1981) method, with x increasing to the east and y to the north. Nodata
+/// neighbours take the value of the centre cell.
+pub fn horn_gradient(window: &[f64; 9], dem: &Raster) -> (f64, f64) {
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    let mut w = [0f64; 9];
+    for i in 0..9 {
+        w[i] = if !dem.is_nodata(window[i]) { window[i] } else { window[4] };
+    }
+    let dz_dx = ((w[2] + 2f64 * w[5] + w[8]) - (w[0] + 2f64 * w[3] + w[6])) / (8f64 * res_x);
+    let dz_dy = ((w[0] + 2f64 * w[1] + w[2]) - (w[6] + 2f64 * w[7] + w[8])) / (8f64 * res_y);
+    (dz_dx, dz_dy)
+}
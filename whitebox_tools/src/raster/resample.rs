@@ -0,0 +1,109 @@
+use raster::Raster;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Resampling {
+    Nearest,
+    Bilinear,
+    CubicConvolution,
+}
+
+impl Default for Resampling {
+    fn default() -> Resampling { Resampling::Nearest }
+}
+
+impl Resampling {
+    pub fn from_str<'a>(val: &'a str) -> Resampling {
+        let val_lc: &str = &val.to_lowercase();
+        if val_lc.contains("bilin") {
+            return Resampling::Bilinear;
+        } else if val_lc.contains("cubic") {
+            return Resampling::CubicConvolution;
+        }
+        Resampling::Nearest
+    }
+}
+
+/// Estimates the value at a fractional (row, column) position, where whole numbers are cell
+/// centres. Positions within half a cell of the grid edge are clamped to the edge cells. Returns
+/// nodata if any of the cells used in the estimate are nodata, rather than blending the nodata
+/// value into the result. Cubic convolution is the exception: when any of its 16 cells is nodata
+/// it falls back to bilinear, and then to nearest-neighbour, interpolation.
+pub fn sample(input: &Raster, row: f64, column: f64, method: Resampling) -> f64 {
+    let nodata = input.configs.nodata;
+    let max_row = (input.configs.rows - 1) as f64;
+    let max_col = (input.configs.columns - 1) as f64;
+    if row < -0.5 || column < -0.5 || row > max_row + 0.5 || column > max_col + 0.5 {
+        return nodata;
+    }
+    let row = if row < 0f64 { 0f64 } else if row > max_row { max_row } else { row };
+    let column = if column < 0f64 { 0f64 } else if column > max_col { max_col } else { column };
+    match method {
+        Resampling::Nearest => {
+            input.get_value(row.round() as isize, column.round() as isize)
+        },
+        Resampling::Bilinear => {
+            let r0 = row.floor() as isize;
+            let c0 = column.floor() as isize;
+            let dr = row - r0 as f64;
+            let dc = column - c0 as f64;
+            // get_value returns nodata beyond the edge, but the weight there is always zero.
+            let r1 = if dr > 0f64 { r0 + 1 } else { r0 };
+            let c1 = if dc > 0f64 { c0 + 1 } else { c0 };
+            let z00 = input.get_value(r0, c0);
+            let z01 = input.get_value(r0, c1);
+            let z10 = input.get_value(r1, c0);
+            let z11 = input.get_value(r1, c1);
+            if input.is_nodata(z00) || input.is_nodata(z01) || input.is_nodata(z10) || input.is_nodata(z11) {
+                return nodata;
+            }
+            (z00 * (1f64 - dc) + z01 * dc) * (1f64 - dr) + (z10 * (1f64 - dc) + z11 * dc) * dr
+        },
+        Resampling::CubicConvolution => {
+            let r0 = row.floor() as isize;
+            let c0 = column.floor() as isize;
+            let dr = row - r0 as f64;
+            let dc = column - c0 as f64;
+            let last_row = input.configs.rows as isize - 1;
+            let last_col = input.configs.columns as isize - 1;
+            // Cells beyond the edge replicate the edge cells, so the window is always 4x4.
+            let clamp = |v: isize, max: isize| if v < 0 { 0 } else if v > max { max } else { v };
+            let mut z = 0f64;
+            let mut z_n: f64;
+            for i in -1..3isize {
+                let w_r = cubic_convolution_weight(i as f64 - dr);
+                for j in -1..3isize {
+                    z_n = input.get_value(clamp(r0 + i, last_row), clamp(c0 + j, last_col));
+                    if input.is_nodata(z_n) {
+                        z = sample(input, row, column, Resampling::Bilinear);
+                        if input.is_nodata(z) {
+                            z = sample(input, row, column, Resampling::Nearest);
+                        }
+                        return z;
+                    }
+                    z += z_n * w_r * cubic_convolution_weight(j as f64 - dc);
+                }
+            }
+            z
+        },
+    }
+}
+
+/// Interpolates between `z1` and `z2` at `t` (0 to 1) using the cubic convolution kernel, with
+/// `z0` and `z3` the equally spaced values on either side.
+pub fn cubic_convolution(z0: f64, z1: f64, z2: f64, z3: f64, t: f64) -> f64 {
+    z0 * cubic_convolution_weight(t + 1f64) + z1 * cubic_convolution_weight(t) +
+        z2 * cubic_convolution_weight(t - 1f64) + z3 * cubic_convolution_weight(t - 2f64)
+}
+
+/// Keys' cubic convolution kernel with a = -0.5 (Catmull-Rom). The weights of the four samples
+/// always sum to one.
+fn cubic_convolution_weight(x: f64) -> f64 {
+    let x = x.abs();
+    if x <= 1f64 {
+        (1.5f64 * x - 2.5f64) * x * x + 1f64
+    } else if x < 2f64 {
+        ((-0.5f64 * x + 2.5f64) * x - 4f64) * x + 2f64
+    } else {
+        0f64
+    }
+}
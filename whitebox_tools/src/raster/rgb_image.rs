@@ -0,0 +1,112 @@
+use std::io::Error;
+use std::io::BufWriter;
+use std::io::prelude::*;
+use std::fs::File;
+
+/// An 8-bit RGBA image, stored row by row from the top.
+#[derive(Default, Clone)]
+pub struct RgbImage {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<u8>,
+}
+
+impl RgbImage {
+    /// Creates a fully transparent image.
+    pub fn new(width: usize, height: usize) -> RgbImage {
+        RgbImage { width: width, height: height, data: vec![0u8; width * height * 4] }
+    }
+
+    pub fn set_pixel(&mut self, row: usize, column: usize, r: u8, g: u8, b: u8, a: u8) {
+        let idx = (row * self.width + column) * 4;
+        self.data[idx] = r;
+        self.data[idx + 1] = g;
+        self.data[idx + 2] = b;
+        self.data[idx + 3] = a;
+    }
+
+    /// Replaces every fully transparent pixel with an opaque background colour.
+    pub fn fill_transparent(&mut self, r: u8, g: u8, b: u8) {
+        for pixel in self.data.chunks_mut(4) {
+            if pixel[3] == 0 {
+                pixel[0] = r;
+                pixel[1] = g;
+                pixel[2] = b;
+                pixel[3] = 255;
+            }
+        }
+    }
+
+    /// Writes the image as an RGBA PNG. The image data are stored in uncompressed deflate
+    /// blocks, which every PNG reader accepts, so no compression library is needed.
+    pub fn write_png<'a>(&self, file_name: &'a str) -> Result<(), Error> {
+        let f = File::create(file_name)?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all(&[137, 80, 78, 71, 13, 10, 26, 10])?;
+
+        let mut ihdr = vec![];
+        ihdr.extend_from_slice(&u32_be(self.width as u32));
+        ihdr.extend_from_slice(&u32_be(self.height as u32));
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]); // bit depth, RGBA, compression, filter, interlace
+        write_chunk(&mut writer, b"IHDR", &ihdr)?;
+
+        // Each scanline is preceded by its filter type, which is always 0 (none).
+        let row_len = self.width * 4;
+        let mut raw = Vec::with_capacity(self.height * (row_len + 1));
+        for row in 0..self.height {
+            raw.push(0u8);
+            raw.extend_from_slice(&self.data[row * row_len..(row + 1) * row_len]);
+        }
+        let mut zlib = vec![0x78, 0x01];
+        let mut blocks = raw.chunks(65535).peekable();
+        if blocks.peek().is_none() {
+            zlib.extend_from_slice(&[1, 0, 0, 255, 255]);
+        }
+        while let Some(block) = blocks.next() {
+            zlib.push(if blocks.peek().is_none() { 1 } else { 0 });
+            let len = block.len() as u16;
+            zlib.extend_from_slice(&[(len & 0xFF) as u8, (len >> 8) as u8, (!len & 0xFF) as u8, (!len >> 8) as u8]);
+            zlib.extend_from_slice(block);
+        }
+        zlib.extend_from_slice(&u32_be(adler32(&raw)));
+        write_chunk(&mut writer, b"IDAT", &zlib)?;
+        write_chunk(&mut writer, b"IEND", &[])?;
+
+        let _ = writer.flush();
+
+        Ok(())
+    }
+}
+
+fn write_chunk<W: Write>(writer: &mut W, chunk_type: &[u8; 4], data: &[u8]) -> Result<(), Error> {
+    writer.write_all(&u32_be(data.len() as u32))?;
+    writer.write_all(chunk_type)?;
+    writer.write_all(data)?;
+    let mut crc = crc32_update(0xFFFFFFFF, chunk_type);
+    crc = crc32_update(crc, data);
+    writer.write_all(&u32_be(crc ^ 0xFFFFFFFF))?;
+    Ok(())
+}
+
+fn u32_be(val: u32) -> [u8; 4] {
+    [(val >> 24) as u8, (val >> 16) as u8, (val >> 8) as u8, val as u8]
+}
+
+fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { 0xEDB88320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
@@ -20,7 +20,7 @@ pub fn read_saga(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec
     let mut top_to_bottom = false;
     let mut z_factor = 1.0;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         //let line_split = line_unwrapped.split("\t");
         let line_split = line_unwrapped.split("=");
         let vec = line_split.collect::<Vec<&str>>();
@@ -35,7 +35,7 @@ pub fn read_saga(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec
                 configs.xy_units = vec[1].trim().replace("=", "").to_string();
             }
         } else if vec[0].to_lowercase().contains("datafile_offset") {
-            data_file_offset = vec[1].replace("=", "").trim().to_string().parse::<u64>().unwrap();
+            data_file_offset = parse_field::<u64>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("dataformat") {
             let data_format = vec[1].replace("=", "").trim().to_lowercase().to_string();
             match &data_format[..] {
@@ -64,20 +64,20 @@ pub fn read_saga(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec
                  configs.endian = Endianness::BigEndian;
              }
         } else if vec[0].to_lowercase().contains("position_xmin") {
-            configs.west = vec[1].replace("=", "").trim().to_string().parse::<f64>().unwrap();
+            configs.west = parse_field::<f64>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("position_ymin") {
-            configs.south = vec[1].replace("=", "").trim().to_string().parse::<f64>().unwrap();
+            configs.south = parse_field::<f64>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("cellcount_x") {
-            configs.columns = vec[1].replace("=", "").trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("cellcount_y") {
-            configs.rows = vec[1].replace("=", "").trim().to_string().parse::<usize>().unwrap();
+            configs.rows = parse_field::<usize>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("cellsize") {
-            configs.resolution_x = vec[1].replace("=", "").trim().to_string().parse::<f64>().unwrap();
-            configs.resolution_y = vec[1].replace("=", "").trim().to_string().parse::<f64>().unwrap();
+            configs.resolution_x = parse_field::<f64>(vec[0], &vec[1].replace("=", ""))?;
+            configs.resolution_y = parse_field::<f64>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("z_factor") {
-            z_factor = vec[1].replace("=", "").trim().to_string().parse::<f64>().unwrap();
+            z_factor = parse_field::<f64>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("nodata_value") {
-            configs.nodata = vec[1].replace("=", "").trim().to_string().parse::<f64>().unwrap();
+            configs.nodata = parse_field::<f64>(vec[0], &vec[1].replace("=", ""))?;
         } else if vec[0].to_lowercase().contains("toptobottom") {
             top_to_bottom = vec[1].replace("=", "").trim().to_lowercase().contains("t")
         }
@@ -292,7 +292,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
         let v = *val;
-        if v != r.configs.nodata {
+        if !r.is_nodata(v) {
             if v < r.configs.minimum { r.configs.minimum = v; }
             if v > r.configs.maximum { r.configs.maximum = v; }
         }
@@ -146,7 +146,7 @@ pub fn write_surfer7<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
         let v = *val;
-        if v != r.configs.nodata {
+        if !r.is_nodata(v) {
             if v < r.configs.minimum { r.configs.minimum = v; }
             if v > r.configs.maximum { r.configs.maximum = v; }
         }
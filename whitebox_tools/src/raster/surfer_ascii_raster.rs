@@ -20,7 +20,7 @@ pub fn read_surfer_ascii_raster(file_name: &String, configs: &mut RasterConfigs,
     let mut num_cells = 0usize;
     let mut line_num = 0;
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         let mut line_split = line_unwrapped.split(" ");
         let mut vec = line_split.collect::<Vec<&str>>();
         if vec.is_empty() && line_num > 0 {
@@ -36,8 +36,8 @@ pub fn read_surfer_ascii_raster(file_name: &String, configs: &mut RasterConfigs,
             if vec.len() != 2 {
                 return Err(Error::new(ErrorKind::InvalidData, "The Surfer file appears to be improperly formated."));
             }
-            configs.columns = vec[0].trim().to_string().parse::<usize>().unwrap();
-            configs.rows = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>("columns", vec[0])?;
+            configs.rows = parse_field::<usize>("rows", vec[1])?;
             row = configs.rows - 1; // files are stored row major, bottom-to-top
             num_cells = configs.rows * configs.columns;
             data.clear();
@@ -48,20 +48,20 @@ pub fn read_surfer_ascii_raster(file_name: &String, configs: &mut RasterConfigs,
             if vec.len() != 2 {
                 return Err(Error::new(ErrorKind::InvalidData, "The Surfer file appears to be improperly formated."));
             }
-            configs.west = vec[0].trim().to_string().parse::<f64>().unwrap();
-            configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.west = parse_field::<f64>("west", vec[0])?;
+            configs.east = parse_field::<f64>("east", vec[1])?;
         } else if line_num == 3 {
             if vec.len() != 2 {
                 return Err(Error::new(ErrorKind::InvalidData, "The Surfer file appears to be improperly formated."));
             }
-            configs.south = vec[0].trim().to_string().parse::<f64>().unwrap();
-            configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.south = parse_field::<f64>("south", vec[0])?;
+            configs.north = parse_field::<f64>("north", vec[1])?;
         } else if line_num == 4 {
             if vec.len() != 2 {
                 return Err(Error::new(ErrorKind::InvalidData, "The Surfer file appears to be improperly formated."));
             }
-            configs.minimum = vec[0].trim().to_string().parse::<f64>().unwrap();
-            configs.maximum = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.minimum = parse_field::<f64>("minimum", vec[0])?;
+            configs.maximum = parse_field::<f64>("maximum", vec[1])?;
         } else { // it's a data line
             let mut val_num;
             let mut i;
@@ -72,7 +72,7 @@ pub fn read_surfer_ascii_raster(file_name: &String, configs: &mut RasterConfigs,
                 } else {
                     i = row * configs.columns + col;
                     if !val.contains("1.71041e38") {
-                        val_num = val.trim().to_string().parse::<f64>().unwrap();
+                        val_num = parse_field::<f64>("data", val)?;
                         data[i] = val_num;
                     } else {
                         data[i] = configs.nodata;
@@ -101,7 +101,7 @@ pub fn write_surfer_ascii_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
         let v = *val;
-        if v != r.configs.nodata {
+        if !r.is_nodata(v) {
             if v < r.configs.minimum { r.configs.minimum = v; }
             if v > r.configs.maximum { r.configs.maximum = v; }
         }
@@ -128,13 +128,13 @@ pub fn write_surfer_ascii_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
         for col in 0..r.configs.columns {
             let i = row * r.configs.columns + col;
             if col < r.configs.columns - 1 {
-                if r.data[i] != r.configs.nodata {
+                if !r.is_nodata(r.data[i]) {
                     s2 += &format!("{:.*} ", num_decimals, r.data[i]);
                 } else {
                     s2 += &format!("1.71041e38 ");
                 }
             } else {
-                if r.data[i] != r.configs.nodata {
+                if !r.is_nodata(r.data[i]) {
                     s2 += &format!("{:.*}\n", num_decimals, r.data[i]);
                 } else {
                     s2 += &format!("1.71041e38\n");
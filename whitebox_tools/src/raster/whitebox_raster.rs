@@ -1,3 +1,5 @@
+extern crate rayon;
+
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::BufReader;
@@ -8,40 +10,44 @@ use std::fs::File;
 use std::mem;
 use raster::*;
 use io_utils::byte_order_reader::Endianness;
+use byteorder::{ByteOrder, NativeEndian};
 
-pub fn read_whitebox(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec<f64>) -> Result<(), Error> {
+fn read_header(file_name: &String, configs: &mut RasterConfigs) -> Result<(), Error> {
     // read the header file
     let header_file = file_name.replace(".tas", ".dep");
     let f = File::open(header_file)?;
     let f = BufReader::new(f);
 
     for line in f.lines() {
-        let line_unwrapped = line.unwrap();
+        let line_unwrapped = line?;
         // println!("{}", line_unwrapped);
         let line_split = line_unwrapped.split(":");
         let vec = line_split.collect::<Vec<&str>>();
+        if vec.len() < 2 {
+            continue; // not a "field: value" line
+        }
         if vec[0].to_lowercase().contains("rows") {
-            configs.rows = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.rows = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("col") {
-            configs.columns = vec[1].trim().to_string().parse::<usize>().unwrap();
+            configs.columns = parse_field::<usize>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("stacks") {
-            configs.bands = vec[1].trim().to_string().parse::<u8>().unwrap();
+            configs.bands = parse_field::<u8>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("north") {
-            configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.north = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("south") {
-            configs.south = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.south = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("east") {
-            configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.east = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("west") {
-            configs.west = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.west = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("display min") {
-            configs.display_min = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.display_min = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("display max") {
-            configs.display_max = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.display_max = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("min") && !vec[0].to_lowercase().contains("display") {
-            configs.minimum = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.minimum = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("max") && !vec[0].to_lowercase().contains("display") {
-            configs.maximum = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.maximum = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("data type") {
             if vec[1].trim().to_lowercase().to_string().contains("double") {
                 configs.data_type = DataType::F64;
@@ -70,11 +76,11 @@ pub fn read_whitebox(file_name: &String, configs: &mut RasterConfigs, data: &mut
         } else if vec[0].to_lowercase().contains("projection") {
             configs.projection = vec[1].trim().to_string();
         } else if vec[0].to_lowercase().contains("nodata") {
-            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.nodata = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("preferred palette") {
             configs.palette = vec[1].trim().to_string();
         } else if vec[0].to_lowercase().contains("nonlinearity") {
-            configs.palette_nonlinearity = vec[1].trim().to_string().parse::<f64>().unwrap();
+            configs.palette_nonlinearity = parse_field::<f64>(vec[0], vec[1])?;
         } else if vec[0].to_lowercase().contains("byte order") {
             if vec[1].trim().to_lowercase().contains("little") ||
               vec[1].trim().to_lowercase().contains("lsb") {
@@ -82,6 +88,10 @@ pub fn read_whitebox(file_name: &String, configs: &mut RasterConfigs, data: &mut
              } else {
                  configs.endian = Endianness::BigEndian;
              }
+        } else if vec[0].to_lowercase().contains("compression") {
+            if vec[1].trim().to_lowercase().contains("rle") {
+                configs.compression = Compression::Rle;
+            }
         } else if vec[0].to_lowercase().contains("metadata") {
             configs.metadata.push(vec[1].trim().to_string());
         }
@@ -90,9 +100,31 @@ pub fn read_whitebox(file_name: &String, configs: &mut RasterConfigs, data: &mut
     configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
     configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
 
-    // read the data file
+    Ok(())
+}
+
+pub fn read_whitebox(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec<f64>) -> Result<(), Error> {
+    read_header(file_name, configs)?;
+    let num_cells = configs.rows * configs.columns;
+    read_data(file_name, configs, data, num_cells)
+}
+
+/// Reads every band of a Whitebox raster with more than one stack, band after band, into
+/// `data`. `read_whitebox` reads only the first band.
+pub fn read_whitebox_stack(file_name: &String, configs: &mut RasterConfigs, data: &mut Vec<f64>) -> Result<(), Error> {
+    read_header(file_name, configs)?;
+    let num_cells = configs.rows * configs.columns * (configs.bands as usize).max(1);
+    read_data(file_name, configs, data, num_cells)
+}
+
+fn read_data(file_name: &String, configs: &RasterConfigs, data: &mut Vec<f64>, num_cells: usize) -> Result<(), Error> {
     let data_file = file_name.replace(".dep", ".tas");
-    let mut f = File::open(data_file.clone())?;
+    if configs.compression == Compression::Rle {
+        return read_rle_data(&data_file, configs, data, num_cells);
+    }
+    let mut f = File::open(data_file.clone()).map_err(|e| {
+        Error::new(e.kind(), format!("Could not open the data file {}: {}", data_file, e))
+    })?;
     //let br = BufReader::new(f);
     // let metadata = try!(fs::metadata(data_file.clone()));
     // let file_size: usize = metadata.len() as usize;
@@ -108,7 +140,6 @@ pub fn read_whitebox(file_name: &String, configs: &mut RasterConfigs, data: &mut
         1
     };
 
-    let num_cells = configs.rows * configs.columns;
     let buf_size = 1_000_000usize;
     let mut j = 0;
     while j < num_cells {
@@ -170,7 +201,7 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
     for val in &r.data {
         let v = *val;
-        if v != r.configs.nodata {
+        if !r.is_nodata(v) {
             if v < r.configs.minimum { r.configs.minimum = v; }
             if v > r.configs.maximum { r.configs.maximum = v; }
         }
@@ -267,7 +298,18 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let s = format!("Display Max:\t{}\n", r.configs.display_max);
     writer.write_all(s.as_bytes())?;
 
-    let s = format!("Preferred Palette:\t{}\n", r.configs.palette);
+    // Whitebox GAT can't display a raster whose palette file can't be found, so fall back on
+    // its default palettes when none was read from the input.
+    let palette = if r.configs.palette != "not specified" {
+        r.configs.palette.clone()
+    } else {
+        match r.configs.photometric_interp {
+            PhotometricInterpretation::Categorical | PhotometricInterpretation::Paletted => "qual.pal".to_string(),
+            PhotometricInterpretation::Boolean => "black_white.pal".to_string(),
+            _ => "spectrum.pal".to_string(),
+        }
+    };
+    let s = format!("Preferred Palette:\t{}\n", palette);
     writer.write_all(s.as_bytes())?;
 
     let s = format!("NoData:\t{}\n", r.configs.nodata);
@@ -284,6 +326,10 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let s = format!("Palette Nonlinearity:\t{}\n", r.configs.palette_nonlinearity);
     writer.write_all(s.as_bytes())?;
 
+    if r.configs.compression == Compression::Rle {
+        writer.write_all("Compression:\tRLE\n".as_bytes())?;
+    }
+
     for md in &r.configs.metadata {
         let s = format!("Metadata Entry:\t{}\n", md.replace(":", ";"));
         writer.write_all(s.as_bytes())?;
@@ -295,43 +341,180 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // write the data file
     let data_file = r.file_name.replace(".dep", ".tas");
     let f = File::create(&data_file)?;
-    let mut writer = BufWriter::new(f);
-
-    let mut u16_bytes: [u8; 2];
-    let mut u32_bytes: [u8; 4];
-    let mut u64_bytes: [u8; 8];
+    let mut writer = BufWriter::with_capacity(WRITE_BUFFER_SIZE, f);
 
-    let num_cells: usize = r.configs.rows * r.configs.columns;
-    match r.configs.data_type {
-        DataType::F64 => {
-            for i in 0..num_cells {
-                u64_bytes = unsafe { mem::transmute(r.data[i]) };
-                writer.write(&u64_bytes)?;
-            }
-        },
-        DataType::F32 => {
-            for i in 0..num_cells {
-                u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
-                writer.write(&u32_bytes)?;
-            }
-        },
-        DataType::I16 => {
-            for i in 0..num_cells {
-                u16_bytes = unsafe { mem::transmute(r.data[i] as u16) };
-                writer.write(&u16_bytes)?;
-            }
-        },
-        DataType::U8 => {
-            for i in 0..num_cells {
-                writer.write(&[r.data[i] as u8])?;
-            }
-        },
+    let data_size = match r.configs.data_type {
+        DataType::F64 => 8,
+        DataType::F32 => 4,
+        DataType::I16 => 2,
+        DataType::U8 => 1,
         _ => {
             return Err(Error::new(ErrorKind::NotFound, "Raster data type is unknown."));
         },
+    };
+
+    if r.configs.compression == Compression::Rle {
+        write_rle_data(&r.data, &mut writer, r.configs.data_type, data_size)?;
+        let _ = writer.flush();
+        return Ok(());
+    }
+
+    // The cells are converted to bytes in parallel, one block at a time so that the byte
+    // buffer stays small, and each block is then written in order.
+    // A stacked raster holds all of its bands, one after another, in its data.
+    let num_cells: usize = r.data.len();
+    let block_size = 1_000_000usize;
+    let mut bytes = vec![0u8; block_size.min(num_cells) * data_size];
+    let mut start = 0usize;
+    while start < num_cells {
+        let end = (start + block_size).min(num_cells);
+        let block_bytes = &mut bytes[..(end - start) * data_size];
+        serialize_cells(&r.data[start..end], block_bytes, r.configs.data_type, data_size);
+        writer.write_all(block_bytes)?;
+        start = end;
     }
 
     let _ = writer.flush();
 
     Ok(())
 }
+
+const WRITE_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+fn serialize_cells(values: &[f64], bytes: &mut [u8], data_type: DataType, data_size: usize) {
+    if values.len() > 100_000 {
+        let mid = values.len() / 2;
+        let (values_left, values_right) = values.split_at(mid);
+        let (bytes_left, bytes_right) = bytes.split_at_mut(mid * data_size);
+        rayon::join(|| serialize_cells(values_left, bytes_left, data_type, data_size),
+                    || serialize_cells(values_right, bytes_right, data_type, data_size));
+        return;
+    }
+    let mut offset: usize;
+    for i in 0..values.len() {
+        offset = i * data_size;
+        match data_type {
+            DataType::F64 => {
+                let u64_bytes: [u8; 8] = unsafe { mem::transmute(values[i]) };
+                bytes[offset..offset + 8].copy_from_slice(&u64_bytes);
+            },
+            DataType::F32 => {
+                let u32_bytes: [u8; 4] = unsafe { mem::transmute(values[i] as f32) };
+                bytes[offset..offset + 4].copy_from_slice(&u32_bytes);
+            },
+            DataType::I16 => {
+                // INTEGER cells are signed, as read_data reads them; casting through u16
+                // saturated negative values, including the usual -32768 nodata, to 0.
+                let u16_bytes: [u8; 2] = unsafe { mem::transmute(values[i] as i16) };
+                bytes[offset..offset + 2].copy_from_slice(&u16_bytes);
+            },
+            _ => {
+                bytes[offset] = values[i] as u8;
+            },
+        }
+    }
+}
+
+// Run-length encoded data are stored as a sequence of runs, each a u32 header followed by
+// values in the raster's data type. A header with the high bit clear is a repeat run: its count
+// and a single value that is repeated. A header with the high bit set is a literal run: its
+// count (without the high bit) followed by that many distinct values, so that data without
+// repeats, like most DEMs, are hardly enlarged.
+const LITERAL_RUN_FLAG: u32 = 0x8000_0000;
+const MAX_RUN_LENGTH: usize = 0x7FFF_FFFF;
+// Repeats shorter than this are cheaper to store within a literal run.
+const MIN_REPEAT_RUN: usize = 3;
+// Literal runs are limited in length when writing, to bound the size of the byte buffer.
+const MAX_LITERAL_RUN_WRITTEN: usize = 1_000_000;
+
+fn write_rle_data<W: Write>(values: &[f64], writer: &mut W, data_type: DataType, data_size: usize) -> Result<(), Error> {
+    let mut value_bytes = vec![0u8; MAX_LITERAL_RUN_WRITTEN.min(values.len()).max(1) * data_size];
+    let mut header = [0u8; 4];
+    let repeat_length = |start: usize| -> usize {
+        let mut end = start + 1;
+        while end < values.len() && values[end] == values[start] && end - start < MAX_RUN_LENGTH {
+            end += 1;
+        }
+        end - start
+    };
+    let mut i = 0usize;
+    while i < values.len() {
+        let run = repeat_length(i);
+        if run >= MIN_REPEAT_RUN {
+            NativeEndian::write_u32(&mut header, run as u32);
+            writer.write_all(&header)?;
+            serialize_cells(&values[i..i + 1], &mut value_bytes[..data_size], data_type, data_size);
+            writer.write_all(&value_bytes[..data_size])?;
+            i += run;
+        } else {
+            // Extend the literal run until the next repeat run begins.
+            let mut end = i + run;
+            while end < values.len() && end - i < MAX_LITERAL_RUN_WRITTEN {
+                let next = repeat_length(end);
+                if next >= MIN_REPEAT_RUN { break; }
+                end += next;
+            }
+            let end = end.min(i + MAX_LITERAL_RUN_WRITTEN);
+            NativeEndian::write_u32(&mut header, (end - i) as u32 | LITERAL_RUN_FLAG);
+            writer.write_all(&header)?;
+            let bytes = &mut value_bytes[..(end - i) * data_size];
+            serialize_cells(&values[i..end], bytes, data_type, data_size);
+            writer.write_all(bytes)?;
+            i = end;
+        }
+    }
+    Ok(())
+}
+
+fn read_rle_data(data_file: &str, configs: &RasterConfigs, data: &mut Vec<f64>, num_cells: usize) -> Result<(), Error> {
+    let data_size = match configs.data_type {
+        DataType::F64 => 8,
+        DataType::F32 => 4,
+        DataType::I16 => 2,
+        DataType::U8 => 1,
+        _ => {
+            return Err(Error::new(ErrorKind::NotFound, "Raster data type is unknown."));
+        },
+    };
+    let mut buffer = vec![];
+    File::open(data_file)?.read_to_end(&mut buffer)?;
+    data.reserve(num_cells);
+    // The values are in native byte order, as for uncompressed data.
+    let read_value = |offset: usize| -> f64 {
+        match configs.data_type {
+            DataType::F64 => NativeEndian::read_f64(&buffer[offset..offset + 8]),
+            DataType::F32 => NativeEndian::read_f32(&buffer[offset..offset + 4]) as f64,
+            DataType::I16 => NativeEndian::read_i16(&buffer[offset..offset + 2]) as f64,
+            _ => buffer[offset] as f64,
+        }
+    };
+    let mut offset = 0usize;
+    let (mut header, mut count, mut num_values): (u32, usize, usize);
+    while data.len() < num_cells {
+        if offset + 4 > buffer.len() {
+            return Err(Error::new(ErrorKind::InvalidData, format!("{} ends before all {} cells were read.", data_file, num_cells)));
+        }
+        header = NativeEndian::read_u32(&buffer[offset..offset + 4]);
+        offset += 4;
+        count = (header & !LITERAL_RUN_FLAG) as usize;
+        num_values = if header & LITERAL_RUN_FLAG != 0 { count } else { 1 };
+        if offset + num_values * data_size > buffer.len() {
+            return Err(Error::new(ErrorKind::InvalidData, format!("{} ends before all {} cells were read.", data_file, num_cells)));
+        }
+        if count > num_cells - data.len() {
+            return Err(Error::new(ErrorKind::InvalidData, format!("{} contains more than {} cells.", data_file, num_cells)));
+        }
+        if header & LITERAL_RUN_FLAG != 0 {
+            for k in 0..count {
+                data.push(read_value(offset + k * data_size));
+            }
+        } else {
+            let value = read_value(offset);
+            for _ in 0..count {
+                data.push(value);
+            }
+        }
+        offset += num_values * data_size;
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::io::{Error, ErrorKind};
+use std::io::BufReader;
+use std::io::prelude::*;
+use std::fs::File;
+
+/// A colour ramp defined by (value, red, green, blue) stops, sorted by value. Colours are
+/// linearly interpolated between stops and clamped to the end stops outside of their range.
+#[derive(Default, Clone, Debug)]
+pub struct ColorRamp {
+    pub stops: Vec<(f64, u8, u8, u8)>,
+}
+
+impl ColorRamp {
+    pub fn new(mut stops: Vec<(f64, u8, u8, u8)>) -> ColorRamp {
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        ColorRamp { stops: stops }
+    }
+
+    /// Reads a ramp from a text file with one `value, r, g, b` stop per line, separated by
+    /// commas or whitespace. Any further fields (e.g. the alpha and label of a QGIS colour map
+    /// export) are ignored, as are lines that don't start with a number, such as QGIS's
+    /// `INTERPOLATION:` header and `#` comments.
+    pub fn from_file<'a>(file_name: &'a str) -> Result<ColorRamp, Error> {
+        let f = File::open(file_name)?;
+        let f = BufReader::new(f);
+        let mut stops = vec![];
+        for line in f.lines() {
+            let line_unwrapped = line?;
+            let vec = line_unwrapped.split(|c: char| c == ',' || c.is_whitespace())
+                                    .filter(|s| !s.is_empty())
+                                    .collect::<Vec<&str>>();
+            if vec.len() < 4 {
+                continue;
+            }
+            let value = match vec[0].parse::<f64>() {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            let mut rgb = [0u8; 3];
+            for i in 0..3 {
+                rgb[i] = match vec[i + 1].parse::<u8>() {
+                    Ok(v) => v,
+                    Err(_) => return Err(Error::new(ErrorKind::InvalidData, format!("Invalid colour value '{}' in {}.", vec[i + 1], file_name))),
+                };
+            }
+            stops.push((value, rgb[0], rgb[1], rgb[2]));
+        }
+        if stops.len() == 0 {
+            return Err(Error::new(ErrorKind::InvalidData, format!("No colour stops were found in {}.", file_name)));
+        }
+        Ok(ColorRamp::new(stops))
+    }
+
+    pub fn get_color(&self, value: f64) -> (u8, u8, u8) {
+        if self.stops.len() == 0 {
+            return (0, 0, 0);
+        }
+        let first = self.stops[0];
+        if value <= first.0 {
+            return (first.1, first.2, first.3);
+        }
+        for i in 1..self.stops.len() {
+            let (v1, r1, g1, b1) = self.stops[i];
+            if value <= v1 {
+                let (v0, r0, g0, b0) = self.stops[i - 1];
+                let t = if v1 > v0 { (value - v0) / (v1 - v0) } else { 1f64 };
+                let lerp = |c0: u8, c1: u8| (c0 as f64 + t * (c1 as f64 - c0 as f64)).round() as u8;
+                return (lerp(r0, r1), lerp(g0, g1), lerp(b0, b1));
+            }
+        }
+        let last = self.stops[self.stops.len() - 1];
+        (last.1, last.2, last.3)
+    }
+}
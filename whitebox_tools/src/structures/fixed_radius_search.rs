@@ -179,6 +179,29 @@ impl<T: Copy> FixedRadiusSearch<T> {
 
         ret
     }
+
+    /// Like `search`, but returns only the values within `max_dist` of (x, y), allowing a
+    /// smaller neighbourhood than the search radius without rebuilding the index. Distances
+    /// greater than the search radius are reduced to it.
+    pub fn search_within(&self, x: f64, y: f64, max_dist: f64) -> Vec<(T, f64)> {
+        let mut ret = vec![];
+        let max_dist_sqr = if max_dist < self.r { max_dist * max_dist } else { self.r_sqr };
+        let i = (x / self.r).floor() as isize;
+        let j = (y / self.r).floor() as isize;
+        for m in -1..2 {
+            for n in -1..2 {
+                if let Some(vals) = self.hm.get(&FixedRadiusSearchKey{ col: i+m, row: j+n }) {
+                    for val in vals {
+                        let dist = (x - val.x)*(x - val.x) + (y - val.y)*(y - val.y);
+                        if dist <= max_dist_sqr {
+                            ret.push((self.values[val.index], dist.sqrt()));
+                        }
+                    }
+                }
+            }
+        }
+        ret
+    }
 }
 
 #[inline(always)]
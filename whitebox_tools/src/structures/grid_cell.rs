@@ -0,0 +1,39 @@
+use std::cmp::Ordering;
+
+/// A grid cell with a floating-point priority, for use in a `BinaryHeap`. The ordering is
+/// reversed so that the heap pops the lowest priority first, as priority-flood and
+/// least-cost (Dijkstra) searches need.
+#[derive(Copy, Clone, Debug)]
+pub struct GridCell {
+    pub row: isize,
+    pub column: isize,
+    pub priority: f64,
+}
+
+impl GridCell {
+    pub fn new(row: isize, column: isize, priority: f64) -> GridCell {
+        GridCell { row: row, column: column, priority: priority }
+    }
+}
+
+impl PartialEq for GridCell {
+    fn eq(&self, other: &GridCell) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for GridCell {}
+
+impl Ord for GridCell {
+    fn cmp(&self, other: &GridCell) -> Ordering {
+        // Note the flipped comparison, which makes the heap a min-heap. NaN priorities compare
+        // as equal rather than panicking.
+        other.priority.partial_cmp(&self.priority).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for GridCell {
+    fn partial_cmp(&self, other: &GridCell) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
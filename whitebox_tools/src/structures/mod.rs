@@ -1,2 +1,6 @@
 pub mod fixed_radius_search;
+pub mod grid_cell;
+pub mod seeded_rng;
 pub mod array2d;
+pub mod color_ramp;
+pub mod structuring_element;
@@ -0,0 +1,67 @@
+/////////////////////////////////////////////////////////////
+// A small, seedable pseudo-random number generator, so that
+// tools making random or arbitrary choices are reproducible.
+//
+// Tools that accept a --seed parameter:
+//   lidar_segmentation (segment colours)
+//   kmeans (initial cluster centres)
+/////////////////////////////////////////////////////////////
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A xorshift64* generator. It is not suitable for cryptography but is fast, and the same seed
+/// always yields the same sequence on every platform.
+#[derive(Debug, Copy, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> SeededRng {
+        // Scramble the seed with a SplitMix64 step so that small or similar seeds give
+        // unrelated sequences; xorshift also requires a non-zero state.
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z = z ^ (z >> 31);
+        SeededRng { state: if z == 0 { 0x9E3779B97F4A7C15 } else { z } }
+    }
+
+    /// Creates a generator seeded from the system clock, for when reproducibility isn't needed.
+    pub fn from_time() -> SeededRng {
+        let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs().wrapping_mul(1_000_000_000).wrapping_add(d.subsec_nanos() as u64),
+            Err(_) => 0u64,
+        };
+        SeededRng::new(seed)
+    }
+
+    /// Returns a generator using `seed` if one is given and the system clock otherwise.
+    pub fn from_optional_seed(seed: Option<u64>) -> SeededRng {
+        match seed {
+            Some(s) => SeededRng::new(s),
+            None => SeededRng::from_time(),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    pub fn next_u8(&mut self) -> u8 {
+        (self.next_u64() >> 56) as u8
+    }
+
+    /// Returns a value uniformly distributed in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a value in [0, n); n must be greater than zero.
+    pub fn gen_range(&mut self, n: usize) -> usize {
+        (self.next_f64() * n as f64) as usize
+    }
+}
@@ -0,0 +1,64 @@
+/////////////////////////////////////////////////////////////
+// Structuring element shapes used by neighbourhood filters
+/////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StructuringElement {
+    Square,
+    Circle,
+}
+
+impl Default for StructuringElement {
+    fn default() -> StructuringElement { StructuringElement::Square }
+}
+
+impl StructuringElement {
+    pub fn from_str<'a>(val: &'a str) -> StructuringElement {
+        let val_lc: &str = &val.to_lowercase();
+        if val_lc.contains("circ") || val_lc.contains("round") {
+            return StructuringElement::Circle;
+        }
+        StructuringElement::Square
+    }
+
+    /// Returns the (dx, dy) offsets of the cells that fall within a structuring element of
+    /// the given size (in cells), relative to the centre cell. Even sizes are increased by one
+    /// so that there is a middle cell. A circle keeps the cells within the largest circle that
+    /// fits in the filter box.
+    pub fn offsets(&self, filter_size: usize) -> Vec<(isize, isize)> {
+        let mut filter_size = filter_size;
+        if filter_size % 2 == 0 {
+            filter_size += 1;
+        }
+        let radius = (filter_size / 2) as isize;
+        let r_sqr = radius * radius;
+        let mut ret = Vec::with_capacity(filter_size * filter_size);
+        for dy in -radius..radius+1 {
+            for dx in -radius..radius+1 {
+                if *self == StructuringElement::Square || dx * dx + dy * dy <= r_sqr {
+                    ret.push((dx, dy));
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// Returns the (dx, dy) offsets of the cells whose centres lie within the annulus between
+/// `inner_radius` and `outer_radius` (inclusive, in map units) of the centre cell, for cells of
+/// size `res_x` by `res_y`.
+pub fn annulus_offsets(inner_radius: f64, outer_radius: f64, res_x: f64, res_y: f64) -> Vec<(isize, isize)> {
+    let max_dx = (outer_radius / res_x).floor() as isize;
+    let max_dy = (outer_radius / res_y).floor() as isize;
+    let mut ret = vec![];
+    let mut dist: f64;
+    for dy in -max_dy..max_dy+1 {
+        for dx in -max_dx..max_dx+1 {
+            dist = ((dx as f64 * res_x).powi(2) + (dy as f64 * res_y).powi(2)).sqrt();
+            if dist >= inner_radius && dist <= outer_radius {
+                ret.push((dx, dy));
+            }
+        }
+    }
+    ret
+}
@@ -0,0 +1,217 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::filter::iterate_filter;
+use raster::neighborhood::{for_each_3x3, horn_gradient};
+use structures::array2d::Array2D;
+
+pub fn get_tool_name() -> String {
+    return "adaptive_smoothing".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Applies a mean filter only where the local slope exceeds a threshold, removing noise on steep ground while leaving gentle terrain untouched.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 11.
+--slope            Slope threshold (degrees) above which cells are smoothed; default is 15.0.
+--num_iterations   Number of times the filter is applied, each pass to the output of the last; default is 1.
+--tolerance        Optional mean absolute change per pass below which iterating stops early; default is 0 (never).
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=adaptive_smoothing --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --filter=5 --slope=20.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 11usize;
+    let mut slope_threshold = 15f64;
+    let mut num_iterations = 1usize;
+    let mut tolerance = 0f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-slope" || vec[0].to_lowercase() == "--slope" {
+            if keyval {
+                slope_threshold = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                slope_threshold = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-num_iterations" || vec[0].to_lowercase() == "--num_iterations" {
+            if keyval {
+                num_iterations = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                num_iterations = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-tolerance" || vec[0].to_lowercase() == "--tolerance" {
+            if keyval {
+                tolerance = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                tolerance = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if num_iterations == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The number of iterations must be at least 1."));
+    }
+
+    if verbose {
+        println!("*********************************");
+        println!("* Welcome to adaptive_smoothing *");
+        println!("*********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Performing adaptive smoothing...") };
+    let (mut output, passes) = iterate_filter(&input, num_iterations, tolerance,
+        |r| adaptive_smoothing(r, slope_threshold, filter_size));
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' adaptive_smoothing tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Slope threshold: {}", slope_threshold));
+    output.add_metadata_entry(format!("Iterations: {}", passes));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Smooths only the steep parts of a surface: each grid cell whose slope (in degrees) exceeds
+/// `slope_threshold` is replaced by the mean of the non-nodata values within the filter window,
+/// while gentler cells are copied unchanged. Even filter sizes are increased by one to centre
+/// the window. Slope is calculated from the unsmoothed 3x3 neighbourhood using Horn's (1981)
+/// method, with nodata and off-grid neighbours taking the value of the centre cell. Nodata cells
+/// remain nodata.
+pub fn adaptive_smoothing(input: &Raster, slope_threshold: f64, filter_size: usize) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+
+    // The integral images have an extra leading row and column of zeros.
+    let (mut z, mut sum, mut n): (f64, f64, f64);
+    let mut values: Vec<f64>;
+    let mut i_sum: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    let mut i_n: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    for row in 0..rows {
+        values = input.get_row_data(row);
+        sum = 0f64;
+        n = 0f64;
+        for col in 0..columns {
+            z = values[col as usize];
+            if !input.is_nodata(z) {
+                sum += z;
+                n += 1f64;
+            }
+            i_sum[(row + 1, col + 1)] = sum + i_sum[(row, col + 1)];
+            i_n[(row + 1, col + 1)] = n + i_n[(row, col + 1)];
+        }
+    }
+
+    for_each_3x3(input, |row, col, window| {
+        let (dz_dx, dz_dy) = horn_gradient(window, input);
+        let slope = (dz_dx * dz_dx + dz_dy * dz_dy).sqrt().atan().to_degrees();
+        if slope <= slope_threshold {
+            output.set_value(row, col, window[4]);
+            return;
+        }
+        // rows and columns in the integral images are shifted by one
+        let y1 = if row - midpoint < 0 { 0 } else { row - midpoint };
+        let y2 = if row + midpoint + 1 > rows { rows } else { row + midpoint + 1 };
+        let x1 = if col - midpoint < 0 { 0 } else { col - midpoint };
+        let x2 = if col + midpoint + 1 > columns { columns } else { col + midpoint + 1 };
+        let n = i_n[(y2, x2)] + i_n[(y1, x1)] - i_n[(y1, x2)] - i_n[(y2, x1)];
+        let sum = i_sum[(y2, x2)] + i_sum[(y1, x1)] - i_sum[(y1, x2)] - i_sum[(y2, x1)];
+        output.set_value(row, col, sum / n);
+    });
+
+    output
+}
@@ -0,0 +1,202 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::resample::{sample, Resampling};
+
+pub fn get_tool_name() -> String {
+    return "affine_warp".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Resamples a raster into a new grid through an affine (scale, rotation and translation) transform.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--transform        Affine coefficients a,b,c,d,e,f, where x' = a*x + b*y + c and y' = d*x + e*y + f.
+--cell_size        Output cell size.
+--method           Resampling method, 'nearest', 'bilinear' or 'cubic'; default is nearest.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=affine_warp --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --transform=\"0.866,-0.5,0.0,0.5,0.866,0.0\" --cell_size=10.0 --method=bilinear\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut transform_str = String::new();
+    let mut cell_size = 0f64;
+    let mut method = Resampling::Nearest;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-transform" || vec[0].to_lowercase() == "--transform" {
+            if keyval {
+                transform_str = vec[1].to_string();
+            } else {
+                transform_str = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-cell_size" || vec[0].to_lowercase() == "--cell_size" {
+            if keyval {
+                cell_size = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                cell_size = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-method" || vec[0].to_lowercase() == "--method" {
+            if keyval {
+                method = Resampling::from_str(vec[1]);
+            } else {
+                method = Resampling::from_str(&args[i+1]);
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    let transform_vals: Vec<f64> = transform_str.split(",")
+                                                .filter(|s| !s.trim().is_empty())
+                                                .map(|s| s.trim().parse::<f64>().unwrap())
+                                                .collect();
+    if transform_vals.len() != 6 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --transform parameter must contain six values: a,b,c,d,e,f."));
+    }
+    if transform_vals[0] * transform_vals[4] - transform_vals[1] * transform_vals[3] == 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The affine transform is not invertible."));
+    }
+    let transform = [transform_vals[0], transform_vals[1], transform_vals[2],
+                     transform_vals[3], transform_vals[4], transform_vals[5]];
+    if cell_size <= 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --cell_size parameter must be greater than zero."));
+    }
+
+    if verbose {
+        println!("**************************");
+        println!("* Welcome to affine_warp *");
+        println!("**************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Warping...") };
+    let mut output = affine_warp(&input, transform, cell_size, method);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' affine_warp tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Transform: {}", transform_str));
+    output.add_metadata_entry(format!("Cell size: {}", cell_size));
+    output.add_metadata_entry(format!("Resampling method: {:?}", method));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Warps the input with the affine transform `[a, b, c, d, e, f]`, which maps input world
+/// coordinates (x, y) to output coordinates x' = a*x + b*y + c, y' = d*x + e*y + f. The output
+/// grid covers the transformed corners of the input at `out_cell_size`, and each output cell
+/// centre is mapped back through the inverse transform to sample the input. Cells that fall
+/// outside the input, or every cell if the transform is not invertible, are nodata.
+pub fn affine_warp(input: &Raster, transform: [f64; 6], out_cell_size: f64, method: Resampling) -> Raster {
+    let [a, b, c, d, e, f] = transform;
+    let corners = [(input.configs.west, input.configs.north), (input.configs.east, input.configs.north),
+                   (input.configs.west, input.configs.south), (input.configs.east, input.configs.south)];
+    let (mut north, mut south, mut east, mut west) = (f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY);
+    for &(x, y) in corners.iter() {
+        let x_t = a * x + b * y + c;
+        let y_t = d * x + e * y + f;
+        if y_t > north { north = y_t; }
+        if y_t < south { south = y_t; }
+        if x_t > east { east = x_t; }
+        if x_t < west { west = x_t; }
+    }
+    let rows = ((north - south) / out_cell_size).ceil().max(1f64) as usize;
+    let columns = ((east - west) / out_cell_size).ceil().max(1f64) as usize;
+    let mut output = Raster::initialize_using_extent(&input.file_name, input, rows, columns,
+        north, west, out_cell_size, out_cell_size);
+
+    let det = a * e - b * d;
+    if det == 0f64 {
+        return output;
+    }
+    let (mut x_t, mut y_t, mut x, mut y, mut z): (f64, f64, f64, f64, f64);
+    for row in 0..rows as isize {
+        y_t = north - (row as f64 + 0.5) * out_cell_size;
+        for col in 0..columns as isize {
+            x_t = west + (col as f64 + 0.5) * out_cell_size;
+            x = (e * (x_t - c) - b * (y_t - f)) / det;
+            y = (a * (y_t - f) - d * (x_t - c)) / det;
+            z = sample(input,
+                (input.configs.north - y) / input.configs.resolution_y - 0.5,
+                (x - input.configs.west) / input.configs.resolution_x - 0.5,
+                method);
+            if !input.is_nodata(z) {
+                output.set_value(row, col, z);
+            }
+        }
+    }
+
+    output
+}
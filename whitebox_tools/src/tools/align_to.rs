@@ -0,0 +1,159 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::resample::Resampling;
+
+pub fn get_tool_name() -> String {
+    return "align_to".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Resamples a raster onto the grid (extent and cell size) of a reference raster.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+--reference        Reference raster file defining the output grid.
+-o, --output       Output raster file.
+--method           Resampling method, 'nearest', 'bilinear' or 'cubic'; default is nearest.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=align_to --wd=\"/dir/to/data\" --args=\"-i=landcover.dep --reference=DEM.dep -o=output.dep --method=nearest\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut reference_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut method = Resampling::Nearest;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-reference" || vec[0].to_lowercase() == "--reference" {
+            if keyval {
+                reference_file = vec[1].to_string();
+            } else {
+                reference_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-method" || vec[0].to_lowercase() == "--method" {
+            if keyval {
+                method = Resampling::from_str(vec[1]);
+            } else {
+                method = Resampling::from_str(&args[i+1]);
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***********************");
+        println!("* Welcome to align_to *");
+        println!("***********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !reference_file.contains(&sep) {
+        reference_file = format!("{}{}", working_directory, reference_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let reference = Raster::new(&reference_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Aligning...") };
+    let mut output = align_to(&reference, &input, method);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' align_to tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Reference file: {}", reference_file));
+    output.add_metadata_entry(format!("Resampling method: {:?}", method));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Resamples `input` onto the grid of `reference`, so that the two rasters are aligned (see
+/// `check_alignment`) and can be combined cell by cell. The output has the reference's rows,
+/// columns, extent and cell size, and the input's nodata value, data type and other metadata;
+/// each cell takes the input's value at its centre (see `Raster::value_at`), and is nodata
+/// where that lies outside of the input or involves nodata cells.
+pub fn align_to(reference: &Raster, input: &Raster, method: Resampling) -> Raster {
+    let rows = reference.configs.rows;
+    let columns = reference.configs.columns;
+    let mut output = Raster::initialize_using_extent(&input.file_name, input, rows, columns,
+        reference.configs.north, reference.configs.west, reference.configs.resolution_x, reference.configs.resolution_y);
+    output.configs.south = reference.configs.south;
+    output.configs.east = reference.configs.east;
+
+    let out_nodata = output.configs.nodata;
+    let mut z: f64;
+    for row in 0..rows as isize {
+        let y = reference.get_y_from_row(row);
+        for col in 0..columns as isize {
+            z = input.value_at(reference.get_x_from_column(col), y, method);
+            output.set_value(row, col, if !input.is_nodata(z) { z } else { out_nodata });
+        }
+    }
+
+    output
+}
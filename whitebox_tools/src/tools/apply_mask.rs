@@ -0,0 +1,153 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "apply_mask".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Assigns nodata to the cells of a raster wherever a mask raster is nodata (or, optionally, zero).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+--mask             Input mask raster file.
+-o, --output       Output raster file.
+--mask_zeros       Optional flag indicating that zero-valued mask cells are also masked.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=apply_mask --wd=\"/dir/to/data\" --args=\"-i=DEM.dep --mask=watershed.dep -o=output.dep --mask_zeros\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut mask_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut mask_zeros = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-mask" || vec[0].to_lowercase() == "--mask" {
+            if keyval {
+                mask_file = vec[1].to_string();
+            } else {
+                mask_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-mask_zeros" || vec[0].to_lowercase() == "--mask_zeros" {
+            mask_zeros = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*************************");
+        println!("* Welcome to apply_mask *");
+        println!("*************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !mask_file.contains(&sep) {
+        mask_file = format!("{}{}", working_directory, mask_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let mask = Raster::new(&mask_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Applying mask...") };
+    let mut output = apply_mask(&input, &mask, mask_zeros)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' apply_mask tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Mask file: {}", mask_file));
+    output.add_metadata_entry(format!("Mask zeros: {}", mask_zeros));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Copies `input` to the output wherever `mask` holds valid data and assigns nodata elsewhere,
+/// i.e. wherever the mask is nodata or, if `mask_zeros` is set, zero. The two rasters must be
+/// aligned (see `check_alignment`).
+pub fn apply_mask(input: &Raster, mask: &Raster, mask_zeros: bool) -> Result<Raster, Error> {
+    check_alignment(input, mask)?;
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    let out_nodata = output.configs.nodata;
+    let mut m: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            m = mask[(row, col)];
+            if mask.is_nodata(m) || (mask_zeros && m == 0f64) {
+                output.set_value(row, col, out_nodata);
+            } else {
+                output.set_value(row, col, input[(row, col)]);
+            }
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,145 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::euclidean_distance::euclidean_distance;
+
+pub fn get_tool_name() -> String {
+    return "buffer".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Marks the cells within a given distance of the nearest feature cell.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input features raster file; valid non-zero cells are features.
+-o, --output       Output raster file.
+--distance         Buffer distance, in the units of the cell size.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=buffer --wd=\"/dir/to/data\" --args=\"-i=roads.dep -o=road_buffer.dep --distance=100.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut distance = -1f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-distance" || vec[0].to_lowercase() == "--distance" {
+            if keyval {
+                distance = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                distance = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if distance < 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "A non-negative buffer distance (--distance) must be specified."));
+    }
+
+    if verbose {
+        println!("*********************");
+        println!("* Welcome to buffer *");
+        println!("*********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Buffering features...") };
+    let mut output = buffer(&input, distance);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' buffer tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Distance: {}", distance));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Returns a buffer around the features of `features` (valid, non-zero cells): cells whose
+/// distance to the nearest feature, as given by `euclidean_distance`, is no more than `distance`
+/// (in the units of the cell size) are 1 and all others are nodata. The features themselves are
+/// always in the buffer.
+pub fn buffer(features: &Raster, distance: f64) -> Raster {
+    let dist = euclidean_distance(features);
+    let mut output = Raster::initialize_using_file(&features.file_name, features);
+    output.configs.photometric_interp = PhotometricInterpretation::Boolean;
+    let rows = features.configs.rows as isize;
+    let columns = features.configs.columns as isize;
+    for row in 0..rows {
+        for col in 0..columns {
+            let d = dist[(row, col)];
+            if !dist.is_nodata(d) && d <= distance {
+                output.set_value(row, col, 1f64);
+            }
+        }
+    }
+    output
+}
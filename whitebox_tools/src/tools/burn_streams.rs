@@ -0,0 +1,165 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "burn_streams".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Lowers the cells of a DEM along mapped streams so that flow routing follows the channels.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--dem              Input raster DEM file.
+--streams          Input streams raster file; valid non-zero cells are streams.
+-o, --output       Output raster file.
+--burn_depth       Depth by which stream cells are lowered, in z units; default is 10.0.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=burn_streams --wd=\"/dir/to/data\" --args=\"--dem=DEM.dep --streams=streams.dep -o=output.dep --burn_depth=5.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut dem_file = String::new();
+    let mut streams_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut burn_depth = 10f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-dem" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                dem_file = vec[1].to_string();
+            } else {
+                dem_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
+            if keyval {
+                streams_file = vec[1].to_string();
+            } else {
+                streams_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-burn_depth" || vec[0].to_lowercase() == "--burn_depth" {
+            if keyval {
+                burn_depth = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                burn_depth = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to burn_streams *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !dem_file.contains(&sep) {
+        dem_file = format!("{}{}", working_directory, dem_file);
+    }
+    if !streams_file.contains(&sep) {
+        streams_file = format!("{}{}", working_directory, streams_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let dem = Raster::new(&dem_file, "r")?;
+    let streams = Raster::new(&streams_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Burning streams...") };
+    let mut output = burn_streams(&dem, &streams, burn_depth)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' burn_streams tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", dem_file));
+    output.add_metadata_entry(format!("Streams file: {}", streams_file));
+    output.add_metadata_entry(format!("Burn depth: {}", burn_depth));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Lowers the DEM by `burn_depth` wherever the streams raster has a valid, non-zero value, so
+/// that flow routing follows the mapped channels. The two rasters must be aligned (see
+/// `check_alignment`). Nodata DEM cells remain nodata.
+pub fn burn_streams(dem: &Raster, streams: &Raster, burn_depth: f64) -> Result<Raster, Error> {
+    check_alignment(dem, streams)?;
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    // A fractional burn depth would be truncated in an integer DEM's type.
+    if output.configs.data_type != DataType::F64 {
+        output.configs.data_type = DataType::F32;
+    }
+    let out_nodata = output.configs.nodata;
+    let (mut z, mut s): (f64, f64);
+    for row in 0..dem.configs.rows as isize {
+        for col in 0..dem.configs.columns as isize {
+            z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                output.set_value(row, col, out_nodata);
+                continue;
+            }
+            s = streams[(row, col)];
+            if !streams.is_nodata(s) && s != 0f64 {
+                output.set_value(row, col, z - burn_depth);
+            } else {
+                output.set_value(row, col, z);
+            }
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,191 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::resample::{sample, Resampling};
+
+pub fn get_tool_name() -> String {
+    return "cast_shadows".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Identifies the cells that lie in the cast shadow of surrounding terrain for a given sun position.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--azimuth          Sun azimuth, in degrees clockwise from north; default is 315.0.
+--altitude         Sun altitude above the horizon, in degrees; default is 30.0.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=cast_shadows --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=shadows.dep --azimuth=135.0 --altitude=20.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut azimuth = 315f64;
+    let mut altitude = 30f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-azimuth" || vec[0].to_lowercase() == "--azimuth" {
+            if keyval {
+                azimuth = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                azimuth = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-altitude" || vec[0].to_lowercase() == "--altitude" {
+            if keyval {
+                altitude = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                altitude = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to cast_shadows *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Casting shadows...") };
+    let mut output = cast_shadows(&input, azimuth, altitude);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' cast_shadows tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Sun azimuth: {}", azimuth));
+    output.add_metadata_entry(format!("Sun altitude: {}", altitude));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Identifies the grid cells in the cast shadow of surrounding terrain when the sun is at the
+/// given azimuth (degrees clockwise from north) and altitude (degrees above the horizon). A
+/// ray is marched from each cell centre towards the sun in steps of the smaller cell
+/// dimension, sampling the DEM by bilinear interpolation, and the cell is in shadow if the
+/// terrain rises above the ray at any step. The march ends once the ray rises above the
+/// highest elevation in the DEM, or at the first sample that is off the grid or involves a
+/// nodata cell; the terrain beyond is treated as not blocking the sun. With the sun at or below
+/// the horizon every cell is in shadow.
+///
+/// The output is 1 for shadowed cells and 0 for lit cells; nodata cells remain nodata.
+pub fn cast_shadows(dem: &Raster, azimuth: f64, altitude: f64) -> Raster {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    let step = res_x.min(res_y);
+    // The change in column, row and ray height per step towards the sun.
+    let d_col = azimuth.to_radians().sin() * step / res_x;
+    let d_row = -azimuth.to_radians().cos() * step / res_y;
+    let d_z = altitude.to_radians().tan() * step;
+    let (_, max_z) = dem.min_max();
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.photometric_interp = PhotometricInterpretation::Boolean;
+    let (mut row_f, mut col_f, mut ray_z, mut z): (f64, f64, f64, f64);
+    for row in 0..rows {
+        for col in 0..columns {
+            let z0 = dem[(row, col)];
+            if dem.is_nodata(z0) {
+                continue;
+            }
+            if altitude <= 0f64 {
+                output.set_value(row, col, 1f64);
+                continue;
+            }
+            let mut in_shadow = false;
+            row_f = row as f64;
+            col_f = col as f64;
+            ray_z = z0;
+            loop {
+                row_f += d_row;
+                col_f += d_col;
+                ray_z += d_z;
+                if ray_z > max_z {
+                    break;
+                }
+                z = sample(dem, row_f, col_f, Resampling::Bilinear);
+                if dem.is_nodata(z) {
+                    break;
+                }
+                if z > ray_z {
+                    in_shadow = true;
+                    break;
+                }
+            }
+            output.set_value(row, col, if in_shadow { 1f64 } else { 0f64 });
+        }
+    }
+
+    output
+}
@@ -0,0 +1,151 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "clip".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Clips a raster to a rectangular extent given in world coordinates.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--extent           Clip extent as min_x,min_y,max_x,max_y in world coordinates.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=clip --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --extent=665000.0,4880000.0,670000.0,4885000.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut extent = String::new();
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-extent" || vec[0].to_lowercase() == "--extent" {
+            if keyval {
+                extent = vec[1].to_string();
+            } else {
+                extent = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    let extent_vals: Vec<f64> = extent.split(",")
+                                      .filter(|s| !s.trim().is_empty())
+                                      .map(|s| s.trim().parse::<f64>().unwrap())
+                                      .collect();
+    if extent_vals.len() != 4 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --extent parameter must contain four values: min_x,min_y,max_x,max_y."));
+    }
+
+    if verbose {
+        println!("*******************");
+        println!("* Welcome to clip *");
+        println!("*******************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Clipping...") };
+    let mut output = clip(&input, extent_vals[0], extent_vals[1], extent_vals[2], extent_vals[3])?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' clip tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Clip extent: {}", extent));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Clips the input to the cells that intersect a rectangular extent in world coordinates. The
+/// extent is clamped to the raster's bounds; an extent lying entirely outside the raster is an
+/// error.
+pub fn clip(input: &Raster, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Result<Raster, Error> {
+    if min_x >= max_x || min_y >= max_y {
+        return Err(Error::new(ErrorKind::InvalidInput, "The clip extent's minimum coordinates must be less than its maximum coordinates."));
+    }
+    if max_x <= input.configs.west || min_x >= input.configs.east ||
+        max_y <= input.configs.south || min_y >= input.configs.north {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("The clip extent lies entirely outside of {}.", input.file_name)));
+    }
+    let last_row = input.configs.rows as isize - 1;
+    let last_col = input.configs.columns as isize - 1;
+    let clamp = |v: isize, max: isize| if v < 0 { 0 } else if v > max { max } else { v };
+    let start_row = clamp(((input.configs.north - max_y) / input.configs.resolution_y).floor() as isize, last_row);
+    let end_row = clamp(((input.configs.north - min_y) / input.configs.resolution_y).ceil() as isize - 1, last_row);
+    let start_col = clamp(((min_x - input.configs.west) / input.configs.resolution_x).floor() as isize, last_col);
+    let end_col = clamp(((max_x - input.configs.west) / input.configs.resolution_x).ceil() as isize - 1, last_col);
+
+    let rows = (end_row - start_row + 1) as usize;
+    let columns = (end_col - start_col + 1) as usize;
+    Ok(input.subset(start_row, start_col, rows, columns))
+}
@@ -0,0 +1,137 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::structuring_element::StructuringElement;
+use tools::dilation::dilation;
+use tools::erosion::erosion;
+
+pub fn get_tool_name() -> String {
+    return "closing".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Performs a morphological closing (a dilation followed by an erosion) on a raster using a square or circular structuring element.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--filter           Structuring element size (cells); default is 11.
+--shape            Structuring element shape, 'square' or 'circle'; default is square.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=closing --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --filter=25 --shape=circle\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut filter_size = 11usize;
+    let mut shape = StructuringElement::Square;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-shape" || vec[0].to_lowercase() == "--shape" {
+            if keyval {
+                shape = StructuringElement::from_str(vec[1]);
+            } else {
+                shape = StructuringElement::from_str(&args[i+1]);
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("**********************");
+        println!("* Welcome to closing *");
+        println!("**********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Performing closing...") };
+    let mut output = closing(&input, filter_size, shape);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' closing tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Structuring element: {:?}", shape));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// A dilation followed by an erosion. Fills pits and troughs that are narrower than the
+/// structuring element while leaving the broader surface intact.
+pub fn closing(input: &Raster, filter_size: usize, shape: StructuringElement) -> Raster {
+    erosion(&dilation(input, filter_size, shape), filter_size, shape)
+}
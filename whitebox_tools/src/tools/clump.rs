@@ -0,0 +1,205 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "clump".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Assigns a unique id to each contiguous patch of equal-valued cells.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--diag             Optional flag indicating that diagonal neighbours are connected (8-connectivity).
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=clump --wd=\"/dir/to/data\" --args=\"-i=landcover.dep -o=patches.dep --diag\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut diagonals = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-diag" || vec[0].to_lowercase() == "--diag" {
+            diagonals = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("********************");
+        println!("* Welcome to clump *");
+        println!("********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Clumping...") };
+    let mut output = clump(&input, diagonals);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' clump tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Diagonal connectivity: {}", diagonals));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Assigns each connected region of equal-valued cells a unique id, numbered contiguously from
+/// 1 in row-major order of the regions' first cells. Cells connect to their 4 orthogonal
+/// neighbours, plus the 4 diagonal neighbours if `diagonals` is true. Nodata cells remain
+/// nodata.
+pub fn clump(input: &Raster, diagonals: bool) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    // Only the neighbours already visited in a row-major scan need to be checked.
+    let prior: Vec<(isize, isize)> = if diagonals {
+        vec![(0, -1), (-1, -1), (-1, 0), (-1, 1)]
+    } else {
+        vec![(0, -1), (-1, 0)]
+    };
+
+    let mut sets = UnionFind::new((rows * columns) as usize);
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input[(row, col)];
+            if input.is_nodata(z) {
+                continue;
+            }
+            for &(d_y, d_x) in &prior {
+                if input[(row + d_y, col + d_x)] == z {
+                    sets.union((row * columns + col) as usize, ((row + d_y) * columns + col + d_x) as usize);
+                }
+            }
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    // F32 holds every integer id up to 2^24 exactly and, unlike I32, every raster format can store it
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+    let out_nodata = output.configs.nodata;
+    let mut ids = vec![0usize; (rows * columns) as usize];
+    let mut num_regions = 0usize;
+    let mut root: usize;
+    for row in 0..rows {
+        for col in 0..columns {
+            if input.is_nodata(input[(row, col)]) {
+                output.set_value(row, col, out_nodata);
+                continue;
+            }
+            root = sets.find((row * columns + col) as usize);
+            if ids[root] == 0 {
+                num_regions += 1;
+                ids[root] = num_regions;
+            }
+            output.set_value(row, col, ids[root] as f64);
+        }
+    }
+
+    output
+}
+
+/// A disjoint-set forest with path halving and union by size.
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> UnionFind {
+        UnionFind { parent: (0..n).collect(), size: vec![1; n] }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            let tmp = a;
+            a = b;
+            b = tmp;
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
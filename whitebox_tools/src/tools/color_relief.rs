@@ -0,0 +1,142 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::rgb_image::RgbImage;
+use structures::color_ramp::ColorRamp;
+
+pub fn get_tool_name() -> String {
+    return "color_relief".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Maps a single-band raster through a colour ramp and writes the result as a PNG image.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output PNG file.
+--palette          Colour ramp file with one 'value, r, g, b' stop per line (e.g. a QGIS colour map export).
+--background       Optional nodata colour as r,g,b; by default nodata cells are transparent.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=color_relief --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=relief.png --palette=elevation_ramp.txt --background=255,255,255\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut palette_file = String::new();
+    let mut background = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-palette" || vec[0].to_lowercase() == "--palette" {
+            if keyval {
+                palette_file = vec[1].to_string();
+            } else {
+                palette_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-background" || vec[0].to_lowercase() == "--background" {
+            if keyval {
+                background = vec[1].to_string();
+            } else {
+                background = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    let background_rgb: Vec<u8> = background.split(",")
+                                            .filter(|s| !s.trim().is_empty())
+                                            .map(|s| s.trim().parse::<u8>().unwrap())
+                                            .collect();
+    if background_rgb.len() != 0 && background_rgb.len() != 3 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --background parameter must contain three values: r,g,b."));
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to color_relief *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !palette_file.contains(&sep) {
+        palette_file = format!("{}{}", working_directory, palette_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let ramp = ColorRamp::from_file(&palette_file)?;
+
+    let start = time::now();
+
+    if verbose { println!("Applying colour ramp...") };
+    let mut image = color_relief(&input, &ramp);
+    if background_rgb.len() == 3 {
+        image.fill_transparent(background_rgb[0], background_rgb[1], background_rgb[2]);
+    }
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let _ = match image.write_png(&output_file) {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+    if verbose {
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Colours each cell of the input using the ramp. Nodata cells are left fully transparent.
+pub fn color_relief(input: &Raster, ramp: &ColorRamp) -> RgbImage {
+    let mut image = RgbImage::new(input.configs.columns, input.configs.rows);
+    for (row, col, z) in input.iter_valid() {
+        let (r, g, b) = ramp.get_color(z);
+        image.set_pixel(row as usize, col as usize, r, g, b, 255);
+    }
+
+    image
+}
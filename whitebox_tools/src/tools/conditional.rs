@@ -0,0 +1,219 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "conditional".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Assigns one of two values to each cell depending on whether it satisfies a comparison (raster if/then/else).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--statement        Comparison, e.g. \"> 30\" or \"value <= 12.5\"; operators are <, <=, >, >=, == and !=.
+--true_value       Value where the statement is true: a number, 'input' or 'nodata'; default is 1.
+--false_value      Value where the statement is false: a number, 'input' or 'nodata'; default is nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=conditional --wd=\"/dir/to/data\" --args=\"-i=slope.dep -o=output.dep --statement=\">30\" --true_value=1 --false_value=nodata\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut statement = String::new();
+    let mut true_val = 1f64;
+    let mut false_val = f64::NEG_INFINITY;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-statement" || vec[0].to_lowercase() == "--statement" {
+            if keyval {
+                // The statement itself may contain equals signs, e.g. "<= 30".
+                statement = vec[1..].join("=");
+            } else {
+                statement = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-true_value" || vec[0].to_lowercase() == "--true_value" {
+            if keyval {
+                true_val = match vec[1].to_lowercase().as_ref() {
+                    "input" => f64::NAN,
+                    "nodata" => f64::NEG_INFINITY,
+                    v => v.parse::<f64>().unwrap(),
+                };
+            } else {
+                true_val = match args[i+1].to_lowercase().as_ref() {
+                    "input" => f64::NAN,
+                    "nodata" => f64::NEG_INFINITY,
+                    v => v.parse::<f64>().unwrap(),
+                };
+            }
+        } else if vec[0].to_lowercase() == "-false_value" || vec[0].to_lowercase() == "--false_value" {
+            if keyval {
+                false_val = match vec[1].to_lowercase().as_ref() {
+                    "input" => f64::NAN,
+                    "nodata" => f64::NEG_INFINITY,
+                    v => v.parse::<f64>().unwrap(),
+                };
+            } else {
+                false_val = match args[i+1].to_lowercase().as_ref() {
+                    "input" => f64::NAN,
+                    "nodata" => f64::NEG_INFINITY,
+                    v => v.parse::<f64>().unwrap(),
+                };
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    let (op, threshold) = parse_statement(&statement)?;
+
+    if verbose {
+        println!("**************************");
+        println!("* Welcome to conditional *");
+        println!("**************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    // 'nodata' was parsed as negative infinity, since the input's nodata value wasn't known yet.
+    if true_val == f64::NEG_INFINITY { true_val = input.configs.nodata; }
+    if false_val == f64::NEG_INFINITY { false_val = input.configs.nodata; }
+
+    if verbose { println!("Evaluating statement...") };
+    let mut output = conditional(&input, op, threshold, true_val, false_val);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' conditional tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Statement: {}", statement));
+    output.add_metadata_entry(format!("True value: {}", true_val));
+    output.add_metadata_entry(format!("False value: {}", false_val));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CompareOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl CompareOp {
+    pub fn evaluate(&self, a: f64, b: f64) -> bool {
+        match *self {
+            CompareOp::Lt => a < b,
+            CompareOp::Le => a <= b,
+            CompareOp::Gt => a > b,
+            CompareOp::Ge => a >= b,
+            CompareOp::Eq => a == b,
+            CompareOp::Ne => a != b,
+        }
+    }
+}
+
+/// Parses a statement such as "> 30", "value <= 12.5" or "!=0" into an operator and threshold.
+/// Any leading word naming the input is ignored.
+pub fn parse_statement<'a>(statement: &'a str) -> Result<(CompareOp, f64), Error> {
+    let s = statement.trim().trim_start_matches(|c: char| c.is_alphabetic() || c == '_').trim();
+    let ops = [("<=", CompareOp::Le), (">=", CompareOp::Ge), ("==", CompareOp::Eq), ("!=", CompareOp::Ne),
+               ("<>", CompareOp::Ne), ("<", CompareOp::Lt), (">", CompareOp::Gt), ("=", CompareOp::Eq)];
+    for &(symbol, op) in ops.iter() {
+        if s.starts_with(symbol) {
+            return match s[symbol.len()..].trim().parse::<f64>() {
+                Ok(threshold) => Ok((op, threshold)),
+                Err(_) => Err(Error::new(ErrorKind::InvalidInput, format!("The statement '{}' does not end with a numeric threshold.", statement))),
+            };
+        }
+    }
+    Err(Error::new(ErrorKind::InvalidInput, format!("The statement '{}' does not contain a comparison operator (<, <=, >, >=, ==, !=).", statement)))
+}
+
+/// Assigns `true_val` where `input op threshold` holds and `false_val` elsewhere. A value of
+/// NaN passes the input value through instead; pass `input.configs.nodata` to assign nodata.
+/// Nodata input cells are always nodata in the output.
+pub fn conditional(input: &Raster, op: CompareOp, threshold: f64, true_val: f64, false_val: f64) -> Raster {
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    let out_nodata = output.configs.nodata;
+    let mut z_n: f64;
+    for (row, col, z) in input.iter_valid() {
+        z_n = if op.evaluate(z, threshold) { true_val } else { false_val };
+        if z_n.is_nan() {
+            z_n = z;
+        }
+        output.set_value(row, col, if !input.is_nodata(z_n) { z_n } else { out_nodata });
+    }
+
+    output
+}
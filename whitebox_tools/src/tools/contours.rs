@@ -0,0 +1,261 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::collections::HashMap;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use vector::Polyline;
+use vector::geojson;
+
+pub fn get_tool_name() -> String {
+    return "contours".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Traces contour lines from a DEM and writes them as GeoJSON LineString features.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster DEM file.
+-o, --output       Output GeoJSON file.
+--interval         Contour interval.
+--base             Optional base contour elevation; default is 0.0.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=contours --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=contours.geojson --interval=10.0 --base=0.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut interval = 0f64;
+    let mut base = 0f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-interval" || vec[0].to_lowercase() == "--interval" {
+            if keyval {
+                interval = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                interval = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-base" || vec[0].to_lowercase() == "--base" {
+            if keyval {
+                base = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                base = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if interval <= 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --interval parameter must be greater than zero."));
+    }
+
+    if verbose {
+        println!("***********************");
+        println!("* Welcome to contours *");
+        println!("***********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Tracing contours...") };
+    let lines = contours(&input, interval, base);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let _ = match geojson::write_polylines(&output_file, &lines, "ELEV") {
+        Ok(_) => if verbose { println!("Output file written ({} contours)", lines.len()) },
+        Err(e) => return Err(e),
+    };
+    if verbose {
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Identifies the crossing point of contour `level` with the grid edge joining cell centre
+/// (row, col) to its neighbour to the east (`vertical` false) or south (`vertical` true).
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct EdgeKey {
+    level: i64,
+    row: isize,
+    col: isize,
+    vertical: bool,
+}
+
+/// Traces contours at `base + k * interval` using marching squares over the lattice of cell
+/// centres, returning polylines in world coordinates with each contour's elevation as its
+/// value. A corner counts as above the contour when its value is greater than or equal to the
+/// contour, so lines never pass exactly through a cell centre. Squares with any nodata corner
+/// are skipped, which breaks contours at nodata areas and the grid edge.
+///
+/// Saddle squares, where diagonally opposite corners lie on the same side of the contour, are
+/// resolved with the mean of the four corners: if the mean is above the contour the two high
+/// corners are treated as connected, otherwise the two low corners are.
+pub fn contours(dem: &Raster, interval: f64, base: f64) -> Vec<Polyline> {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+
+    let mut segments: Vec<(EdgeKey, EdgeKey)> = vec![];
+    let mut z = [0f64; 4];
+    for row in 0..rows - 1 {
+        for col in 0..columns - 1 {
+            // corners in the order top-left, top-right, bottom-right, bottom-left
+            z[0] = dem[(row, col)];
+            z[1] = dem[(row, col + 1)];
+            z[2] = dem[(row + 1, col + 1)];
+            z[3] = dem[(row + 1, col)];
+            if z.iter().any(|&v| dem.is_nodata(v)) {
+                continue;
+            }
+            let min = z.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = z.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let k_start = ((min - base) / interval).ceil() as i64;
+            let k_end = ((max - base) / interval).floor() as i64;
+            for k in k_start..k_end + 1 {
+                let level = base + k as f64 * interval;
+                let top = EdgeKey { level: k, row: row, col: col, vertical: false };
+                let right = EdgeKey { level: k, row: row, col: col + 1, vertical: true };
+                let bottom = EdgeKey { level: k, row: row + 1, col: col, vertical: false };
+                let left = EdgeKey { level: k, row: row, col: col, vertical: true };
+                let mut case = 0usize;
+                if z[0] >= level { case |= 8; }
+                if z[1] >= level { case |= 4; }
+                if z[2] >= level { case |= 2; }
+                if z[3] >= level { case |= 1; }
+                let centre_above = (z[0] + z[1] + z[2] + z[3]) / 4f64 >= level;
+                match case {
+                    1 | 14 => segments.push((left, bottom)),
+                    2 | 13 => segments.push((bottom, right)),
+                    3 | 12 => segments.push((left, right)),
+                    4 | 11 => segments.push((top, right)),
+                    6 | 9 => segments.push((top, bottom)),
+                    7 | 8 => segments.push((left, top)),
+                    5 => {
+                        if centre_above {
+                            segments.push((left, top));
+                            segments.push((bottom, right));
+                        } else {
+                            segments.push((top, right));
+                            segments.push((left, bottom));
+                        }
+                    },
+                    10 => {
+                        if centre_above {
+                            segments.push((top, right));
+                            segments.push((left, bottom));
+                        } else {
+                            segments.push((left, top));
+                            segments.push((bottom, right));
+                        }
+                    },
+                    _ => {}, // 0 and 15: the contour doesn't cross this square
+                }
+            }
+        }
+    }
+
+    // Each crossing point is shared by at most two segments, so the segments can be chained
+    // into lines by walking from one segment to the other at each end.
+    let mut segments_at: HashMap<EdgeKey, Vec<usize>> = HashMap::new();
+    for (i, &(a, b)) in segments.iter().enumerate() {
+        segments_at.entry(a).or_insert(vec![]).push(i);
+        segments_at.entry(b).or_insert(vec![]).push(i);
+    }
+    let mut used = vec![false; segments.len()];
+    let mut lines = vec![];
+    for i in 0..segments.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+        let (first, last) = segments[i];
+        let mut forward = vec![first, last];
+        extend_chain(&mut forward, &segments, &segments_at, &mut used);
+        let mut backward = vec![first];
+        extend_chain(&mut backward, &segments, &segments_at, &mut used);
+        backward.reverse();
+        backward.pop();
+        backward.extend(forward);
+
+        let points = backward.iter().map(|edge| crossing_point(dem, interval, base, edge)).collect();
+        lines.push(Polyline::new(points, base + backward[0].level as f64 * interval));
+    }
+
+    lines
+}
+
+/// Appends the far ends of unused segments to the chain until it can't be extended further.
+fn extend_chain(chain: &mut Vec<EdgeKey>, segments: &[(EdgeKey, EdgeKey)], segments_at: &HashMap<EdgeKey, Vec<usize>>, used: &mut [bool]) {
+    loop {
+        let end = chain[chain.len() - 1];
+        let next = segments_at[&end].iter().cloned().find(|&j| !used[j]);
+        match next {
+            Some(j) => {
+                used[j] = true;
+                chain.push(if segments[j].0 == end { segments[j].1 } else { segments[j].0 });
+            },
+            None => return,
+        }
+    }
+}
+
+fn crossing_point(dem: &Raster, interval: f64, base: f64, edge: &EdgeKey) -> (f64, f64) {
+    let level = base + edge.level as f64 * interval;
+    let (row2, col2) = if edge.vertical { (edge.row + 1, edge.col) } else { (edge.row, edge.col + 1) };
+    let z1 = dem[(edge.row, edge.col)];
+    let z2 = dem[(row2, col2)];
+    let t = (level - z1) / (z2 - z1);
+    let row = edge.row as f64 + t * (row2 - edge.row) as f64;
+    let col = edge.col as f64 + t * (col2 - edge.col) as f64;
+    (dem.configs.west + (col + 0.5) * dem.configs.resolution_x,
+     dem.configs.north - (row + 0.5) * dem.configs.resolution_y)
+}
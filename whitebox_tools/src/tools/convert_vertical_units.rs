@@ -0,0 +1,162 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "convert_vertical_units".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Converts the vertical units of a DEM, e.g. from feet to meters, by multiplying its valid cells by a factor.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--factor           Factor by which elevations are multiplied.
+--feet_to_meters   Converts elevations in feet to meters, i.e. uses a factor of 0.3048.
+--meters_to_feet   Converts elevations in meters to feet, i.e. uses a factor of 1 / 0.3048.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=convert_vertical_units --wd=\"/dir/to/data\" --args=\"-i=DEM_ft.dep -o=DEM_m.dep --feet_to_meters\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut factor = 0f64;
+    let mut feet_to_meters = false;
+    let mut meters_to_feet = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-factor" || vec[0].to_lowercase() == "--factor" {
+            if keyval {
+                factor = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                factor = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-feet_to_meters" || vec[0].to_lowercase() == "--feet_to_meters" {
+            feet_to_meters = true;
+        } else if vec[0].to_lowercase() == "-meters_to_feet" || vec[0].to_lowercase() == "--meters_to_feet" {
+            meters_to_feet = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    let presets = [factor != 0f64, feet_to_meters, meters_to_feet].iter().filter(|&&given| given).count();
+    if presets != 1 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Exactly one of --factor, --feet_to_meters and --meters_to_feet must be specified."));
+    }
+    if feet_to_meters {
+        factor = METERS_PER_FOOT;
+    } else if meters_to_feet {
+        factor = 1f64 / METERS_PER_FOOT;
+    }
+
+    if verbose {
+        println!("*************************************");
+        println!("* Welcome to convert_vertical_units *");
+        println!("*************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Converting vertical units...") };
+    let mut output = convert_vertical_units(&input, factor);
+    if feet_to_meters {
+        output.configs.z_units = "metres".to_string();
+    } else if meters_to_feet {
+        output.configs.z_units = "feet".to_string();
+    }
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' convert_vertical_units tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// The length of the international foot, in meters.
+pub const METERS_PER_FOOT: f64 = 0.3048;
+
+/// Converts the vertical units of a DEM by multiplying each of its valid cells by `factor`,
+/// e.g. `METERS_PER_FOOT` to convert elevations in feet to meters. Nodata cells are left
+/// untouched, and integer DEMs are output as 32-bit floats so that the converted values are
+/// not truncated. The conversion is recorded in the output's metadata.
+pub fn convert_vertical_units(dem: &Raster, factor: f64) -> Raster {
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    if output.configs.data_type != DataType::F64 {
+        output.configs.data_type = DataType::F32;
+    }
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for (row, col, z) in dem.iter_valid() {
+        output.set_value(row, col, z * factor);
+    }
+    output.add_metadata_entry(format!("Vertical units converted by a factor of {}", factor));
+
+    output
+}
@@ -0,0 +1,242 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use std::collections::BinaryHeap;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::grid_cell::GridCell;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "cost_distance".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the least accumulated cost of travel from source cells across a friction surface.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--source           Input source raster file; valid non-zero cells are sources.
+--cost             Input friction (cost) raster file.
+--out_accum        Output accumulated cost raster file.
+--out_backlink     Output backlink raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=cost_distance --wd=\"/dir/to/data\" --args=\"--source=sources.dep --cost=friction.dep --out_accum=accum.dep --out_backlink=backlink.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut source_file = String::new();
+    let mut cost_file = String::new();
+    let mut accum_file = String::new();
+    let mut backlink_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-source" || vec[0].to_lowercase() == "--source" {
+            if keyval {
+                source_file = vec[1].to_string();
+            } else {
+                source_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-cost" || vec[0].to_lowercase() == "--cost" {
+            if keyval {
+                cost_file = vec[1].to_string();
+            } else {
+                cost_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-out_accum" || vec[0].to_lowercase() == "--out_accum" {
+            if keyval {
+                accum_file = vec[1].to_string();
+            } else {
+                accum_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-out_backlink" || vec[0].to_lowercase() == "--out_backlink" {
+            if keyval {
+                backlink_file = vec[1].to_string();
+            } else {
+                backlink_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("****************************");
+        println!("* Welcome to cost_distance *");
+        println!("****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !source_file.contains(&sep) {
+        source_file = format!("{}{}", working_directory, source_file);
+    }
+    if !cost_file.contains(&sep) {
+        cost_file = format!("{}{}", working_directory, cost_file);
+    }
+    if !accum_file.contains(&sep) {
+        accum_file = format!("{}{}", working_directory, accum_file);
+    }
+    if !backlink_file.contains(&sep) {
+        backlink_file = format!("{}{}", working_directory, backlink_file);
+    }
+    check_output_file(&accum_file, overwrite)?;
+    check_output_file(&backlink_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let sources = Raster::new(&source_file, "r")?;
+    let cost = Raster::new(&cost_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating cost distance...") };
+    let (mut accum, mut backlink) = cost_distance(&sources, &cost)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    accum.set_file_name(&accum_file);
+    backlink.set_file_name(&backlink_file);
+    for output in [&mut accum, &mut backlink].iter_mut() {
+        output.add_metadata_entry("Created by whitebox_tools\' cost_distance tool".to_owned());
+        output.add_metadata_entry(format!("Source file: {}", source_file));
+        output.add_metadata_entry(format!("Cost file: {}", cost_file));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        accum.configs.compression = compression;
+    }
+    let _ = match accum.write() {
+        Ok(_) => if verbose { println!("Accumulated cost file written") },
+        Err(e) => return Err(e),
+    };
+    if let Some(compression) = compression {
+        backlink.configs.compression = compression;
+    }
+    let _ = match backlink.write() {
+        Ok(_) => if verbose { println!("Backlink file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the least accumulated cost of travelling from the nearest source cell (any valid,
+/// non-zero cell of `sources`) across the friction surface `cost`, using Dijkstra's algorithm.
+/// A move between neighbouring cells costs the mean friction of the two cells times the distance
+/// between their centres, so diagonal moves are scaled by the diagonal cell length. Nodata or
+/// negative friction cells are impassable. The two rasters must be aligned (see
+/// `check_alignment`).
+///
+/// Returns the accumulated cost and a backlink raster giving, for each cell, the direction of the
+/// neighbour it was reached from: 1 = NE, 2 = E, 4 = SE, 8 = S, 16 = SW, 32 = W, 64 = NW,
+/// 128 = N, and 0 at the sources. Unreachable cells are nodata in both.
+pub fn cost_distance(sources: &Raster, cost: &Raster) -> Result<(Raster, Raster), Error> {
+    check_alignment(sources, cost)?;
+    let rows = cost.configs.rows as isize;
+    let columns = cost.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let res_x = cost.configs.resolution_x;
+    let res_y = cost.configs.resolution_y;
+    let diag = (res_x * res_x + res_y * res_y).sqrt();
+    let dist = [ diag, res_x, diag, res_y, diag, res_x, diag, res_y ];
+
+    let mut accum = vec![f64::INFINITY; (rows * columns) as usize];
+    let mut backlink = vec![-1f64; (rows * columns) as usize];
+    let mut heap = BinaryHeap::new();
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = sources.get_value(row, col);
+            if !sources.is_nodata(z) && z != 0f64 && is_passable(cost, cost[(row, col)]) {
+                accum[(row * columns + col) as usize] = 0f64;
+                backlink[(row * columns + col) as usize] = 0f64;
+                heap.push(GridCell::new(row, col, 0f64));
+            }
+        }
+    }
+
+    let (mut row_n, mut col_n, mut idx_n): (isize, isize, usize);
+    let (mut friction, mut friction_n, mut accum_n): (f64, f64, f64);
+    while let Some(cell) = heap.pop() {
+        let idx = (cell.row * columns + cell.column) as usize;
+        if cell.priority > accum[idx] {
+            continue; // a stale entry; the cell was already reached more cheaply
+        }
+        friction = cost[(cell.row, cell.column)];
+        for n in 0..8 {
+            row_n = cell.row + d_y[n];
+            col_n = cell.column + d_x[n];
+            if row_n < 0 || col_n < 0 || row_n >= rows || col_n >= columns {
+                continue;
+            }
+            friction_n = cost[(row_n, col_n)];
+            if !is_passable(cost, friction_n) {
+                continue;
+            }
+            accum_n = cell.priority + (friction + friction_n) / 2f64 * dist[n];
+            idx_n = (row_n * columns + col_n) as usize;
+            if accum_n < accum[idx_n] {
+                accum[idx_n] = accum_n;
+                // The backlink points from the neighbour back to this cell, the opposite direction.
+                backlink[idx_n] = (1 << ((n + 4) % 8)) as f64;
+                heap.push(GridCell::new(row_n, col_n, accum_n));
+            }
+        }
+    }
+
+    let mut accum_output = Raster::initialize_using_file(&cost.file_name, cost);
+    let mut backlink_output = Raster::initialize_using_file(&cost.file_name, cost);
+    accum_output.configs.data_type = DataType::F32;
+    accum_output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    backlink_output.set_data_type(DataType::I16);
+    backlink_output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+    let accum_nodata = accum_output.configs.nodata;
+    let backlink_nodata = backlink_output.configs.nodata;
+    for row in 0..rows {
+        for col in 0..columns {
+            let idx = (row * columns + col) as usize;
+            if accum[idx] != f64::INFINITY {
+                accum_output.set_value(row, col, accum[idx]);
+                backlink_output.set_value(row, col, backlink[idx]);
+            } else {
+                accum_output.set_value(row, col, accum_nodata);
+                backlink_output.set_value(row, col, backlink_nodata);
+            }
+        }
+    }
+
+    Ok((accum_output, backlink_output))
+}
+
+fn is_passable(cost: &Raster, friction: f64) -> bool {
+    !cost.is_nodata(friction) && friction >= 0f64
+}
@@ -0,0 +1,252 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "d8_flow_accumulation".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates D8 flow accumulation from a flow pointer raster, optionally accumulating a weight raster such as rainfall.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input D8 flow pointer raster file.
+-o, --output       Output raster file.
+--weights          Optional input weight raster file; each cell contributes its weight rather than 1.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=d8_flow_accumulation --wd=\"/dir/to/data\" --args=\"-i=pointer.dep -o=output.dep --weights=rainfall.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut weights_file = String::new();
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-weights" || vec[0].to_lowercase() == "--weights" {
+            if keyval {
+                weights_file = vec[1].to_string();
+            } else {
+                weights_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***********************************");
+        println!("* Welcome to d8_flow_accumulation *");
+        println!("***********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let weights = if !weights_file.is_empty() {
+        if !weights_file.contains(&sep) {
+            weights_file = format!("{}{}", working_directory, weights_file);
+        }
+        Some(Raster::new(&weights_file, "r")?)
+    } else {
+        None
+    };
+
+    let start = time::now();
+
+    if verbose { println!("Calculating flow accumulation...") };
+    let mut output = d8_flow_accumulation(&input, weights.as_ref())?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' d8_flow_accumulation tool".to_owned());
+    output.add_metadata_entry(format!("Pointer file: {}", input_file));
+    if !weights_file.is_empty() {
+        output.add_metadata_entry(format!("Weights file: {}", weights_file));
+    }
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates D8 flow accumulation from a flow pointer raster, in which each cell's value gives
+/// the direction of the neighbour it drains to: 1 = NE, 2 = E, 4 = SE, 8 = S, 16 = SW, 32 = W,
+/// 64 = NW, 128 = N, and 0 for cells that don't drain to a neighbour (outlets and sinks). Flow
+/// directed off the grid or into a nodata cell leaves the grid.
+///
+/// Without `weights`, each cell contributes 1 and the output is the number of cells draining
+/// through each cell, including itself. With `weights`, which must be aligned with the pointer
+/// raster (see `check_alignment`), each cell contributes its weight instead (e.g. rainfall);
+/// nodata weights contribute zero but still pass on the flow they receive. Nodata pointer cells
+/// are nodata in the output.
+pub fn d8_flow_accumulation(pointer: &Raster, weights: Option<&Raster>) -> Result<Raster, Error> {
+    if let Some(w) = weights {
+        check_alignment(pointer, w)?;
+    }
+    let rows = pointer.configs.rows as isize;
+    let columns = pointer.configs.columns as isize;
+
+    let mut accum = vec![0f64; (rows * columns) as usize];
+    for row in 0..rows {
+        for col in 0..columns {
+            if pointer.is_nodata(pointer[(row, col)]) {
+                continue;
+            }
+            accum[(row * columns + col) as usize] = match weights {
+                Some(w) => {
+                    let z = w[(row, col)];
+                    if !w.is_nodata(z) { z } else { 0f64 }
+                },
+                None => 1f64,
+            };
+        }
+    }
+    let mut accumulators = vec![accum];
+    accumulate_downstream(pointer, &mut accumulators);
+    let accum = &accumulators[0];
+
+    let mut output = Raster::initialize_using_file(&pointer.file_name, pointer);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let out_nodata = output.configs.nodata;
+    for row in 0..rows {
+        for col in 0..columns {
+            if !pointer.is_nodata(pointer[(row, col)]) {
+                output.set_value(row, col, accum[(row * columns + col) as usize]);
+            } else {
+                output.set_value(row, col, out_nodata);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Accumulates each of the row-major `accumulators`, which hold every cell's own contribution
+/// on entry, downstream along a D8 flow pointer raster (encoded as for `d8_flow_accumulation`),
+/// so that on return each cell holds the total of its own and all upstream cells'
+/// contributions. All accumulators are carried in a single traversal. Flow directed off the
+/// grid or into a nodata cell leaves the grid, and nodata pointer cells are left untouched.
+pub fn accumulate_downstream(pointer: &Raster, accumulators: &mut [Vec<f64>]) {
+    let rows = pointer.configs.rows as isize;
+    let columns = pointer.configs.columns as isize;
+    let flows_to = |row: isize, col: isize| d8_receiver(pointer, row, col);
+
+    let mut num_inflowing = vec![0u8; (rows * columns) as usize];
+    for row in 0..rows {
+        for col in 0..columns {
+            if pointer.is_nodata(pointer[(row, col)]) {
+                continue;
+            }
+            if let Some((row_n, col_n)) = flows_to(row, col) {
+                num_inflowing[(row_n * columns + col_n) as usize] += 1;
+            }
+        }
+    }
+
+    // Pass each cell's accumulation downstream once all of its upstream cells have been
+    // resolved, starting from the cells that receive no flow.
+    let mut stack = vec![];
+    for row in 0..rows {
+        for col in 0..columns {
+            if !pointer.is_nodata(pointer[(row, col)]) && num_inflowing[(row * columns + col) as usize] == 0 {
+                stack.push((row, col));
+            }
+        }
+    }
+    while let Some((row, col)) = stack.pop() {
+        if let Some((row_n, col_n)) = flows_to(row, col) {
+            let idx_n = (row_n * columns + col_n) as usize;
+            let idx = (row * columns + col) as usize;
+            for accum in accumulators.iter_mut() {
+                accum[idx_n] += accum[idx];
+            }
+            num_inflowing[idx_n] -= 1;
+            if num_inflowing[idx_n] == 0 {
+                stack.push((row_n, col_n));
+            }
+        }
+    }
+}
+
+/// Returns the (row, column) of the neighbour that the cell drains to according to a D8 flow
+/// pointer raster (encoded as for `d8_flow_accumulation`), or None if the cell is nodata,
+/// doesn't drain to a neighbour, or drains off the grid or into a nodata cell.
+pub fn d8_receiver(pointer: &Raster, row: isize, col: isize) -> Option<(isize, isize)> {
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let z = pointer[(row, col)];
+    if pointer.is_nodata(z) || z == 0f64 {
+        return None;
+    }
+    let n = match (0..8).find(|&n| z == (1 << n) as f64) {
+        Some(n) => n,
+        None => return None,
+    };
+    let (row_n, col_n) = (row + d_y[n], col + d_x[n]);
+    // Indexing beyond the edge returns nodata, so flow off the grid is caught here too.
+    if pointer.is_nodata(pointer[(row_n, col_n)]) {
+        return None;
+    }
+    Some((row_n, col_n))
+}
@@ -0,0 +1,321 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::io::BufWriter;
+use std::io::prelude::*;
+use std::fs::File;
+use std::f64;
+use std::collections::{BinaryHeap, VecDeque};
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::grid_cell::GridCell;
+
+pub fn get_tool_name() -> String {
+    return "depression_stats".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Identifies the depressions in a DEM by priority-flood, outputting the depth of each cell below its depression's spill elevation and, optionally, a CSV of each depression's size, volume, maximum depth and outlet.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--stats            Optional output CSV file of depression statistics.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=depression_stats --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=depth.dep --stats=depressions.csv\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut stats_file = String::new();
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-stats" || vec[0].to_lowercase() == "--stats" {
+            if keyval {
+                stats_file = vec[1].to_string();
+            } else {
+                stats_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to depression_stats *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+    if !stats_file.is_empty() {
+        if !stats_file.contains(&sep) {
+            stats_file = format!("{}{}", working_directory, stats_file);
+        }
+        check_output_file(&stats_file, overwrite)?;
+    }
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Characterizing depressions...") };
+    let (mut output, depressions) = depression_stats(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' depression_stats tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Depressions: {}", depressions.len()));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+    if !stats_file.is_empty() {
+        let f = File::create(&stats_file)?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all("ID,CELLS,VOLUME,MAX_DEPTH,SPILL_ELEV,OUTLET_X,OUTLET_Y\n".as_bytes())?;
+        for (i, d) in depressions.iter().enumerate() {
+            writer.write_all(format!("{},{},{},{},{},{},{}\n", i + 1, d.num_cells, d.volume, d.max_depth,
+                d.spill_elevation, d.outlet_x, d.outlet_y).as_bytes())?;
+        }
+        let _ = writer.flush();
+        if verbose { println!("Output file written ({} depressions)", depressions.len()) };
+    }
+
+    Ok(())
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct DepressionInfo {
+    /// The number of cells below the spill elevation.
+    pub num_cells: usize,
+    /// The volume of water, in cubic map units, needed to fill the depression to its spill
+    /// elevation.
+    pub volume: f64,
+    pub max_depth: f64,
+    /// The elevation at which the depression overflows, i.e. that of its outlet.
+    pub spill_elevation: f64,
+    /// The cell through which the depression overflows, and its centre in map coordinates.
+    pub outlet_row: isize,
+    pub outlet_column: isize,
+    pub outlet_x: f64,
+    pub outlet_y: f64,
+}
+
+/// Identifies the depressions of a DEM by priority-flood (Barnes et al., 2014), flooding inwards
+/// from the edges of the grid and of nodata areas. Each depression is a connected region of
+/// cells that lie strictly below the elevation of the cell the flood entered it from, its
+/// outlet. Returns the depth of each cell below its depression's spill elevation, 0 outside of
+/// depressions and nodata at nodata cells, and the statistics of each depression, in the
+/// order in which they were found (by increasing spill elevation). Volumes assume that the
+/// DEM's vertical units match its horizontal units.
+pub fn depression_stats(dem: &Raster) -> (Raster, Vec<DepressionInfo>) {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let cell_area = dem.configs.resolution_x * dem.configs.resolution_y;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+
+    // The flood starts from every valid cell on the edge of the grid or next to nodata.
+    let mut fill = vec![f64::NEG_INFINITY; (rows * columns) as usize];
+    let mut closed = vec![false; (rows * columns) as usize];
+    let mut heap = BinaryHeap::new();
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                continue;
+            }
+            // Indexing beyond the edge returns nodata, so edge cells are seeds too.
+            if (0..8).any(|n| dem.is_nodata(dem[(row + d_y[n], col + d_x[n])])) {
+                let idx = (row * columns + col) as usize;
+                fill[idx] = z;
+                closed[idx] = true;
+                heap.push(GridCell::new(row, col, z));
+            }
+        }
+    }
+
+    // Cells below the current flood level are raised to it and spread from a FIFO queue, so
+    // that each depression is flooded in full from the single outlet it was entered through.
+    // label[idx] is the depression's index plus one, or 0 outside of depressions.
+    let mut label = vec![0usize; (rows * columns) as usize];
+    let mut depressions: Vec<DepressionInfo> = vec![];
+    let mut pit_queue = VecDeque::new();
+    loop {
+        let cell = match pit_queue.pop_front() {
+            Some(cell) => cell,
+            None => match heap.pop() {
+                Some(cell) => cell,
+                None => break,
+            },
+        };
+        let idx = (cell.row * columns + cell.column) as usize;
+        // A cell popped from the heap is outside of any depression, and all of its lower
+        // neighbours belong to the single depression that it is the outlet of.
+        let mut outlet_label: Option<usize> = None;
+        for n in 0..8 {
+            let (row_n, col_n) = (cell.row + d_y[n], cell.column + d_x[n]);
+            let z_n = dem[(row_n, col_n)];
+            if dem.is_nodata(z_n) {
+                continue;
+            }
+            let idx_n = (row_n * columns + col_n) as usize;
+            if closed[idx_n] {
+                continue;
+            }
+            closed[idx_n] = true;
+            if z_n < fill[idx] {
+                fill[idx_n] = fill[idx];
+                if label[idx] == 0 {
+                    label[idx_n] = match outlet_label {
+                        Some(l) => l,
+                        None => {
+                            depressions.push(DepressionInfo {
+                                spill_elevation: fill[idx],
+                                outlet_row: cell.row,
+                                outlet_column: cell.column,
+                                outlet_x: dem.get_x_from_column(cell.column),
+                                outlet_y: dem.get_y_from_row(cell.row),
+                                ..Default::default()
+                            });
+                            outlet_label = Some(depressions.len());
+                            depressions.len()
+                        },
+                    };
+                } else {
+                    label[idx_n] = label[idx];
+                }
+                pit_queue.push_back(GridCell::new(row_n, col_n, fill[idx_n]));
+            } else {
+                fill[idx_n] = z_n;
+                heap.push(GridCell::new(row_n, col_n, z_n));
+            }
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                continue;
+            }
+            let idx = (row * columns + col) as usize;
+            if label[idx] == 0 {
+                output.set_value(row, col, 0f64);
+                continue;
+            }
+            let depth = fill[idx] - z;
+            output.set_value(row, col, depth);
+            let depression = &mut depressions[label[idx] - 1];
+            depression.num_cells += 1;
+            depression.volume += depth * cell_area;
+            if depth > depression.max_depth {
+                depression.max_depth = depth;
+            }
+        }
+    }
+
+    (output, depressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use raster::*;
+    use super::depression_stats;
+
+    /// A 4 x 4 raster of unit cells whose 2 x 2 interior is at elevation 2, inside a rim at 10
+    /// with a single low point of 4 at row 0, column 2.
+    fn bowl() -> Raster {
+        let configs = RasterConfigs {
+            rows: 4,
+            columns: 4,
+            north: 4f64,
+            south: 0f64,
+            east: 4f64,
+            west: 0f64,
+            resolution_x: 1f64,
+            resolution_y: 1f64,
+            ..Default::default()
+        };
+        let mut data = vec![10f64; 16];
+        for &(row, col) in [(1, 1), (1, 2), (2, 1), (2, 2)].iter() {
+            data[row * 4 + col] = 2f64;
+        }
+        data[2] = 4f64;
+        Raster::from_data(configs, data).unwrap()
+    }
+
+    #[test]
+    fn outlet_with_two_lower_neighbours_opens_one_depression() {
+        let (depth, depressions) = depression_stats(&bowl());
+        assert_eq!(depressions.len(), 1);
+        let depression = &depressions[0];
+        assert_eq!((depression.outlet_row, depression.outlet_column), (0, 2));
+        assert_eq!(depression.spill_elevation, 4f64);
+        assert_eq!(depression.num_cells, 4);
+        assert_eq!(depression.volume, 8f64);
+        assert_eq!(depression.max_depth, 2f64);
+        assert_eq!(depth[(1, 1)], 2f64);
+        assert_eq!(depth[(0, 0)], 0f64);
+    }
+}
@@ -0,0 +1,274 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::collections::VecDeque;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "detect_flats".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Labels the flat areas of a DEM and optionally imposes a gradient across them towards their outlets.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--dem_output       Optional output file for the DEM with a gradient imposed on its flats.
+--increment        Elevation increment per cell used when imposing the gradient; default is 0.001, which FLOAT rasters can still resolve at typical elevations.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=detect_flats --wd=\"/dir/to/data\" --args=\"-i=filled_DEM.dep -o=flats.dep --dem_output=conditioned_DEM.dep --increment=0.001\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut dem_output_file = String::new();
+    let mut increment = 0.001f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-dem_output" || vec[0].to_lowercase() == "--dem_output" {
+            if keyval {
+                dem_output_file = vec[1].to_string();
+            } else {
+                dem_output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-increment" || vec[0].to_lowercase() == "--increment" {
+            if keyval {
+                increment = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                increment = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to detect_flats *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+    if !dem_output_file.is_empty() {
+        if !dem_output_file.contains(&sep) {
+            dem_output_file = format!("{}{}", working_directory, dem_output_file);
+        }
+        check_output_file(&dem_output_file, overwrite)?;
+    }
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Detecting flats...") };
+    let mut output = detect_flats(&input);
+    let mut dem_output = if !dem_output_file.is_empty() {
+        if verbose { println!("Imposing gradient on flats...") };
+        Some(impose_gradient_on_flats(&input, &output, increment))
+    } else {
+        None
+    };
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' detect_flats tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+    if let Some(ref mut dem_output) = dem_output {
+        dem_output.set_file_name(&dem_output_file);
+        dem_output.add_metadata_entry("Created by whitebox_tools\' detect_flats tool".to_owned());
+        dem_output.add_metadata_entry(format!("Input file: {}", input_file));
+        dem_output.add_metadata_entry(format!("Gradient increment: {}", increment));
+        if let Some(compression) = compression {
+            dem_output.configs.compression = compression;
+        }
+        let _ = match dem_output.write() {
+            Ok(_) => if verbose { println!("Conditioned DEM written") },
+            Err(e) => return Err(e),
+        };
+    }
+
+    Ok(())
+}
+
+/// Labels each flat, i.e. each 4-connected region of two or more cells with exactly equal
+/// elevation, with a unique id starting at 1. Cells that aren't part of a flat are 0.
+pub fn detect_flats(dem: &Raster) -> Raster {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let d_x = [ 1, 0, -1, 0 ];
+    let d_y = [ 0, 1, 0, -1 ];
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    // The labels would wrap in a U8 DEM's type; F32, as for clump, holds ids up to 2^24
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+    let out_nodata = output.configs.nodata;
+    let mut visited = vec![false; (rows * columns) as usize];
+    let mut region: Vec<(isize, isize)> = vec![];
+    let mut label = 0f64;
+    let (mut z, mut row_n, mut col_n): (f64, isize, isize);
+    for row in 0..rows {
+        for col in 0..columns {
+            if visited[(row * columns + col) as usize] {
+                continue;
+            }
+            z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                output.set_value(row, col, out_nodata);
+                continue;
+            }
+            visited[(row * columns + col) as usize] = true;
+            region.clear();
+            region.push((row, col));
+            let mut i = 0;
+            while i < region.len() {
+                let (r, c) = region[i];
+                for n in 0..4 {
+                    row_n = r + d_y[n];
+                    col_n = c + d_x[n];
+                    if dem[(row_n, col_n)] == z && !visited[(row_n * columns + col_n) as usize] {
+                        visited[(row_n * columns + col_n) as usize] = true;
+                        region.push((row_n, col_n));
+                    }
+                }
+                i += 1;
+            }
+            let value = if region.len() > 1 {
+                label += 1f64;
+                label
+            } else {
+                0f64
+            };
+            for &(r, c) in &region {
+                output.set_value(r, c, value);
+            }
+        }
+    }
+
+    output
+}
+
+/// Raises the cells of each flat by `increment` times their distance (in cells, through the
+/// flat) from the flat's outlets, the flat cells with a lower 8-neighbour outside the flat, so
+/// that D8 can route flow across the flat towards its outlets. Flats without an outlet are left
+/// unchanged. `increment` should be small enough that the raised cells stay below the terrain
+/// surrounding the flat.
+pub fn impose_gradient_on_flats(dem: &Raster, flats: &Raster, increment: f64) -> Raster {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    // Small increments would be truncated away in an integer DEM's type.
+    if output.configs.data_type != DataType::F64 {
+        output.configs.data_type = DataType::F32;
+    }
+    let out_nodata = output.configs.nodata;
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = dem[(row, col)];
+            output.set_value(row, col, if !dem.is_nodata(z) { z } else { out_nodata });
+        }
+    }
+
+    // A multi-source breadth-first search from every outlet gives each flat cell its distance
+    // to the nearest outlet of its own flat.
+    let mut distance = vec![-1isize; (rows * columns) as usize];
+    let mut queue = VecDeque::new();
+    let (mut label, mut row_n, mut col_n): (f64, isize, isize);
+    for row in 0..rows {
+        for col in 0..columns {
+            label = flats[(row, col)];
+            if label <= 0f64 || flats.is_nodata(label) {
+                continue;
+            }
+            z = dem[(row, col)];
+            for n in 0..8 {
+                row_n = row + d_y[n];
+                col_n = col + d_x[n];
+                let z_n = dem[(row_n, col_n)];
+                if !dem.is_nodata(z_n) && z_n < z {
+                    distance[(row * columns + col) as usize] = 0;
+                    queue.push_back((row, col));
+                    break;
+                }
+            }
+        }
+    }
+    while let Some((row, col)) = queue.pop_front() {
+        let d = distance[(row * columns + col) as usize];
+        label = flats[(row, col)];
+        for n in 0..8 {
+            row_n = row + d_y[n];
+            col_n = col + d_x[n];
+            if flats[(row_n, col_n)] == label && distance[(row_n * columns + col_n) as usize] < 0 {
+                distance[(row_n * columns + col_n) as usize] = d + 1;
+                output.set_value(row_n, col_n, dem[(row_n, col_n)] + (d + 1) as f64 * increment);
+                queue.push_back((row_n, col_n));
+            }
+        }
+    }
+
+    output
+}
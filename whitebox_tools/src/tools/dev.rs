@@ -0,0 +1,209 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::array2d::Array2D;
+
+pub fn get_tool_name() -> String {
+    return "dev".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the deviation from mean elevation (DEV), a topographic position index standardized by the local standard deviation.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 11.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=dev --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=dev.dep --filter=25\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 11usize;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("******************");
+        println!("* Welcome to dev *");
+        println!("******************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating deviation from mean elevation...") };
+    let mut output = dev(&input, filter_size);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' dev tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the deviation from mean elevation (DEV), (z - mean) / stdev, where the mean and
+/// standard deviation are those of the non-nodata values within the filter window, i.e. a
+/// topographic position index standardized by the local relief. The statistics come from
+/// integral images of the values and squared values, so the cost per cell is independent of
+/// the filter size. Flat windows, with a standard deviation of zero, are assigned 0, and cells
+/// for which fewer than half of the window cells are valid, e.g. near the grid edge or large
+/// nodata areas, are nodata.
+pub fn dev(input: &Raster, filter_size: usize) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let min_count = ((filter_size * filter_size + 1) / 2) as f64;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+
+    // Values are offset by the first valid value to limit the loss of precision when
+    // differencing large sums of squares.
+    let reference = match input.iter_valid().next() {
+        Some((_, _, z)) => z,
+        None => return output,
+    };
+
+    // The integral images have an extra leading row and column of zeros.
+    let (mut z, mut sum, mut sum_sqr, mut n): (f64, f64, f64, f64);
+    let mut values: Vec<f64>;
+    let mut i_sum: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    let mut i_sum_sqr: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    let mut i_n: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    for row in 0..rows {
+        values = input.get_row_data(row);
+        sum = 0f64;
+        sum_sqr = 0f64;
+        n = 0f64;
+        for col in 0..columns {
+            z = values[col as usize];
+            if !input.is_nodata(z) {
+                z -= reference;
+                sum += z;
+                sum_sqr += z * z;
+                n += 1f64;
+            }
+            i_sum[(row + 1, col + 1)] = sum + i_sum[(row, col + 1)];
+            i_sum_sqr[(row + 1, col + 1)] = sum_sqr + i_sum_sqr[(row, col + 1)];
+            i_n[(row + 1, col + 1)] = n + i_n[(row, col + 1)];
+        }
+    }
+
+    let (mut y1, mut y2, mut x1, mut x2): (isize, isize, isize, isize);
+    let (mut variance, mut stdev): (f64, f64);
+    for row in 0..rows {
+        // rows and columns in the integral images are shifted by one
+        y1 = if row - midpoint < 0 { 0 } else { row - midpoint };
+        y2 = if row + midpoint + 1 > rows { rows } else { row + midpoint + 1 };
+        for col in 0..columns {
+            z = input.get_value(row, col);
+            if !input.is_nodata(z) {
+                x1 = if col - midpoint < 0 { 0 } else { col - midpoint };
+                x2 = if col + midpoint + 1 > columns { columns } else { col + midpoint + 1 };
+                n = i_n[(y2, x2)] + i_n[(y1, x1)] - i_n[(y1, x2)] - i_n[(y2, x1)];
+                if n > 1f64 && n >= min_count {
+                    sum = i_sum[(y2, x2)] + i_sum[(y1, x1)] - i_sum[(y1, x2)] - i_sum[(y2, x1)];
+                    sum_sqr = i_sum_sqr[(y2, x2)] + i_sum_sqr[(y1, x1)] - i_sum_sqr[(y1, x2)] - i_sum_sqr[(y2, x1)];
+                    variance = (sum_sqr - sum * sum / n) / (n - 1f64);
+                    if variance < 0f64 { variance = 0f64; } // rounding errors on flat areas
+                    stdev = variance.sqrt();
+                    if stdev > 0f64 {
+                        output.set_value(row, col, (z - reference - sum / n) / stdev);
+                    } else {
+                        output.set_value(row, col, 0f64);
+                    }
+                }
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,275 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::dinf_pointer::neighbour_angles;
+
+pub fn get_tool_name() -> String {
+    return "dinf_flow_accumulation".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates D-infinity flow accumulation from a D-infinity pointer raster.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input D-infinity pointer raster file.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=dinf_flow_accumulation --wd=\"/dir/to/data\" --args=\"-i=dinf_pointer.dep -o=dinf_accum.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*************************************");
+        println!("* Welcome to dinf_flow_accumulation *");
+        println!("*************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating flow accumulation...") };
+    let mut output = dinf_flow_accumulation(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' dinf_flow_accumulation tool".to_owned());
+    output.add_metadata_entry(format!("Pointer file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates D-infinity flow accumulation from a pointer raster produced by `dinf_pointer`, in
+/// which each cell's value is its flow direction in radians counter-clockwise from east, or -1
+/// for cells that don't drain to a neighbour (pits and flats). Each cell contributes 1 and
+/// passes its accumulated flow to the two neighbours whose directions bracket its flow
+/// direction, in proportion to the angular distance from each (Tarboton, 1997). Flow directed
+/// off the grid or into a nodata cell leaves the grid. Nodata pointer cells are nodata in the
+/// output.
+pub fn dinf_flow_accumulation(pointer: &Raster) -> Raster {
+    let rows = pointer.configs.rows as isize;
+    let columns = pointer.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let angles = neighbour_angles(pointer.configs.resolution_x, pointer.configs.resolution_y);
+    // The neighbours in counter-clockwise order, starting from east, so that consecutive pairs
+    // bound the facets.
+    let mut order = [0usize; 8];
+    for n in 0..8 {
+        order[n] = n;
+    }
+    order.sort_by(|&a, &b| angles[a].partial_cmp(&angles[b]).unwrap());
+
+    // The receiving neighbours of each cell and the proportion of its flow each receives.
+    let flows_to = |row: isize, col: isize| -> Vec<(isize, isize, f64)> {
+        let mut receivers = vec![];
+        let a = pointer[(row, col)];
+        if pointer.is_nodata(a) || a < 0f64 {
+            return receivers;
+        }
+        for k in 0..8 {
+            let (lo, hi) = (order[k], order[(k + 1) % 8]);
+            let hi_angle = if k == 7 { angles[hi] + 2f64 * f64::consts::PI } else { angles[hi] };
+            if a < angles[lo] || a >= hi_angle {
+                continue;
+            }
+            let p = (a - angles[lo]) / (hi_angle - angles[lo]);
+            for &(n, share) in &[(lo, 1f64 - p), (hi, p)] {
+                if share < 1e-12 {
+                    continue;
+                }
+                let (row_n, col_n) = (row + d_y[n], col + d_x[n]);
+                if row_n >= 0 && col_n >= 0 && row_n < rows && col_n < columns && !pointer.is_nodata(pointer[(row_n, col_n)]) {
+                    receivers.push((row_n, col_n, share));
+                }
+            }
+            break;
+        }
+        receivers
+    };
+
+    let mut accum = vec![0f64; (rows * columns) as usize];
+    let mut num_inflowing = vec![0u8; (rows * columns) as usize];
+    for row in 0..rows {
+        for col in 0..columns {
+            if pointer.is_nodata(pointer[(row, col)]) {
+                continue;
+            }
+            accum[(row * columns + col) as usize] = 1f64;
+            for (row_n, col_n, _) in flows_to(row, col) {
+                num_inflowing[(row_n * columns + col_n) as usize] += 1;
+            }
+        }
+    }
+
+    // Pass each cell's accumulation downstream once all of its upstream cells have been
+    // resolved, starting from the cells that receive no flow.
+    let mut stack = vec![];
+    for row in 0..rows {
+        for col in 0..columns {
+            if !pointer.is_nodata(pointer[(row, col)]) && num_inflowing[(row * columns + col) as usize] == 0 {
+                stack.push((row, col));
+            }
+        }
+    }
+    while let Some((row, col)) = stack.pop() {
+        let a = accum[(row * columns + col) as usize];
+        for (row_n, col_n, share) in flows_to(row, col) {
+            let idx_n = (row_n * columns + col_n) as usize;
+            accum[idx_n] += a * share;
+            num_inflowing[idx_n] -= 1;
+            if num_inflowing[idx_n] == 0 {
+                stack.push((row_n, col_n));
+            }
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&pointer.file_name, pointer);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let out_nodata = output.configs.nodata;
+    for row in 0..rows {
+        for col in 0..columns {
+            if !pointer.is_nodata(pointer[(row, col)]) {
+                output.set_value(row, col, accum[(row * columns + col) as usize]);
+            } else {
+                output.set_value(row, col, out_nodata);
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use raster::*;
+    use tools::dinf_pointer::dinf_pointer;
+    use super::dinf_flow_accumulation;
+
+    /// Builds a raster of unit cells whose north-west corner is at (0, `rows`).
+    fn grid<F>(rows: usize, columns: usize, f: F) -> Raster where F: Fn(usize, usize) -> f64 {
+        let configs = RasterConfigs {
+            rows: rows,
+            columns: columns,
+            north: rows as f64,
+            south: 0f64,
+            east: columns as f64,
+            west: 0f64,
+            resolution_x: 1f64,
+            resolution_y: 1f64,
+            ..Default::default()
+        };
+        let mut data = Vec::with_capacity(rows * columns);
+        for row in 0..rows {
+            for col in 0..columns {
+                data.push(f(row, col));
+            }
+        }
+        Raster::from_data(configs, data).unwrap()
+    }
+
+    /// A plane falling by one unit per row towards the south.
+    fn inclined_plane() -> Raster {
+        grid(8, 5, |row, _| 100f64 - row as f64)
+    }
+
+    #[test]
+    fn plane_flows_due_south() {
+        let pointer = dinf_pointer(&inclined_plane());
+        for row in 0..7 {
+            for col in 0..5 {
+                let angle = pointer[(row, col)];
+                assert!((angle - 1.5 * f64::consts::PI).abs() < 1e-9,
+                    "Cell (row {}, column {}) has a flow angle of {}.", row, col, angle);
+            }
+        }
+        // The southern edge has no downslope neighbour.
+        for col in 0..5 {
+            assert_eq!(pointer[(7, col)], -1f64);
+        }
+    }
+
+    #[test]
+    fn plane_accumulation_grows_linearly_downslope() {
+        let accumulation = dinf_flow_accumulation(&dinf_pointer(&inclined_plane()));
+        // Each cell drains every cell upslope of it in its column, and itself.
+        let expected = grid(8, 5, |row, _| row as f64 + 1f64);
+        if let Err(message) = rasters_equal(&accumulation, &expected, 1e-6) {
+            panic!("{}", message);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::neighborhood::for_each_3x3;
+
+pub fn get_tool_name() -> String {
+    return "dinf_pointer".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates D-infinity (Tarboton) flow directions from a DEM.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=dinf_pointer --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=dinf_pointer.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to dinf_pointer *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating flow directions...") };
+    let mut output = dinf_pointer(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' dinf_pointer tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates Tarboton's (1997) D-infinity flow directions: each grid cell is assigned the
+/// direction of steepest descent over the eight triangular facets formed with its neighbours,
+/// as an angle in radians counter-clockwise from east, in [0, 2π). Cells without a downslope
+/// facet (pits and flats) are assigned -1, and nodata cells, along with facets involving nodata
+/// or off-grid neighbours, are ignored.
+pub fn dinf_pointer(dem: &Raster) -> Raster {
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    // Each facet is given by its cardinal (e1) and diagonal (e2) neighbours' indices in the 3x3
+    // window, the facet's base angle multiplier (ac) and direction (af), and the distances from
+    // the centre to e1 and from e1 to e2.
+    let facets = [
+        (5, 2, 0f64, 1f64, res_x, res_y),
+        (1, 2, 1f64, -1f64, res_y, res_x),
+        (1, 0, 1f64, 1f64, res_y, res_x),
+        (3, 0, 2f64, -1f64, res_x, res_y),
+        (3, 6, 2f64, 1f64, res_x, res_y),
+        (7, 6, 3f64, -1f64, res_y, res_x),
+        (7, 8, 3f64, 1f64, res_y, res_x),
+        (5, 8, 4f64, -1f64, res_x, res_y),
+    ];
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for_each_3x3(dem, |row, col, window| {
+        let e0 = window[4];
+        let mut max_slope = 0f64;
+        let mut angle = -1f64;
+        for &(i1, i2, ac, af, d1, d2) in &facets {
+            let (e1, e2) = (window[i1], window[i2]);
+            if dem.is_nodata(e1) || dem.is_nodata(e2) {
+                continue;
+            }
+            let s1 = (e0 - e1) / d1;
+            let s2 = (e1 - e2) / d2;
+            let max_r = (d2 / d1).atan();
+            let mut r = s2.atan2(s1);
+            let mut s = (s1 * s1 + s2 * s2).sqrt();
+            if r < 0f64 {
+                r = 0f64;
+                s = s1;
+            } else if r > max_r {
+                r = max_r;
+                s = (e0 - e2) / (d1 * d1 + d2 * d2).sqrt();
+            }
+            if s > max_slope {
+                max_slope = s;
+                // r is measured from the direction of e1 towards e2, in map space, so this
+                // holds for non-square cells too.
+                angle = ac * f64::consts::FRAC_PI_2 + af * r;
+            }
+        }
+        if angle >= 2f64 * f64::consts::PI {
+            angle -= 2f64 * f64::consts::PI;
+        }
+        output.set_value(row, col, angle);
+    });
+
+    output
+}
+
+/// The directions of the D8 neighbours (in the order of the `d_x` and `d_y` offsets used by
+/// the tools) as angles in radians counter-clockwise from east, in [0, 2π).
+pub fn neighbour_angles(res_x: f64, res_y: f64) -> [f64; 8] {
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let mut angles = [0f64; 8];
+    for n in 0..8 {
+        let a = (-d_y[n] as f64 * res_y).atan2(d_x[n] as f64 * res_x);
+        angles[n] = if a < 0f64 { a + 2f64 * f64::consts::PI } else { a };
+    }
+    angles
+}
@@ -0,0 +1,138 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::neighborhood::{for_each_3x3, horn_gradient};
+
+pub fn get_tool_name() -> String {
+    return "directional_slope".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the slope of a DEM in the direction of a given azimuth, in degrees; positive values indicate that the surface rises in that direction.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--azimuth          Direction of the slope, in degrees clockwise from north; default is 0.0.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=directional_slope --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --azimuth=225.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut azimuth = 0f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-azimuth" || vec[0].to_lowercase() == "--azimuth" {
+            if keyval {
+                azimuth = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                azimuth = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("********************************");
+        println!("* Welcome to directional_slope *");
+        println!("********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating directional slope...") };
+    let mut output = directional_slope(&input, azimuth);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' directional_slope tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Azimuth: {}", azimuth));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the slope of a DEM in the direction of the given azimuth (degrees clockwise from
+/// north), i.e. the angle, in degrees, of the surface's directional derivative along the
+/// azimuth, from the 3x3 gradient of `horn_gradient`. Values are positive where the surface
+/// rises in the azimuth's direction and negative where it falls. Nodata cells remain nodata.
+pub fn directional_slope(dem: &Raster, azimuth: f64) -> Raster {
+    let (sin_az, cos_az) = azimuth.to_radians().sin_cos();
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for_each_3x3(dem, |row, col, window| {
+        let (dz_dx, dz_dy) = horn_gradient(window, dem);
+        let derivative = dz_dx * sin_az + dz_dy * cos_az;
+        output.set_value(row, col, derivative.atan().to_degrees());
+    });
+
+    output
+}
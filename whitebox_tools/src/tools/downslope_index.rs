@@ -0,0 +1,224 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "downslope_index".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the downslope index, the distance along the steepest descent path to a given vertical drop.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--drop             Vertical drop (z units); default is 2.0.
+--out_type         Output type, 'distance', 'tangent' or 'degrees'; default is distance.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=downslope_index --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --drop=5.0 --out_type=tangent\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut drop = 2f64;
+    let mut out_type = "distance".to_string();
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-drop" || vec[0].to_lowercase() == "--drop" {
+            if keyval {
+                drop = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                drop = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-out_type" || vec[0].to_lowercase() == "--out_type" {
+            if keyval {
+                out_type = vec[1].to_lowercase();
+            } else {
+                out_type = args[i+1].to_lowercase();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if drop <= 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The vertical drop must be positive."));
+    }
+    if out_type != "distance" && out_type != "tangent" && out_type != "degrees" {
+        return Err(Error::new(ErrorKind::InvalidInput, "The output type must be 'distance', 'tangent' or 'degrees'."));
+    }
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to downslope_index *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating downslope index...") };
+    let mut output = downslope_index(&input, drop);
+    if out_type != "distance" {
+        // Express the index as the gradient over the traced distance.
+        let nodata = output.configs.nodata;
+        let mut length: f64;
+        for row in 0..output.configs.rows as isize {
+            for col in 0..output.configs.columns as isize {
+                length = output[(row, col)];
+                if output.is_nodata(length) {
+                    continue;
+                }
+                if length > 0f64 {
+                    let tan = drop / length;
+                    output.set_value(row, col, if out_type == "tangent" { tan } else { tan.atan().to_degrees() });
+                } else {
+                    output.set_value(row, col, nodata);
+                }
+            }
+        }
+    }
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' downslope_index tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Vertical drop: {}", drop));
+    output.add_metadata_entry(format!("Output type: {}", out_type));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the downslope index (Hjerdt et al., 2004), the horizontal distance along the D8
+/// steepest descent path from each grid cell to the point where the path has dropped `drop`
+/// elevation units below the cell. The final step is linearly interpolated to the exact drop.
+/// Paths that reach the grid edge, a nodata cell or a pit or flat before dropping `drop` are
+/// assigned the distance accumulated so far. Nodata cells remain nodata.
+pub fn downslope_index(dem: &Raster, drop: f64) -> Raster {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    let diag = (res_x * res_x + res_y * res_y).sqrt();
+    let dist = [ diag, res_x, diag, res_y, diag, res_x, diag, res_y ];
+
+    // The steepest downslope neighbour of each cell, if it has a lower neighbour.
+    let mut receiver: Vec<Option<usize>> = vec![None; (rows * columns) as usize];
+    let (mut z, mut z_n, mut slope, mut max_slope): (f64, f64, f64, f64);
+    for row in 0..rows {
+        for col in 0..columns {
+            z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                continue;
+            }
+            max_slope = 0f64;
+            for n in 0..8 {
+                z_n = dem[(row + d_y[n], col + d_x[n])];
+                if !dem.is_nodata(z_n) {
+                    slope = (z - z_n) / dist[n];
+                    if slope > max_slope {
+                        max_slope = slope;
+                        receiver[(row * columns + col) as usize] = Some(n);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let (mut row_n, mut col_n, mut length, mut z_prev): (isize, isize, f64, f64);
+    for row in 0..rows {
+        for col in 0..columns {
+            z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                continue;
+            }
+            row_n = row;
+            col_n = col;
+            length = 0f64;
+            z_prev = z;
+            while let Some(n) = receiver[(row_n * columns + col_n) as usize] {
+                row_n += d_y[n];
+                col_n += d_x[n];
+                z_n = dem[(row_n, col_n)];
+                if z - z_n >= drop {
+                    length += dist[n] * (drop - (z - z_prev)) / (z_prev - z_n);
+                    break;
+                }
+                length += dist[n];
+                z_prev = z_n;
+            }
+            output.set_value(row, col, length);
+        }
+    }
+
+    output
+}
@@ -0,0 +1,175 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "downslope_profile_curvature".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the profile convexity along the D8 flow path, from the second difference of elevations one cell up- and downslope.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+--pointer          Input D8 flow pointer raster file.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=downslope_profile_curvature --wd=\"/dir/to/data\" --args=\"-i=DEM.dep --pointer=pointer.dep -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut dem_file = String::new();
+    let mut pointer_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                dem_file = vec[1].to_string();
+            } else {
+                dem_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-pointer" || vec[0].to_lowercase() == "--pointer" {
+            if keyval {
+                pointer_file = vec[1].to_string();
+            } else {
+                pointer_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("******************************************");
+        println!("* Welcome to downslope_profile_curvature *");
+        println!("******************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !dem_file.contains(&sep) {
+        dem_file = format!("{}{}", working_directory, dem_file);
+    }
+    if !pointer_file.contains(&sep) {
+        pointer_file = format!("{}{}", working_directory, pointer_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let dem = Raster::new(&dem_file, "r")?;
+    let pointer = Raster::new(&pointer_file, "r")?;
+    check_alignment(&dem, &pointer)?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating downslope profile curvature...") };
+    let mut output = downslope_profile_curvature(&dem, &pointer);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' downslope_profile_curvature tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", dem_file));
+    output.add_metadata_entry(format!("Pointer file: {}", pointer_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the convexity of the flow path through each cell: the negated second difference
+/// of the elevations of the cell and its D8 downslope neighbour, given by `pointer` (encoded as
+/// for `d8_flow_accumulation`), and of the neighbour one cell upslope in the opposite
+/// direction, i.e. -(z_up - 2 z + z_down) / d^2, where d is the distance between cell centres
+/// along the flow direction. Values are positive where the path steepens downslope (convex
+/// profiles, e.g. shoulders) and negative where it levels out (concave profiles, e.g. footslopes),
+/// in units of 1/length when elevations and cell sizes share units.
+///
+/// Cells that don't drain to a neighbour, whose path leaves the grid in either direction or
+/// involves a nodata elevation, and nodata pointer cells, are nodata. The pointer must be
+/// aligned with the DEM (see `check_alignment`).
+pub fn downslope_profile_curvature(dem: &Raster, pointer: &Raster) -> Raster {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let mut dist_sqr = [0f64; 8];
+    for n in 0..8 {
+        dist_sqr[n] = (d_x[n] as f64 * res_x).powi(2) + (d_y[n] as f64 * res_y).powi(2);
+    }
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = dem[(row, col)];
+            let p = pointer[(row, col)];
+            if dem.is_nodata(z) || pointer.is_nodata(p) {
+                continue;
+            }
+            let n = match (0..8).find(|&n| p == (1 << n) as f64) {
+                Some(n) => n,
+                None => continue,
+            };
+            // Indexing beyond the edge returns nodata, so paths leaving the grid are skipped.
+            let z_down = dem[(row + d_y[n], col + d_x[n])];
+            let z_up = dem[(row - d_y[n], col - d_x[n])];
+            if dem.is_nodata(z_down) || dem.is_nodata(z_up) {
+                continue;
+            }
+            output.set_value(row, col, -(z_up - 2f64 * z + z_down) / dist_sqr[n]);
+        }
+    }
+
+    output
+}
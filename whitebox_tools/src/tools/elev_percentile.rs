@@ -0,0 +1,177 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "elev_percentile".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the percentage of the cells within a moving window that are lower than each cell, a scale-dependent measure of relative topographic position.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 11.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=elev_percentile --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=elev_percentile.dep --filter=25\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 11usize;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to elev_percentile *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating elevation percentiles...") };
+    let mut output = elev_percentile(&input, filter_size);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' elev_percentile tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the elevation percentile of each grid cell, the percentage (0-100) of the other
+/// valid cells within the filter window that are lower than it, a measure of relative
+/// topographic position whose meaning depends on the window size. Neighbours of equal
+/// elevation count as half lower and half higher, so that a cell in a flat window is at the
+/// 50th percentile rather than the 0th. Nodata neighbours are excluded from both the count and
+/// the total; cells without any valid neighbours, and nodata cells, are assigned nodata.
+pub fn elev_percentile(dem: &Raster, filter_size: usize) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = dem.configs.columns as isize;
+    let rows = dem.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let (mut z, mut z_n): (f64, f64);
+    let (mut num_lower, mut n): (f64, f64);
+    for row in 0..rows {
+        for col in 0..columns {
+            z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                continue;
+            }
+            num_lower = 0f64;
+            n = 0f64;
+            for row2 in (row - midpoint)..(row + midpoint + 1) {
+                for col2 in (col - midpoint)..(col + midpoint + 1) {
+                    if row2 == row && col2 == col {
+                        continue;
+                    }
+                    z_n = dem[(row2, col2)];
+                    if !dem.is_nodata(z_n) {
+                        n += 1f64;
+                        if z_n < z {
+                            num_lower += 1f64;
+                        } else if z_n == z {
+                            num_lower += 0.5;
+                        }
+                    }
+                }
+            }
+            if n > 0f64 {
+                output.set_value(row, col, 100f64 * num_lower / n);
+            }
+        }
+    }
+
+    output
+}
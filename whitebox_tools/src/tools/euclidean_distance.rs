@@ -0,0 +1,270 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "euclidean_distance".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the straight-line distance from each cell to the nearest feature cell, optionally with the nearest feature's value (allocation).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input features raster file; valid non-zero cells are features.
+-o, --output       Output distance raster file.
+--allocation       Optional output allocation raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=euclidean_distance --wd=\"/dir/to/data\" --args=\"-i=streams.dep -o=distance.dep --allocation=nearest_stream.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut allocation_file = String::new();
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-allocation" || vec[0].to_lowercase() == "--allocation" {
+            if keyval {
+                allocation_file = vec[1].to_string();
+            } else {
+                allocation_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*********************************");
+        println!("* Welcome to euclidean_distance *");
+        println!("*********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+    if !allocation_file.is_empty() {
+        if !allocation_file.contains(&sep) {
+            allocation_file = format!("{}{}", working_directory, allocation_file);
+        }
+        check_output_file(&allocation_file, overwrite)?;
+    }
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating distances...") };
+    let mut output = euclidean_distance(&input);
+    let allocation = if allocation_file.is_empty() { None } else { Some(euclidean_allocation(&input)) };
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' euclidean_distance tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+    if let Some(mut allocation) = allocation {
+        allocation.set_file_name(&allocation_file);
+        allocation.add_metadata_entry("Created by whitebox_tools\' euclidean_distance tool".to_owned());
+        allocation.add_metadata_entry(format!("Input file: {}", input_file));
+        if let Some(compression) = compression {
+            allocation.configs.compression = compression;
+        }
+        let _ = match allocation.write() {
+            Ok(_) => if verbose { println!("Output file written") },
+            Err(e) => return Err(e),
+        };
+    }
+
+    Ok(())
+}
+
+/// Returns the distance, in the units of the cell size, from each cell to the nearest feature
+/// cell (any valid, non-zero cell of `features`), measured between cell centres; feature cells
+/// have a distance of 0. The distances are exact Euclidean distances, computed in linear time by
+/// the two-pass method of Meijster et al. (2000) with the lower envelope of parabolas of
+/// Felzenszwalb and Huttenlocher (2012), and account for rectangular cells. Nodata cells are
+/// treated as non-features and receive distances too. Every cell is nodata if there are no
+/// features.
+pub fn euclidean_distance(features: &Raster) -> Raster {
+    let (dist_sqr, _) = nearest_features(features);
+    let mut output = Raster::initialize_using_file(&features.file_name, features);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let columns = features.configs.columns;
+    for (i, d) in dist_sqr.iter().enumerate() {
+        if *d != f64::INFINITY {
+            output.set_value((i / columns) as isize, (i % columns) as isize, d.sqrt());
+        }
+    }
+    output
+}
+
+/// Returns, for each cell, the value of the nearest feature cell of `features` (see
+/// `euclidean_distance`), i.e. the Voronoi regions of the features. Ties between equally distant
+/// features are broken arbitrarily but consistently. Every cell is nodata if there are no
+/// features.
+pub fn euclidean_allocation(features: &Raster) -> Raster {
+    let (_, nearest) = nearest_features(features);
+    let mut output = Raster::initialize_using_file(&features.file_name, features);
+    let columns = features.configs.columns;
+    let values = features.as_slice();
+    for (i, n) in nearest.iter().enumerate() {
+        if *n != NO_FEATURE {
+            output.set_value((i / columns) as isize, (i % columns) as isize, values[*n]);
+        }
+    }
+    output
+}
+
+const NO_FEATURE: usize = usize::MAX;
+
+/// Returns the squared distance to, and the cell index (row * columns + column) of, the nearest
+/// feature of every cell, with infinite distances and `NO_FEATURE` if there are no features.
+fn nearest_features(features: &Raster) -> (Vec<f64>, Vec<usize>) {
+    let rows = features.configs.rows;
+    let columns = features.configs.columns;
+    let res_x = features.configs.resolution_x;
+    let res_y = features.configs.resolution_y;
+    let values = features.as_slice();
+    let is_feature = |i: usize| !features.is_nodata(values[i]) && values[i] != 0f64;
+
+    // First pass: the nearest feature row within each cell's own column, from a downward and
+    // an upward sweep.
+    let mut nearest_row = vec![NO_FEATURE; rows * columns];
+    for col in 0..columns {
+        let mut last = NO_FEATURE;
+        for row in 0..rows {
+            if is_feature(row * columns + col) { last = row; }
+            nearest_row[row * columns + col] = last;
+        }
+        last = NO_FEATURE;
+        for row in (0..rows).rev() {
+            let i = row * columns + col;
+            if is_feature(i) { last = row; }
+            if last != NO_FEATURE && (nearest_row[i] == NO_FEATURE || last - row < row - nearest_row[i]) {
+                nearest_row[i] = last;
+            }
+        }
+    }
+
+    // Second pass: along each row, the lower envelope of the parabolas (x - q)^2 + g(q)^2
+    // centred on each column q, where g(q) is the column distance from the first pass.
+    let mut dist_sqr = vec![f64::INFINITY; rows * columns];
+    let mut nearest = vec![NO_FEATURE; rows * columns];
+    let mut f = vec![f64::INFINITY; columns];
+    let mut v = vec![0usize; columns]; // the columns of the parabolas in the envelope
+    let mut z = vec![0f64; columns + 1]; // the boundaries between them
+    for row in 0..rows {
+        for col in 0..columns {
+            let r = nearest_row[row * columns + col];
+            f[col] = if r == NO_FEATURE {
+                f64::INFINITY
+            } else {
+                let dy = (r as f64 - row as f64) * res_y;
+                dy * dy
+            };
+        }
+        let intersection = |q: usize, p: usize| -> f64 {
+            let (xq, xp) = (q as f64 * res_x, p as f64 * res_x);
+            ((f[q] + xq * xq) - (f[p] + xp * xp)) / (2f64 * (xq - xp))
+        };
+        let mut k = 0usize;
+        let mut empty = true;
+        for q in 0..columns {
+            if f[q] == f64::INFINITY {
+                continue;
+            }
+            if empty {
+                v[0] = q;
+                z[0] = f64::NEG_INFINITY;
+                z[1] = f64::INFINITY;
+                empty = false;
+                continue;
+            }
+            let mut s = intersection(q, v[k]);
+            while s <= z[k] {
+                k -= 1;
+                s = intersection(q, v[k]);
+            }
+            k += 1;
+            v[k] = q;
+            z[k] = s;
+            z[k + 1] = f64::INFINITY;
+        }
+        if empty {
+            continue;
+        }
+        k = 0;
+        for col in 0..columns {
+            let x = col as f64 * res_x;
+            while z[k + 1] < x {
+                k += 1;
+            }
+            let dx = x - v[k] as f64 * res_x;
+            let i = row * columns + col;
+            dist_sqr[i] = dx * dx + f[v[k]];
+            nearest[i] = nearest_row[row * columns + v[k]] * columns + v[k];
+        }
+    }
+
+    (dist_sqr, nearest)
+}
@@ -0,0 +1,223 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::filter::iterate_filter;
+
+pub fn get_tool_name() -> String {
+    return "feature_preserving_smoothing".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Smooths a raster, e.g. a LiDAR DEM, with an edge-preserving bilateral filter.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 11.
+--sigma_spatial    Standard deviation of the spatial weighting, in cells; default is 2.0.
+--sigma_value      Standard deviation of the value (z) weighting, in z units; default is 1.0.
+--num_iterations   Number of times the filter is applied, each pass to the output of the last; default is 1.
+--tolerance        Optional mean absolute change per pass below which iterating stops early; default is 0 (never).
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=feature_preserving_smoothing --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --filter=11 --sigma_spatial=3.0 --sigma_value=0.5\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 11usize;
+    let mut sigma_spatial = 2f64;
+    let mut sigma_value = 1f64;
+    let mut num_iterations = 1usize;
+    let mut tolerance = 0f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-sigma_spatial" || vec[0].to_lowercase() == "--sigma_spatial" {
+            if keyval {
+                sigma_spatial = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                sigma_spatial = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-sigma_value" || vec[0].to_lowercase() == "--sigma_value" {
+            if keyval {
+                sigma_value = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                sigma_value = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-num_iterations" || vec[0].to_lowercase() == "--num_iterations" {
+            if keyval {
+                num_iterations = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                num_iterations = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-tolerance" || vec[0].to_lowercase() == "--tolerance" {
+            if keyval {
+                tolerance = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                tolerance = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if sigma_spatial <= 0f64 || sigma_value <= 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --sigma_spatial and --sigma_value parameters must be greater than zero."));
+    }
+
+    if num_iterations == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The number of iterations must be at least 1."));
+    }
+
+    if verbose {
+        println!("*******************************************");
+        println!("* Welcome to feature_preserving_smoothing *");
+        println!("*******************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Smoothing...") };
+    let (mut output, passes) = iterate_filter(&input, num_iterations, tolerance,
+        |r| feature_preserving_smoothing(r, filter_size, sigma_spatial, sigma_value));
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' feature_preserving_smoothing tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Spatial sigma: {}", sigma_spatial));
+    output.add_metadata_entry(format!("Value sigma: {}", sigma_value));
+    output.add_metadata_entry(format!("Iterations: {}", passes));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Smooths the raster with a bilateral filter, an edge-preserving average in which each
+/// neighbour within the square window is weighted by a Gaussian of its distance from the
+/// centre cell (`sigma_spatial`, in cells) and by a Gaussian of its difference in value from the
+/// centre cell (`sigma_value`, in z units). Neighbours across a sharp break, such as a ridge
+/// crest or scarp, differ greatly in value and so contribute little, keeping the break sharp.
+/// Nodata neighbours are excluded and the remaining weights renormalized; nodata cells remain
+/// nodata.
+pub fn feature_preserving_smoothing(input: &Raster, filter_size: usize, sigma_spatial: f64, sigma_value: f64) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+
+    // The spatial weights are the same for every cell, so they are calculated once.
+    let mut offsets: Vec<(isize, isize, f64)> = Vec::with_capacity(filter_size * filter_size);
+    for dy in -midpoint..midpoint+1 {
+        for dx in -midpoint..midpoint+1 {
+            let d_sqr = (dx * dx + dy * dy) as f64;
+            offsets.push((dx, dy, (-d_sqr / (2f64 * sigma_spatial * sigma_spatial)).exp()));
+        }
+    }
+    let value_denom = 2f64 * sigma_value * sigma_value;
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let (mut z, mut z_n, mut w, mut sum, mut sum_weights): (f64, f64, f64, f64, f64);
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input.get_value(row, col);
+            if !input.is_nodata(z) {
+                sum = 0f64;
+                sum_weights = 0f64;
+                for &(dx, dy, w_spatial) in offsets.iter() {
+                    z_n = input.get_value(row + dy, col + dx);
+                    if !input.is_nodata(z_n) {
+                        w = w_spatial * (-(z_n - z) * (z_n - z) / value_denom).exp();
+                        sum += w * z_n;
+                        sum_weights += w;
+                    }
+                }
+                // The centre cell's own weight is one, so sum_weights is never zero.
+                output.set_value(row, col, sum / sum_weights);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,173 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "fill_single_cell_pits".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Raises single-cell pits in a DEM to the elevation of their lowest neighbour.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=fill_single_cell_pits --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("************************************");
+        println!("* Welcome to fill_single_cell_pits *");
+        println!("************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Filling single-cell pits...") };
+    let mut output = fill_single_cell_pits(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' fill_single_cell_pits tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Raises each cell that is lower than all 8 of its neighbours to the elevation of its lowest
+/// neighbour, repeating until a pass makes no changes (or after 100 passes). Edge cells and cells
+/// next to nodata are never modified. The number of pits filled is recorded as a metadata entry.
+pub fn fill_single_cell_pits(input: &Raster) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    let out_nodata = output.configs.nodata;
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input[(row, col)];
+            output.set_value(row, col, if !input.is_nodata(z) { z } else { out_nodata });
+        }
+    }
+
+    let mut num_filled = 0usize;
+    let mut num_passes = 0usize;
+    let (mut z_n, mut min_n): (f64, f64);
+    let mut is_pit: bool;
+    loop {
+        let mut changed = false;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = output[(row, col)];
+                if output.is_nodata(z) { continue; }
+                is_pit = true;
+                min_n = f64::INFINITY;
+                for i in 0..8 {
+                    // Indexing beyond the edge returns nodata, so edge cells are skipped too.
+                    z_n = output[(row + d_y[i], col + d_x[i])];
+                    if output.is_nodata(z_n) || z_n <= z {
+                        is_pit = false;
+                        break;
+                    }
+                    if z_n < min_n { min_n = z_n; }
+                }
+                if is_pit {
+                    output.set_value(row, col, min_n);
+                    num_filled += 1;
+                    changed = true;
+                }
+            }
+        }
+        num_passes += 1;
+        if !changed || num_passes == 100 {
+            break;
+        }
+    }
+
+    output.add_metadata_entry(format!("Pits filled: {}", num_filled));
+
+    output
+}
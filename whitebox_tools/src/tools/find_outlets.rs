@@ -0,0 +1,148 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+use vector::geojson;
+
+pub fn get_tool_name() -> String {
+    return "find_outlets".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Locates the stream cells that drain off the edge of the grid or into nodata, e.g. as pour points for watershed delineation.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--streams          Input streams raster file; non-zero cells are stream cells.
+--pointer          Input D8 flow pointer raster file.
+-o, --output       Output GeoJSON points file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=find_outlets --wd=\"/dir/to/data\" --args=\"--streams=streams.dep --pointer=pointer.dep -o=outlets.geojson\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut streams_file = String::new();
+    let mut pointer_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-streams" || vec[0].to_lowercase() == "--streams" {
+            if keyval {
+                streams_file = vec[1].to_string();
+            } else {
+                streams_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-pointer" || vec[0].to_lowercase() == "--pointer" {
+            if keyval {
+                pointer_file = vec[1].to_string();
+            } else {
+                pointer_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to find_outlets *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !streams_file.contains(&sep) {
+        streams_file = format!("{}{}", working_directory, streams_file);
+    }
+    if !pointer_file.contains(&sep) {
+        pointer_file = format!("{}{}", working_directory, pointer_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let streams = Raster::new(&streams_file, "r")?;
+    let pointer = Raster::new(&pointer_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Finding outlets...") };
+    let outlets = find_outlets(&streams, &pointer)?
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &(x, y))| (x, y, (i + 1) as f64))
+                        .collect::<Vec<(f64, f64, f64)>>();
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let _ = match geojson::write_points(&output_file, &outlets, "FID") {
+        Ok(_) => if verbose { println!("Output file written ({} outlets)", outlets.len()) },
+        Err(e) => return Err(e),
+    };
+    if verbose {
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Returns the world coordinates of the centres of the stream cells (non-zero, non-nodata)
+/// that drain out of the data, i.e. whose D8 `pointer` (see `d8_flow_accumulation` for the
+/// encoding) directs flow off the edge of the grid or into a nodata pointer cell, as at the
+/// edge of a masked DEM. Cells with a pointer of 0 (sinks) are not outlets. The outlets are in
+/// row-major order and the pointer must be aligned with the streams raster.
+pub fn find_outlets(streams: &Raster, pointer: &Raster) -> Result<Vec<(f64, f64)>, Error> {
+    check_alignment(streams, pointer)?;
+    let rows = streams.configs.rows as isize;
+    let columns = streams.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+
+    let mut outlets = vec![];
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = streams[(row, col)];
+            let p = pointer[(row, col)];
+            if streams.is_nodata(z) || z == 0f64 || pointer.is_nodata(p) || p == 0f64 {
+                continue;
+            }
+            if let Some(n) = (0..8).find(|&n| p == (1 << n) as f64) {
+                // Off-grid cells index as nodata.
+                if pointer.is_nodata(pointer[(row + d_y[n], col + d_x[n])]) {
+                    outlets.push((streams.get_x_from_column(col), streams.get_y_from_row(row)));
+                }
+            }
+        }
+    }
+
+    Ok(outlets)
+}
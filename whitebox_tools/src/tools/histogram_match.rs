@@ -0,0 +1,166 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::percentile_stretch::histogram_percentile;
+
+pub fn get_tool_name() -> String {
+    return "histogram_match".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Remaps the values of a raster so that their distribution matches that of a reference raster.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+--reference        Reference raster file whose distribution is matched.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=histogram_match --wd=\"/dir/to/data\" --args=\"-i=image_2016.dep --reference=image_2010.dep -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut reference_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-reference" || vec[0].to_lowercase() == "--reference" {
+            if keyval {
+                reference_file = vec[1].to_string();
+            } else {
+                reference_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to histogram_match *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !reference_file.contains(&sep) {
+        reference_file = format!("{}{}", working_directory, reference_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let reference = Raster::new(&reference_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Matching histograms...") };
+    let mut output = histogram_match(&input, &reference);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' histogram_match tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Reference file: {}", reference_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Remaps the values of `input` so that their distribution matches that of `reference`. Each
+/// value's cumulative proportion among the input's valid cells is found from a fine histogram,
+/// interpolating linearly within its bin, and replaced with the reference value at that same
+/// proportion. The output is F64 if the reference is, and F32 otherwise. Nodata cells remain
+/// nodata.
+pub fn histogram_match(input: &Raster, reference: &Raster) -> Raster {
+    let num_bins = 10000;
+    let (min, bin_width, counts) = input.histogram(num_bins);
+    let (ref_min, ref_bin_width, ref_counts) = reference.histogram(num_bins);
+    let n: usize = counts.iter().sum();
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    // The reference values are interpolated, so even an integer reference gives fractions.
+    output.configs.data_type = if reference.configs.data_type == DataType::F64 { DataType::F64 } else { DataType::F32 };
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    if n == 0 || ref_counts.iter().sum::<usize>() == 0 {
+        return output;
+    }
+    // The cumulative count below each bin.
+    let mut cumulative = vec![0usize; num_bins];
+    for bin in 1..num_bins {
+        cumulative[bin] = cumulative[bin - 1] + counts[bin - 1];
+    }
+
+    let (mut bin, mut frac, mut pct): (usize, f64, f64);
+    for (row, col, z) in input.iter_valid() {
+        if bin_width > 0f64 {
+            frac = (z - min) / bin_width;
+            bin = (frac.floor() as usize).min(num_bins - 1);
+            frac = (frac - bin as f64).min(1f64);
+        } else {
+            // A constant input maps to the reference median.
+            bin = 0;
+            frac = 0.5;
+        }
+        pct = 100f64 * (cumulative[bin] as f64 + frac * counts[bin] as f64) / n as f64;
+        output.set_value(row, col, histogram_percentile(ref_min, ref_bin_width, &ref_counts, pct));
+    }
+
+    output
+}
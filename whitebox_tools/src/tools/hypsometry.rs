@@ -0,0 +1,310 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::fs::File;
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+/// The number of equal elevation intervals the hypsometric curve is sampled at.
+const NUM_CURVE_BINS: usize = 100;
+
+pub fn get_tool_name() -> String {
+    return "hypsometry".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the hypsometric curve and integral (elevation-relief ratio) of a DEM, optionally within a basin.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input DEM raster file.
+--basin            Optional input basin raster file; only cells with non-zero basin values are analyzed.
+-o, --output       Output HTML file.
+--csv              Optional output CSV file of the hypsometric curve.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=hypsometry --wd=\"/dir/to/data\" --args=\"-i=DEM.dep --basin=basin.dep -o=hypsometry.html --csv=curve.csv\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut basin_file = String::new();
+    let mut output_file = String::new();
+    let mut csv_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-basin" || vec[0].to_lowercase() == "--basin" {
+            if keyval {
+                basin_file = vec[1].to_string();
+            } else {
+                basin_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-csv" || vec[0].to_lowercase() == "--csv" {
+            if keyval {
+                csv_file = vec[1].to_string();
+            } else {
+                csv_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("*************************");
+        println!("* Welcome to hypsometry *");
+        println!("*************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !basin_file.is_empty() && !basin_file.contains(&sep) {
+        basin_file = format!("{}{}", working_directory, basin_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    if !output_file.ends_with(".html") {
+        output_file = output_file + ".html";
+    }
+    check_output_file(&output_file, overwrite)?;
+    if !csv_file.is_empty() {
+        if !csv_file.contains(&sep) {
+            csv_file = format!("{}{}", working_directory, csv_file);
+        }
+        check_output_file(&csv_file, overwrite)?;
+    }
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let basin = if !basin_file.is_empty() {
+        Some(Raster::new(&basin_file, "r")?)
+    } else {
+        None
+    };
+
+    let start = time::now();
+
+    if verbose { println!("Calculating hypsometry...") };
+    let result = hypsometry(&input, basin.as_ref())?;
+    if result.n == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "There are no valid DEM cells to analyze."));
+    }
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let f = File::create(output_file.clone())?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all(&format!("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+    <head>
+        <meta content=\"text/html; charset=iso-8859-1\" http-equiv=\"content-type\">
+        <title>Hypsometric Analysis</title>
+        <style  type=\"text/css\">
+            h1 {{
+                font-size: 14pt;
+                margin-left: 15px;
+                margin-right: 15px;
+                text-align: center;
+                font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+            }}
+            p, caption {{
+                font-size: 12pt;
+                font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                margin-left: 15px;
+                margin-right: 15px;
+            }}
+            table {{
+                font-size: 12pt;
+                font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                border-collapse: collapse;
+                align: center;
+            }}
+            td, th {{
+                border: 1px solid #222222;
+                text-align: left;
+                padding: 8px;
+            }}
+            .numberCell {{
+                text-align: right;
+            }}
+        </style>
+    </head>
+    <body>
+        <h1>Hypsometric Analysis</h1>
+        <p><strong>DEM</strong>: {}<br><strong>Basin</strong>: {}</p>
+        <table align=\"center\">
+            <tr><th>Statistic</th><th>Value</th></tr>
+            <tr><td>Cells (n)</td><td class=\"numberCell\">{}</td></tr>
+            <tr><td>Minimum elevation</td><td class=\"numberCell\">{:.4}</td></tr>
+            <tr><td>Maximum elevation</td><td class=\"numberCell\">{:.4}</td></tr>
+            <tr><td>Mean elevation</td><td class=\"numberCell\">{:.4}</td></tr>
+            <tr><td>Hypsometric integral (elevation-relief ratio)</td><td class=\"numberCell\">{:.4}</td></tr>
+        </table>
+", input_file, if basin_file.is_empty() { "none" } else { &basin_file }, result.n, result.min, result.max, result.mean, result.integral).as_bytes())?;
+    writer.write_all(curve_svg(&result.curve).as_bytes())?;
+    writer.write_all("    </body>\n</html>\n".as_bytes())?;
+    let _ = writer.flush();
+
+    if !csv_file.is_empty() {
+        let f = File::create(csv_file.clone())?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all("ELEVATION,RELATIVE_HEIGHT,RELATIVE_AREA\n".as_bytes())?;
+        for &(relative_area, relative_height) in &result.curve {
+            writer.write_all(format!("{},{},{}\n", result.min + relative_height * (result.max - result.min), relative_height, relative_area).as_bytes())?;
+        }
+        let _ = writer.flush();
+    }
+
+    if verbose {
+        println!("Output file written");
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// The hypsometric statistics of a DEM.
+#[derive(Default, Clone, Debug)]
+pub struct HypsometryResult {
+    /// The number of cells analyzed.
+    pub n: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// The hypsometric integral, estimated by the elevation-relief ratio (mean - min) / (max - min).
+    pub integral: f64,
+    /// The hypsometric curve as (relative area, relative height) pairs, ordered by increasing
+    /// relative height from 0 to 1, where the relative area is the proportion of cells at or
+    /// above the height.
+    pub curve: Vec<(f64, f64)>,
+}
+
+/// Calculates the hypsometric curve and integral of the valid cells of `dem`. If `basin` is
+/// given, it must be aligned with the DEM (see `check_alignment`) and only the cells where it is
+/// valid and non-zero are analyzed. The statistics are NaN and the curve is empty if there are
+/// no cells to analyze; the integral is also NaN if the analyzed cells are all the same height.
+pub fn hypsometry(dem: &Raster, basin: Option<&Raster>) -> Result<HypsometryResult, Error> {
+    if let Some(b) = basin {
+        check_alignment(dem, b)?;
+    }
+    let in_basin = |row: isize, col: isize| -> bool {
+        match basin {
+            Some(b) => {
+                let z = b[(row, col)];
+                !b.is_nodata(z) && z != 0f64
+            },
+            None => true,
+        }
+    };
+
+    let mut n = 0usize;
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut sum = 0f64;
+    for (row, col, z) in dem.iter_valid() {
+        if in_basin(row, col) {
+            n += 1;
+            sum += z;
+            if z < min { min = z; }
+            if z > max { max = z; }
+        }
+    }
+    if n == 0 {
+        return Ok(HypsometryResult { n: 0, min: f64::NAN, max: f64::NAN, mean: f64::NAN, integral: f64::NAN, curve: vec![] });
+    }
+    let mean = sum / n as f64;
+    let relief = max - min;
+    if relief <= 0f64 {
+        return Ok(HypsometryResult { n: n, min: min, max: max, mean: mean, integral: f64::NAN, curve: vec![] });
+    }
+
+    let mut counts = vec![0usize; NUM_CURVE_BINS];
+    for row in 0..dem.configs.rows as isize {
+        for col in 0..dem.configs.columns as isize {
+            let z = dem[(row, col)];
+            if !dem.is_nodata(z) && in_basin(row, col) {
+                let bin = (((z - min) / relief) * NUM_CURVE_BINS as f64).floor() as usize;
+                counts[bin.min(NUM_CURVE_BINS - 1)] += 1;
+            }
+        }
+    }
+    let mut curve = Vec::with_capacity(NUM_CURVE_BINS + 1);
+    let mut num_above = n;
+    for k in 0..NUM_CURVE_BINS {
+        curve.push((num_above as f64 / n as f64, k as f64 / NUM_CURVE_BINS as f64));
+        num_above -= counts[k];
+    }
+    curve.push((0f64, 1f64));
+
+    Ok(HypsometryResult {
+        n: n,
+        min: min,
+        max: max,
+        mean: mean,
+        integral: (mean - min) / relief,
+        curve: curve,
+    })
+}
+
+/// Draws an SVG plot of a hypsometric curve, relative height against relative area.
+fn curve_svg(curve: &[(f64, f64)]) -> String {
+    let (width, height, margin) = (500f64, 500f64, 60f64);
+    let scale_x = |v: f64| margin + v * (width - 2f64 * margin);
+    let scale_y = |v: f64| height - margin - v * (height - 2f64 * margin);
+
+    let mut s = format!("        <p align=\"center\">
+        <svg width=\"{w}\" height=\"{h}\" xmlns=\"http://www.w3.org/2000/svg\" font-family=\"Helvetica, Arial, sans-serif\" font-size=\"12\">
+            <rect x=\"{m}\" y=\"{m}\" width=\"{pw}\" height=\"{ph}\" fill=\"none\" stroke=\"#222222\"/>
+            <text x=\"{m}\" y=\"{ty}\">0</text>
+            <text x=\"{tx}\" y=\"{ty}\" text-anchor=\"end\">1</text>
+            <text x=\"{cx}\" y=\"{ly}\" text-anchor=\"middle\">Relative area (a/A)</text>
+            <text x=\"15\" y=\"{cy}\" text-anchor=\"middle\" transform=\"rotate(-90 15 {cy})\">Relative height (h/H)</text>
+",
+        w = width, h = height, m = margin, pw = width - 2f64 * margin, ph = height - 2f64 * margin,
+        tx = width - margin, ty = height - margin + 15f64,
+        cx = width / 2f64, ly = height - 20f64, cy = height / 2f64);
+    let points = curve.iter()
+                      .map(|&(a, h)| format!("{:.1},{:.1}", scale_x(a), scale_y(h)))
+                      .collect::<Vec<String>>()
+                      .join(" ");
+    s.push_str(&format!("            <polyline points=\"{}\" fill=\"none\" stroke=\"#1f4e99\" stroke-width=\"2\"/>\n", points));
+    s.push_str("        </svg>\n        </p>\n");
+    s
+}
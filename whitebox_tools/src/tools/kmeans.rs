@@ -0,0 +1,293 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::seeded_rng::SeededRng;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "kmeans".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Performs an unsupervised k-means classification of a stack of rasters (e.g. image bands).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --inputs       Input raster files, separated by semicolons or commas.
+-o, --output       Output raster file.
+--classes          Number of classes; default is 5.
+--max_iter         Maximum number of iterations; default is 25.
+--seed             Optional seed for the random choice of the initial cluster centres.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=kmeans --wd=\"/dir/to/data\" --args=\"-i=\"band1.dep;band2.dep;band3.dep\" --classes=8 --seed=42 -o=classes.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_files = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut k = 5usize;
+    let mut max_iter = 25usize;
+    let mut seed: Option<u64> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--inputs" {
+            if keyval {
+                input_files = vec[1].to_string();
+            } else {
+                input_files = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-classes" || vec[0].to_lowercase() == "--classes" {
+            if keyval {
+                k = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                k = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-max_iter" || vec[0].to_lowercase() == "--max_iter" {
+            if keyval {
+                max_iter = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                max_iter = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-seed" || vec[0].to_lowercase() == "--seed" {
+            if keyval {
+                seed = Some(vec[1].to_string().parse::<u64>().unwrap());
+            } else {
+                seed = Some(args[i+1].to_string().parse::<u64>().unwrap());
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*********************");
+        println!("* Welcome to kmeans *");
+        println!("*********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut cmd = input_files.split(";");
+    let mut vec = cmd.collect::<Vec<&str>>();
+    if vec.len() == 1 {
+        cmd = input_files.split(",");
+        vec = cmd.collect::<Vec<&str>>();
+    }
+    let mut inputs: Vec<Raster> = vec![];
+    for value in vec {
+        if !value.trim().is_empty() {
+            let mut input_file = value.trim().to_owned();
+            if !input_file.contains(&sep) {
+                input_file = format!("{}{}", working_directory, input_file);
+            }
+            inputs.push(Raster::new(&input_file, "r")?);
+        }
+    }
+    // Without a seed the classification differs from run to run, so record the seed used.
+    let seed = match seed {
+        Some(s) => s,
+        None => SeededRng::from_time().next_u64(),
+    };
+
+    let start = time::now();
+
+    if verbose { println!("Performing k-means classification...") };
+    let mut output = kmeans(&inputs, k, max_iter, seed)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' kmeans tool".to_owned());
+    output.add_metadata_entry(format!("Input files: {}", input_files));
+    output.add_metadata_entry(format!("Classes: {}", k));
+    output.add_metadata_entry(format!("Maximum iterations: {}", max_iter));
+    output.add_metadata_entry(format!("Seed: {}", seed));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Classifies the cells of a stack of aligned rasters (see `check_alignment`) into `k` classes,
+/// numbered from 1, by k-means clustering of each cell's vector of layer values. The initial
+/// cluster centres are chosen with the k-means++ method using a `SeededRng`, so a given `seed`
+/// always yields the same classification, and the assignment and update steps are repeated
+/// until no cell changes class or `max_iter` iterations have been performed. A cluster that
+/// becomes empty is restarted at the cell farthest from its current centre. Values are used
+/// as they are, so layers with larger ranges carry more weight; cells that are nodata or
+/// non-finite in any layer are nodata in the output, which holds I16 class numbers 1 to `k`.
+pub fn kmeans(layers: &[Raster], k: usize, max_iter: usize, seed: u64) -> Result<Raster, Error> {
+    if layers.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "At least one input raster is required."));
+    }
+    if k == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The number of classes must be greater than zero."));
+    }
+    if k > i16::max_value() as usize {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("The number of classes must not exceed {}.", i16::max_value())));
+    }
+    let first = &layers[0];
+    for layer in &layers[1..] {
+        check_alignment(first, layer)?;
+    }
+    let rows = first.configs.rows as isize;
+    let columns = first.configs.columns as isize;
+    let num_layers = layers.len();
+
+    // The feature vectors of the valid cells, stored contiguously. Non-finite values would
+    // poison the centres and distances, so they are treated as nodata.
+    let mut cells = vec![];
+    let mut features = vec![];
+    for row in 0..rows {
+        for col in 0..columns {
+            if layers.iter().any(|l| l.is_nodata(l[(row, col)]) || !l[(row, col)].is_finite()) {
+                continue;
+            }
+            cells.push((row, col));
+            for l in layers {
+                features.push(l[(row, col)]);
+            }
+        }
+    }
+    let n = cells.len();
+    let mut output = Raster::initialize_using_file(&first.file_name, first);
+    output.set_data_type(DataType::I16);
+    output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+    if n == 0 {
+        return Ok(output);
+    }
+    let feature = |i: usize| -> &[f64] { &features[i * num_layers..(i + 1) * num_layers] };
+    let dist_sqr = |a: &[f64], b: &[f64]| -> f64 {
+        a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+    };
+
+    // k-means++ initialization: each further centre is a cell chosen with probability
+    // proportional to its squared distance from the nearest existing centre.
+    let mut rng = SeededRng::new(seed);
+    let mut centres: Vec<f64> = feature(rng.gen_range(n)).to_vec();
+    let mut nearest = vec![f64::INFINITY; n];
+    for c in 1..k {
+        let mut total = 0f64;
+        for i in 0..n {
+            let d = dist_sqr(feature(i), &centres[(c - 1) * num_layers..c * num_layers]);
+            if d < nearest[i] {
+                nearest[i] = d;
+            }
+            total += nearest[i];
+        }
+        let mut chosen = rng.gen_range(n);
+        if total > 0f64 {
+            let target = rng.next_f64() * total;
+            let mut cumulative = 0f64;
+            for i in 0..n {
+                cumulative += nearest[i];
+                if cumulative > target {
+                    chosen = i;
+                    break;
+                }
+            }
+        }
+        centres.extend_from_slice(feature(chosen));
+    }
+
+    let mut class = vec![usize::max_value(); n];
+    let mut sums = vec![0f64; k * num_layers];
+    let mut counts = vec![0usize; k];
+    for _ in 0..max_iter.max(1) {
+        let mut changed = false;
+        for i in 0..n {
+            let mut best = 0;
+            let mut best_dist = f64::INFINITY;
+            for c in 0..k {
+                let d = dist_sqr(feature(i), &centres[c * num_layers..(c + 1) * num_layers]);
+                if d < best_dist {
+                    best_dist = d;
+                    best = c;
+                }
+            }
+            if class[i] != best {
+                class[i] = best;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        for v in sums.iter_mut() { *v = 0f64; }
+        for v in counts.iter_mut() { *v = 0; }
+        for i in 0..n {
+            counts[class[i]] += 1;
+            for j in 0..num_layers {
+                sums[class[i] * num_layers + j] += feature(i)[j];
+            }
+        }
+        for c in 0..k {
+            if counts[c] > 0 {
+                for j in 0..num_layers {
+                    centres[c * num_layers + j] = sums[c * num_layers + j] / counts[c] as f64;
+                }
+            } else {
+                let farthest = (0..n).max_by(|&a, &b| {
+                    let da = dist_sqr(feature(a), &centres[class[a] * num_layers..(class[a] + 1) * num_layers]);
+                    let db = dist_sqr(feature(b), &centres[class[b] * num_layers..(class[b] + 1) * num_layers]);
+                    da.partial_cmp(&db).unwrap()
+                }).unwrap();
+                centres[c * num_layers..(c + 1) * num_layers].copy_from_slice(&features[farthest * num_layers..(farthest + 1) * num_layers]);
+            }
+        }
+    }
+
+    for i in 0..n {
+        output.set_value(cells[i].0, cells[i].1, (class[i] + 1) as f64);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,153 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::collections::HashMap;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::clump::clump;
+
+pub fn get_tool_name() -> String {
+    return "largest_clump".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Extracts the largest connected region of non-zero cells as a 1/0 mask, e.g. the main lake of a water mask.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--diag             Optional flag indicating that diagonal neighbours are connected (8-connectivity).
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=largest_clump --wd=\"/dir/to/data\" --args=\"-i=water.dep -o=main_lake.dep --diag\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut diagonals = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-diag" || vec[0].to_lowercase() == "--diag" {
+            diagonals = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("****************************");
+        println!("* Welcome to largest_clump *");
+        println!("****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Finding the largest clump...") };
+    let mut output = largest_clump(&input, diagonals);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' largest_clump tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Diagonal connectivity: {}", diagonals));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Returns a mask of the largest connected region of non-zero cells, e.g. the main lake of a
+/// water mask without its slivers. Regions of equal-valued cells are found with `clump`, using
+/// 8-connectivity if `diagonals` is true, and the region with the most cells is set to 1 in
+/// the output while all other valid cells are 0. Zero-valued cells are background and never
+/// form the largest region. Ties go to the region with the lowest clump id, i.e. the one whose
+/// first cell comes first in row-major order. Nodata cells remain nodata.
+pub fn largest_clump(input: &Raster, diagonals: bool) -> Raster {
+    let clumps = clump(input, diagonals);
+    let mut sizes: HashMap<usize, usize> = HashMap::new();
+    for (row, col, z) in input.iter_valid() {
+        if z != 0f64 {
+            *sizes.entry(clumps[(row, col)] as usize).or_insert(0) += 1;
+        }
+    }
+    let mut largest = 0usize; // clump ids start at 1
+    let mut largest_size = 0usize;
+    for (&id, &size) in &sizes {
+        if size > largest_size || (size == largest_size && id < largest) {
+            largest = id;
+            largest_size = size;
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.photometric_interp = PhotometricInterpretation::Boolean;
+    for row in 0..input.configs.rows as isize {
+        for col in 0..input.configs.columns as isize {
+            if !input.is_nodata(input[(row, col)]) {
+                output.set_value(row, col, if clumps[(row, col)] as usize == largest { 1f64 } else { 0f64 });
+            }
+        }
+    }
+
+    output
+}
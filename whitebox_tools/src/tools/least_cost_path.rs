@@ -0,0 +1,162 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use vector::Polyline;
+use vector::geojson;
+use vector::shapefile::Shapefile;
+
+pub fn get_tool_name() -> String {
+    return "least_cost_path".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Traces least-cost paths from destination points back to the sources using a cost_distance backlink raster.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--backlink         Input backlink raster file, created by the cost_distance tool.
+--destinations     Input point shapefile of destinations.
+-o, --output       Output GeoJSON file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=least_cost_path --wd=\"/dir/to/data\" --args=\"--backlink=backlink.dep --destinations=destinations.shp -o=paths.geojson\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut backlink_file = String::new();
+    let mut destinations_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-backlink" || vec[0].to_lowercase() == "--backlink" {
+            if keyval {
+                backlink_file = vec[1].to_string();
+            } else {
+                backlink_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-destinations" || vec[0].to_lowercase() == "--destinations" {
+            if keyval {
+                destinations_file = vec[1].to_string();
+            } else {
+                destinations_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to least_cost_path *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !backlink_file.contains(&sep) {
+        backlink_file = format!("{}{}", working_directory, backlink_file);
+    }
+    if !destinations_file.contains(&sep) {
+        destinations_file = format!("{}{}", working_directory, destinations_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let backlink = Raster::new(&backlink_file, "r")?;
+    let destinations = Shapefile::read_points(&destinations_file)?;
+
+    let start = time::now();
+
+    if verbose { println!("Tracing paths...") };
+    let mut paths = vec![];
+    for (i, &(x, y, _)) in destinations.iter().enumerate() {
+        let points = least_cost_path(&backlink, (x, y));
+        if points.len() > 1 {
+            paths.push(Polyline::new(points, (i + 1) as f64));
+        } else if verbose {
+            println!("Warning: destination {} is not connected to a source and was skipped.", i + 1);
+        }
+    }
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let _ = match geojson::write_polylines(&output_file, &paths, "DEST_ID") {
+        Ok(_) => if verbose { println!("Output file written ({} paths)", paths.len()) },
+        Err(e) => return Err(e),
+    };
+    if verbose {
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Traces the least-cost path from the world coordinate `dest` back to a source by following a
+/// backlink raster produced by `cost_distance`, returning the cell centres visited as world
+/// coordinates, beginning at the destination and ending at the source. An empty path is
+/// returned if the destination lies outside the raster or on a nodata cell, and the trace stops
+/// early if it meets an invalid backlink code or revisits a cell.
+pub fn least_cost_path(backlink: &Raster, dest: (f64, f64)) -> Vec<(f64, f64)> {
+    let rows = backlink.configs.rows as isize;
+    let columns = backlink.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+
+    let mut path = vec![];
+    let mut row = backlink.get_row_from_y(dest.1);
+    let mut col = backlink.get_column_from_x(dest.0);
+    if row < 0 || col < 0 || row >= rows || col >= columns {
+        return path;
+    }
+    let mut visited = vec![false; (rows * columns) as usize];
+    let mut z: f64;
+    loop {
+        z = backlink[(row, col)];
+        if backlink.is_nodata(z) || visited[(row * columns + col) as usize] {
+            break;
+        }
+        visited[(row * columns + col) as usize] = true;
+        path.push((backlink.get_x_from_column(col), backlink.get_y_from_row(row)));
+        if z == 0f64 {
+            break; // reached a source
+        }
+        let n = match (0..8).find(|&n| z == (1 << n) as f64) {
+            Some(n) => n,
+            None => break,
+        };
+        row += d_y[n];
+        col += d_x[n];
+    }
+
+    path
+}
@@ -3,6 +3,7 @@ extern crate time;
 use std::f64;
 use std::path;
 use std::io::{Error, ErrorKind};
+use io_utils::file_utils::check_output_file;
 use lidar::las;
 
 pub fn get_tool_name() -> String {
@@ -23,7 +24,8 @@ pub fn get_tool_parameters() -> String {
 --minz             Minimum elevation value.
 --class            Optional boolean flag indicating whether points outside the range should be retained in output but reclassified.
 --inclassval       Optional parameter specifying the class value assigned to points within the slice; default is 2.
---outclassval      Optional parameter specifying the class value assigned to points outside the slice; default is 1.";
+--outclassval      Optional parameter specifying the class value assigned to points outside the slice; default is 1.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
     return s.to_string();
 }
 
@@ -37,6 +39,7 @@ pub fn get_example_usage() -> Option<String> {
 pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
     let mut input_file: String = "".to_string();
     let mut output_file: String = "".to_string();
+    let mut overwrite = false;
     let mut minz = -f64::INFINITY;
     let mut maxz = f64::INFINITY;
     let mut filter = true;
@@ -94,6 +97,8 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
             } else {
                 out_class_value = args[i+1].to_string().parse::<u8>().unwrap();
             }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
         }
     }
 
@@ -103,6 +108,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     if !output_file.contains(path::MAIN_SEPARATOR) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    check_output_file(&output_file, overwrite)?;
 
     if verbose {
         println!("***********************************");
@@ -3,6 +3,7 @@ extern crate time;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
+use io_utils::file_utils::check_output_file;
 use lidar::las;
 use raster::*;
 use structures::fixed_radius_search::FixedRadiusSearch;
@@ -22,7 +23,8 @@ pub fn get_tool_parameters() -> String {
     let s = "-i, --input        Input LAS file.
 -o, --output       Output raster file.
 --resolution       Output raster's grid resolution.
---palette          Optional palette name (for use with Whitebox raster files).";
+--palette          Optional palette name (for use with Whitebox raster files).
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
     return s.to_string();
 }
 
@@ -35,6 +37,8 @@ pub fn get_example_usage() -> Option<String> {
 pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
     let mut input_file: String = "".to_string();
     let mut output_file: String = "".to_string();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
     let mut grid_res: f64 = 1.0;
     let mut palette = "default".to_string();
 
@@ -73,6 +77,14 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
             } else {
                 palette = args[i+1].to_string();
             }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
         }
     }
 
@@ -82,6 +94,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     if !output_file.contains(path::MAIN_SEPARATOR) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    check_output_file(&output_file, overwrite)?;
 
     if verbose {
         println!("***************************************");
@@ -220,6 +233,9 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
 
     if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
     let _ = match output.write() {
         Ok(_) => if verbose { println!("Output file written") },
         Err(e) => return Err(e),
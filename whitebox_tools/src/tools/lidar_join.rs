@@ -2,6 +2,7 @@ extern crate nalgebra as na;
 
 use std;
 use std::io::{Error, ErrorKind};
+use io_utils::file_utils::check_output_file;
 use lidar::las;
 
 pub fn get_tool_name() -> String {
@@ -16,7 +17,8 @@ pub fn get_tool_description() -> String {
 
 pub fn get_tool_parameters() -> String {
     let s = "-i, --inputs       Input LAS files, separated by commas
--o, --output       Output LAS file.";
+-o, --output       Output LAS file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
 
     return s.to_string();
 }
@@ -29,6 +31,7 @@ pub fn get_example_usage() -> Option<String> {
 pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
     let mut input_files: String = String::new();
     let mut output_file = String::new();
+    let mut overwrite = false;
 
     // read the arguments
     if args.len() == 0 {
@@ -53,6 +56,8 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
             } else {
                 output_file = args[i+1].to_string();
             }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
         }
     }
 
@@ -70,6 +75,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     if !output_file.contains(sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    check_output_file(&output_file, overwrite)?;
 
     let mut output: las::LasFile = las::LasFile::new(&output_file, "w")?;
 
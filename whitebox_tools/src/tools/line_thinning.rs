@@ -0,0 +1,193 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "line_thinning".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Reduces the features of a Boolean raster to single-cell-wide skeletons (Zhang-Suen thinning).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=line_thinning --wd=\"/dir/to/data\" --args=\"-i=streams.dep -o=thinned.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("****************************");
+        println!("* Welcome to line_thinning *");
+        println!("****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Thinning...") };
+    let mut output = thin(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' line_thinning tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Reduces the foreground features of a binary raster (non-zero cells) to single-cell-wide
+/// skeletons using Zhang-Suen thinning, repeating the two sub-iterations until no further
+/// cells are removed. A cell is only removed if it has between two and six foreground
+/// neighbours and exactly one background-to-foreground transition around it, so features stay
+/// connected and line endpoints are not eroded. The output is 1 on the skeleton and 0 in the
+/// background; nodata cells are treated as background and remain nodata.
+pub fn thin(input: &Raster) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    // Neighbours clockwise from north (P2 to P9 in Zhang and Suen's notation).
+    let d_x = [ 0, 1, 1, 1, 0, -1, -1, -1 ];
+    let d_y = [ -1, -1, 0, 1, 1, 1, 0, -1 ];
+
+    let mut grid = vec![0u8; (rows * columns) as usize];
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = input[(row, col)];
+            if !input.is_nodata(z) && z != 0f64 {
+                grid[(row * columns + col) as usize] = 1;
+            }
+        }
+    }
+    let get = |grid: &Vec<u8>, row: isize, col: isize| -> u8 {
+        if row < 0 || col < 0 || row >= rows || col >= columns { 0 } else { grid[(row * columns + col) as usize] }
+    };
+
+    let mut p = [0u8; 8];
+    let mut removals = vec![];
+    loop {
+        let mut changed = false;
+        for step in 0..2 {
+            for row in 0..rows {
+                for col in 0..columns {
+                    if grid[(row * columns + col) as usize] == 0 {
+                        continue;
+                    }
+                    for n in 0..8 {
+                        p[n] = get(&grid, row + d_y[n], col + d_x[n]);
+                    }
+                    let b: u8 = p.iter().sum();
+                    if b < 2 || b > 6 {
+                        continue;
+                    }
+                    let a = (0..8).filter(|&n| p[n] == 0 && p[(n + 1) % 8] == 1).count();
+                    if a != 1 {
+                        continue;
+                    }
+                    // p[0], p[2], p[4] and p[6] are the N, E, S and W neighbours.
+                    let removable = if step == 0 {
+                        p[0] * p[2] * p[4] == 0 && p[2] * p[4] * p[6] == 0
+                    } else {
+                        p[0] * p[2] * p[6] == 0 && p[0] * p[4] * p[6] == 0
+                    };
+                    if removable {
+                        removals.push((row * columns + col) as usize);
+                    }
+                }
+            }
+            if !removals.is_empty() {
+                changed = true;
+            }
+            for idx in removals.drain(..) {
+                grid[idx] = 0;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.photometric_interp = PhotometricInterpretation::Boolean;
+    for row in 0..rows {
+        for col in 0..columns {
+            if !input.is_nodata(input[(row, col)]) {
+                output.set_value(row, col, grid[(row * columns + col) as usize] as f64);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,169 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::neighborhood::for_each_3x3;
+
+pub fn get_tool_name() -> String {
+    return "local_binary_pattern".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the local binary pattern (LBP) texture code of each grid cell, optionally in rotation-invariant form.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--rotation_invariant Optional flag to output the rotation-invariant LBP codes.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=local_binary_pattern --wd=\"/dir/to/data\" --args=\"-i=image.dep -o=lbp.dep --rotation_invariant\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut rotation_invariant = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-rotation_invariant" || vec[0].to_lowercase() == "--rotation_invariant" {
+            rotation_invariant = true;
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***********************************");
+        println!("* Welcome to local_binary_pattern *");
+        println!("***********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating local binary patterns...") };
+    let mut output = if rotation_invariant { rotation_invariant_lbp(&input) } else { local_binary_pattern(&input) };
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' local_binary_pattern tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Rotation invariant: {}", rotation_invariant));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the local binary pattern (LBP; Ojala et al., 1996) texture code of each grid cell.
+/// Each of the eight neighbours contributes one bit, set if the neighbour is greater than or
+/// equal to the cell, with the bits in the order of the D8 pointer codes, i.e. NE = 1, E = 2,
+/// SE = 4, S = 8, SW = 16, W = 32, NW = 64 and N = 128. Cells with any nodata or off-grid
+/// neighbour are assigned nodata.
+pub fn local_binary_pattern(input: &Raster) -> Raster {
+    // The window index of the neighbour for each bit.
+    let neighbours = [2, 5, 8, 7, 6, 3, 0, 1];
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.set_data_type(DataType::I16);
+    output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+    for_each_3x3(input, |row, col, window| {
+        let mut code = 0u8;
+        for (bit, &i) in neighbours.iter().enumerate() {
+            if input.is_nodata(window[i]) {
+                return;
+            }
+            if window[i] >= window[4] {
+                code |= 1 << bit;
+            }
+        }
+        output.set_value(row, col, code as f64);
+    });
+
+    output
+}
+
+/// Calculates the rotation-invariant form of `local_binary_pattern`, in which each code is
+/// replaced by the smallest code among its eight circular bit rotations, so that a pattern
+/// gives the same code whatever its orientation.
+pub fn rotation_invariant_lbp(input: &Raster) -> Raster {
+    let mut output = local_binary_pattern(input);
+    let rows = output.configs.rows as isize;
+    let columns = output.configs.columns as isize;
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = output[(row, col)];
+            if !output.is_nodata(z) {
+                let code = z as u8;
+                let min_code = (0..8).map(|r| code.rotate_right(r)).min().unwrap();
+                output.set_value(row, col, min_code as f64);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,171 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use std::collections::HashMap;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "majority_filter".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Assigns each cell the most frequent value within a moving window; intended for smoothing categorical rasters.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 11.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=majority_filter --wd=\"/dir/to/data\" --args=\"-i=landcover.dep -o=output.dep --filter=5\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 11usize;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to majority_filter *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Performing majority filter...") };
+    let mut output = majority_filter(&input, filter_size);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' majority_filter tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Replaces each grid cell with the most frequent (modal) non-nodata value within the filter
+/// window. Values are counted exactly, so integer class codes are preserved. Ties are broken in
+/// favour of the centre cell's value when it is among the most frequent, which keeps class
+/// boundaries from flickering, and otherwise in favour of the lowest value. Nodata cells remain
+/// nodata.
+pub fn majority_filter(input: &Raster, filter_size: usize) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    let (mut z, mut z_n): (f64, f64);
+    let mut counts: HashMap<u64, usize> = HashMap::new();
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input.get_value(row, col);
+            if !input.is_nodata(z) {
+                counts.clear();
+                for row2 in (row - midpoint)..(row + midpoint + 1) {
+                    for col2 in (col - midpoint)..(col + midpoint + 1) {
+                        z_n = input.get_value(row2, col2);
+                        if !input.is_nodata(z_n) { *counts.entry(z_n.to_bits()).or_insert(0) += 1; }
+                    }
+                }
+                let mut majority = z;
+                let mut max_count = counts[&z.to_bits()];
+                for (&bits, &count) in counts.iter() {
+                    z_n = f64::from_bits(bits);
+                    if count > max_count || (count == max_count && majority != z && z_n < majority) {
+                        majority = z_n;
+                        max_count = count;
+                    }
+                }
+                output.set_value(row, col, majority);
+            }
+        }
+    }
+
+    output
+}
@@ -1,8 +1,82 @@
+pub mod adaptive_smoothing;
+pub mod affine_warp;
+pub mod align_to;
+pub mod apply_mask;
+pub mod buffer;
+pub mod burn_streams;
+pub mod cast_shadows;
+pub mod clip;
+pub mod closing;
+pub mod clump;
+pub mod color_relief;
+pub mod conditional;
+pub mod contours;
+pub mod convert_vertical_units;
+pub mod cost_distance;
+pub mod d8_flow_accumulation;
+pub mod depression_stats;
+pub mod detect_flats;
+pub mod dev;
+pub mod dilation;
+pub mod dinf_flow_accumulation;
+pub mod dinf_pointer;
+pub mod directional_slope;
+pub mod downslope_index;
+pub mod downslope_profile_curvature;
+pub mod elev_percentile;
+pub mod erosion;
+pub mod euclidean_distance;
+pub mod feature_preserving_smoothing;
+pub mod fill_single_cell_pits;
+pub mod find_outlets;
+pub mod histogram_match;
+pub mod hypsometry;
+pub mod kmeans;
+pub mod largest_clump;
+pub mod least_cost_path;
 pub mod lidar_elevation_slice;
 pub mod lidar_flightline_overlap;
 pub mod lidar_info;
 pub mod lidar_join;
+pub mod line_thinning;
+pub mod local_binary_pattern;
+pub mod majority_filter;
+pub mod mosaic;
+pub mod normalize;
+pub mod num_inflowing_neighbors;
+pub mod opening;
+pub mod openness;
+pub mod pad;
+pub mod patch_statistics;
+pub mod percentile_filter;
+pub mod percentile_stretch;
+pub mod raster_comparison;
+pub mod raster_difference;
+pub mod raster_streams_to_vector;
+pub mod raster_to_points;
 pub mod remove_off_terrain_objects;
+pub mod replace_nodata;
+pub mod resample;
+pub mod round_values;
+pub mod ruggedness_index;
+pub mod set_nodata_value;
+pub mod snap_pour_points;
+pub mod solar_radiation;
+pub mod specific_catchment_area;
+pub mod split_bands;
+pub mod stack_bands;
+pub mod stdev_filter;
+pub mod stream_power_index;
+pub mod surface_area_ratio;
+pub mod terrain_fabric;
+pub mod tophat_transform;
+pub mod tpi;
+pub mod trim_nodata;
+pub mod upstream_average;
+pub mod viewshed;
+pub mod weighted_overlay;
+pub mod wetness_index;
+pub mod wiener_filter;
 
 use tools;
 use std::io::{Error, ErrorKind};
@@ -24,6 +98,114 @@ impl ToolManager {
         //     tool_args_vec.insert(0, format!("--wd={}", working_dir));
         // }
         match tool_name.to_lowercase().as_ref() {
+            "adaptive_smoothing" => {
+                return tools::adaptive_smoothing::run(args, &self.working_dir, self.verbose);
+            },
+            "affine_warp" => {
+                return tools::affine_warp::run(args, &self.working_dir, self.verbose);
+            },
+            "align_to" => {
+                return tools::align_to::run(args, &self.working_dir, self.verbose);
+            },
+            "apply_mask" => {
+                return tools::apply_mask::run(args, &self.working_dir, self.verbose);
+            },
+            "buffer" => {
+                return tools::buffer::run(args, &self.working_dir, self.verbose);
+            },
+            "burn_streams" => {
+                return tools::burn_streams::run(args, &self.working_dir, self.verbose);
+            },
+            "cast_shadows" => {
+                return tools::cast_shadows::run(args, &self.working_dir, self.verbose);
+            },
+            "clip" => {
+                return tools::clip::run(args, &self.working_dir, self.verbose);
+            },
+            "closing" => {
+                return tools::closing::run(args, &self.working_dir, self.verbose);
+            },
+            "clump" => {
+                return tools::clump::run(args, &self.working_dir, self.verbose);
+            },
+            "color_relief" => {
+                return tools::color_relief::run(args, &self.working_dir, self.verbose);
+            },
+            "conditional" => {
+                return tools::conditional::run(args, &self.working_dir, self.verbose);
+            },
+            "contours" => {
+                return tools::contours::run(args, &self.working_dir, self.verbose);
+            },
+            "convert_vertical_units" => {
+                return tools::convert_vertical_units::run(args, &self.working_dir, self.verbose);
+            },
+            "cost_distance" => {
+                return tools::cost_distance::run(args, &self.working_dir, self.verbose);
+            },
+            "d8_flow_accumulation" => {
+                return tools::d8_flow_accumulation::run(args, &self.working_dir, self.verbose);
+            },
+            "depression_stats" => {
+                return tools::depression_stats::run(args, &self.working_dir, self.verbose);
+            },
+            "detect_flats" => {
+                return tools::detect_flats::run(args, &self.working_dir, self.verbose);
+            },
+            "dev" => {
+                return tools::dev::run(args, &self.working_dir, self.verbose);
+            },
+            "dilation" => {
+                return tools::dilation::run(args, &self.working_dir, self.verbose);
+            },
+            "dinf_flow_accumulation" => {
+                return tools::dinf_flow_accumulation::run(args, &self.working_dir, self.verbose);
+            },
+            "dinf_pointer" => {
+                return tools::dinf_pointer::run(args, &self.working_dir, self.verbose);
+            },
+            "directional_slope" => {
+                return tools::directional_slope::run(args, &self.working_dir, self.verbose);
+            },
+            "downslope_index" => {
+                return tools::downslope_index::run(args, &self.working_dir, self.verbose);
+            },
+            "downslope_profile_curvature" => {
+                return tools::downslope_profile_curvature::run(args, &self.working_dir, self.verbose);
+            },
+            "elev_percentile" => {
+                return tools::elev_percentile::run(args, &self.working_dir, self.verbose);
+            },
+            "erosion" => {
+                return tools::erosion::run(args, &self.working_dir, self.verbose);
+            },
+            "euclidean_distance" => {
+                return tools::euclidean_distance::run(args, &self.working_dir, self.verbose);
+            },
+            "feature_preserving_smoothing" => {
+                return tools::feature_preserving_smoothing::run(args, &self.working_dir, self.verbose);
+            },
+            "fill_single_cell_pits" => {
+                return tools::fill_single_cell_pits::run(args, &self.working_dir, self.verbose);
+            },
+            "find_outlets" => {
+                return tools::find_outlets::run(args, &self.working_dir, self.verbose);
+            },
+            "histogram_match" => {
+                return tools::histogram_match::run(args, &self.working_dir, self.verbose);
+            },
+            "hypsometry" => {
+                return tools::hypsometry::run(args, &self.working_dir, self.verbose);
+            },
+            "kmeans" => {
+                return tools::kmeans::run(args, &self.working_dir, self.verbose);
+            },
+            "largest_clump" => {
+                return tools::largest_clump::run(args, &self.working_dir, self.verbose);
+            },
+            "least_cost_path" => {
+                return tools::least_cost_path::run(args, &self.working_dir, self.verbose);
+            },
             "lidar_elevation_slice" => {
                 return tools::lidar_elevation_slice::run(args, &self.working_dir, self.verbose);
             }
@@ -36,9 +218,123 @@ impl ToolManager {
             "lidar_join" => {
                 return tools::lidar_join::run(args, &self.working_dir, self.verbose);
             },
+            "line_thinning" => {
+                return tools::line_thinning::run(args, &self.working_dir, self.verbose);
+            },
+            "local_binary_pattern" => {
+                return tools::local_binary_pattern::run(args, &self.working_dir, self.verbose);
+            },
+            "majority_filter" => {
+                return tools::majority_filter::run(args, &self.working_dir, self.verbose);
+            },
+            "mosaic" => {
+                return tools::mosaic::run(args, &self.working_dir, self.verbose);
+            },
+            "normalize" => {
+                return tools::normalize::run(args, &self.working_dir, self.verbose);
+            },
+            "num_inflowing_neighbors" => {
+                return tools::num_inflowing_neighbors::run(args, &self.working_dir, self.verbose);
+            },
+            "opening" => {
+                return tools::opening::run(args, &self.working_dir, self.verbose);
+            },
+            "openness" => {
+                return tools::openness::run(args, &self.working_dir, self.verbose);
+            },
+            "pad" => {
+                return tools::pad::run(args, &self.working_dir, self.verbose);
+            },
+            "patch_statistics" => {
+                return tools::patch_statistics::run(args, &self.working_dir, self.verbose);
+            },
+            "percentile_filter" => {
+                return tools::percentile_filter::run(args, &self.working_dir, self.verbose);
+            },
+            "percentile_stretch" => {
+                return tools::percentile_stretch::run(args, &self.working_dir, self.verbose);
+            },
+            "raster_comparison" => {
+                return tools::raster_comparison::run(args, &self.working_dir, self.verbose);
+            },
+            "raster_difference" => {
+                return tools::raster_difference::run(args, &self.working_dir, self.verbose);
+            },
+            "raster_streams_to_vector" => {
+                return tools::raster_streams_to_vector::run(args, &self.working_dir, self.verbose);
+            },
+            "raster_to_points" => {
+                return tools::raster_to_points::run(args, &self.working_dir, self.verbose);
+            },
             "remove_off_terrain_objects" => {
                 return tools::remove_off_terrain_objects::run(args, &self.working_dir, self.verbose);
             },
+            "replace_nodata" => {
+                return tools::replace_nodata::run(args, &self.working_dir, self.verbose);
+            },
+            "resample" => {
+                return tools::resample::run(args, &self.working_dir, self.verbose);
+            },
+            "round_values" => {
+                return tools::round_values::run(args, &self.working_dir, self.verbose);
+            },
+            "ruggedness_index" => {
+                return tools::ruggedness_index::run(args, &self.working_dir, self.verbose);
+            },
+            "set_nodata_value" => {
+                return tools::set_nodata_value::run(args, &self.working_dir, self.verbose);
+            },
+            "snap_pour_points" => {
+                return tools::snap_pour_points::run(args, &self.working_dir, self.verbose);
+            },
+            "solar_radiation" => {
+                return tools::solar_radiation::run(args, &self.working_dir, self.verbose);
+            },
+            "specific_catchment_area" => {
+                return tools::specific_catchment_area::run(args, &self.working_dir, self.verbose);
+            },
+            "split_bands" => {
+                return tools::split_bands::run(args, &self.working_dir, self.verbose);
+            },
+            "stack_bands" => {
+                return tools::stack_bands::run(args, &self.working_dir, self.verbose);
+            },
+            "stdev_filter" => {
+                return tools::stdev_filter::run(args, &self.working_dir, self.verbose);
+            },
+            "stream_power_index" => {
+                return tools::stream_power_index::run(args, &self.working_dir, self.verbose);
+            },
+            "surface_area_ratio" => {
+                return tools::surface_area_ratio::run(args, &self.working_dir, self.verbose);
+            },
+            "terrain_fabric" => {
+                return tools::terrain_fabric::run(args, &self.working_dir, self.verbose);
+            },
+            "tophat_transform" => {
+                return tools::tophat_transform::run(args, &self.working_dir, self.verbose);
+            },
+            "tpi" => {
+                return tools::tpi::run(args, &self.working_dir, self.verbose);
+            },
+            "trim_nodata" => {
+                return tools::trim_nodata::run(args, &self.working_dir, self.verbose);
+            },
+            "upstream_average" => {
+                return tools::upstream_average::run(args, &self.working_dir, self.verbose);
+            },
+            "viewshed" => {
+                return tools::viewshed::run(args, &self.working_dir, self.verbose);
+            },
+            "weighted_overlay" => {
+                return tools::weighted_overlay::run(args, &self.working_dir, self.verbose);
+            },
+            "wetness_index" => {
+                return tools::wetness_index::run(args, &self.working_dir, self.verbose);
+            },
+            "wiener_filter" => {
+                return tools::wiener_filter::run(args, &self.working_dir, self.verbose);
+            },
             _ => Err(Error::new(ErrorKind::NotFound, format!("Unrecognized tool name {}.", tool_name))),
         }
     }
@@ -48,6 +344,294 @@ impl ToolManager {
         let mut parameters = "".to_string();
         let mut example = "".to_string();
         let ret: Result<(), Error> = match tool_name.to_lowercase().as_ref() {
+        "adaptive_smoothing" => {
+            description = tools::adaptive_smoothing::get_tool_description();
+            parameters = tools::adaptive_smoothing::get_tool_parameters();
+            if tools::adaptive_smoothing::get_example_usage().is_some() {
+                example = tools::adaptive_smoothing::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "affine_warp" => {
+            description = tools::affine_warp::get_tool_description();
+            parameters = tools::affine_warp::get_tool_parameters();
+            if tools::affine_warp::get_example_usage().is_some() {
+                example = tools::affine_warp::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "align_to" => {
+            description = tools::align_to::get_tool_description();
+            parameters = tools::align_to::get_tool_parameters();
+            if tools::align_to::get_example_usage().is_some() {
+                example = tools::align_to::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "apply_mask" => {
+            description = tools::apply_mask::get_tool_description();
+            parameters = tools::apply_mask::get_tool_parameters();
+            if tools::apply_mask::get_example_usage().is_some() {
+                example = tools::apply_mask::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "buffer" => {
+            description = tools::buffer::get_tool_description();
+            parameters = tools::buffer::get_tool_parameters();
+            if tools::buffer::get_example_usage().is_some() {
+                example = tools::buffer::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "burn_streams" => {
+            description = tools::burn_streams::get_tool_description();
+            parameters = tools::burn_streams::get_tool_parameters();
+            if tools::burn_streams::get_example_usage().is_some() {
+                example = tools::burn_streams::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "cast_shadows" => {
+            description = tools::cast_shadows::get_tool_description();
+            parameters = tools::cast_shadows::get_tool_parameters();
+            if tools::cast_shadows::get_example_usage().is_some() {
+                example = tools::cast_shadows::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "clip" => {
+            description = tools::clip::get_tool_description();
+            parameters = tools::clip::get_tool_parameters();
+            if tools::clip::get_example_usage().is_some() {
+                example = tools::clip::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "closing" => {
+            description = tools::closing::get_tool_description();
+            parameters = tools::closing::get_tool_parameters();
+            if tools::closing::get_example_usage().is_some() {
+                example = tools::closing::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "clump" => {
+            description = tools::clump::get_tool_description();
+            parameters = tools::clump::get_tool_parameters();
+            if tools::clump::get_example_usage().is_some() {
+                example = tools::clump::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "color_relief" => {
+            description = tools::color_relief::get_tool_description();
+            parameters = tools::color_relief::get_tool_parameters();
+            if tools::color_relief::get_example_usage().is_some() {
+                example = tools::color_relief::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "conditional" => {
+            description = tools::conditional::get_tool_description();
+            parameters = tools::conditional::get_tool_parameters();
+            if tools::conditional::get_example_usage().is_some() {
+                example = tools::conditional::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "contours" => {
+            description = tools::contours::get_tool_description();
+            parameters = tools::contours::get_tool_parameters();
+            if tools::contours::get_example_usage().is_some() {
+                example = tools::contours::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "convert_vertical_units" => {
+            description = tools::convert_vertical_units::get_tool_description();
+            parameters = tools::convert_vertical_units::get_tool_parameters();
+            if tools::convert_vertical_units::get_example_usage().is_some() {
+                example = tools::convert_vertical_units::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "cost_distance" => {
+            description = tools::cost_distance::get_tool_description();
+            parameters = tools::cost_distance::get_tool_parameters();
+            if tools::cost_distance::get_example_usage().is_some() {
+                example = tools::cost_distance::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "d8_flow_accumulation" => {
+            description = tools::d8_flow_accumulation::get_tool_description();
+            parameters = tools::d8_flow_accumulation::get_tool_parameters();
+            if tools::d8_flow_accumulation::get_example_usage().is_some() {
+                example = tools::d8_flow_accumulation::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "depression_stats" => {
+            description = tools::depression_stats::get_tool_description();
+            parameters = tools::depression_stats::get_tool_parameters();
+            if tools::depression_stats::get_example_usage().is_some() {
+                example = tools::depression_stats::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "detect_flats" => {
+            description = tools::detect_flats::get_tool_description();
+            parameters = tools::detect_flats::get_tool_parameters();
+            if tools::detect_flats::get_example_usage().is_some() {
+                example = tools::detect_flats::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "dev" => {
+            description = tools::dev::get_tool_description();
+            parameters = tools::dev::get_tool_parameters();
+            if tools::dev::get_example_usage().is_some() {
+                example = tools::dev::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "dilation" => {
+            description = tools::dilation::get_tool_description();
+            parameters = tools::dilation::get_tool_parameters();
+            if tools::dilation::get_example_usage().is_some() {
+                example = tools::dilation::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "dinf_flow_accumulation" => {
+            description = tools::dinf_flow_accumulation::get_tool_description();
+            parameters = tools::dinf_flow_accumulation::get_tool_parameters();
+            if tools::dinf_flow_accumulation::get_example_usage().is_some() {
+                example = tools::dinf_flow_accumulation::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "dinf_pointer" => {
+            description = tools::dinf_pointer::get_tool_description();
+            parameters = tools::dinf_pointer::get_tool_parameters();
+            if tools::dinf_pointer::get_example_usage().is_some() {
+                example = tools::dinf_pointer::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "directional_slope" => {
+            description = tools::directional_slope::get_tool_description();
+            parameters = tools::directional_slope::get_tool_parameters();
+            if tools::directional_slope::get_example_usage().is_some() {
+                example = tools::directional_slope::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "downslope_index" => {
+            description = tools::downslope_index::get_tool_description();
+            parameters = tools::downslope_index::get_tool_parameters();
+            if tools::downslope_index::get_example_usage().is_some() {
+                example = tools::downslope_index::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "downslope_profile_curvature" => {
+            description = tools::downslope_profile_curvature::get_tool_description();
+            parameters = tools::downslope_profile_curvature::get_tool_parameters();
+            if tools::downslope_profile_curvature::get_example_usage().is_some() {
+                example = tools::downslope_profile_curvature::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "elev_percentile" => {
+            description = tools::elev_percentile::get_tool_description();
+            parameters = tools::elev_percentile::get_tool_parameters();
+            if tools::elev_percentile::get_example_usage().is_some() {
+                example = tools::elev_percentile::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "erosion" => {
+            description = tools::erosion::get_tool_description();
+            parameters = tools::erosion::get_tool_parameters();
+            if tools::erosion::get_example_usage().is_some() {
+                example = tools::erosion::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "euclidean_distance" => {
+            description = tools::euclidean_distance::get_tool_description();
+            parameters = tools::euclidean_distance::get_tool_parameters();
+            if tools::euclidean_distance::get_example_usage().is_some() {
+                example = tools::euclidean_distance::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "feature_preserving_smoothing" => {
+            description = tools::feature_preserving_smoothing::get_tool_description();
+            parameters = tools::feature_preserving_smoothing::get_tool_parameters();
+            if tools::feature_preserving_smoothing::get_example_usage().is_some() {
+                example = tools::feature_preserving_smoothing::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "fill_single_cell_pits" => {
+            description = tools::fill_single_cell_pits::get_tool_description();
+            parameters = tools::fill_single_cell_pits::get_tool_parameters();
+            if tools::fill_single_cell_pits::get_example_usage().is_some() {
+                example = tools::fill_single_cell_pits::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "find_outlets" => {
+            description = tools::find_outlets::get_tool_description();
+            parameters = tools::find_outlets::get_tool_parameters();
+            if tools::find_outlets::get_example_usage().is_some() {
+                example = tools::find_outlets::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "histogram_match" => {
+            description = tools::histogram_match::get_tool_description();
+            parameters = tools::histogram_match::get_tool_parameters();
+            if tools::histogram_match::get_example_usage().is_some() {
+                example = tools::histogram_match::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "hypsometry" => {
+            description = tools::hypsometry::get_tool_description();
+            parameters = tools::hypsometry::get_tool_parameters();
+            if tools::hypsometry::get_example_usage().is_some() {
+                example = tools::hypsometry::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "kmeans" => {
+            description = tools::kmeans::get_tool_description();
+            parameters = tools::kmeans::get_tool_parameters();
+            if tools::kmeans::get_example_usage().is_some() {
+                example = tools::kmeans::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "largest_clump" => {
+            description = tools::largest_clump::get_tool_description();
+            parameters = tools::largest_clump::get_tool_parameters();
+            if tools::largest_clump::get_example_usage().is_some() {
+                example = tools::largest_clump::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "least_cost_path" => {
+            description = tools::least_cost_path::get_tool_description();
+            parameters = tools::least_cost_path::get_tool_parameters();
+            if tools::least_cost_path::get_example_usage().is_some() {
+                example = tools::least_cost_path::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
         "lidar_elevation_slice" => {
             description = tools::lidar_elevation_slice::get_tool_description();
             parameters = tools::lidar_elevation_slice::get_tool_parameters();
@@ -80,6 +664,134 @@ impl ToolManager {
             }
             Ok(())
         },
+        "line_thinning" => {
+            description = tools::line_thinning::get_tool_description();
+            parameters = tools::line_thinning::get_tool_parameters();
+            if tools::line_thinning::get_example_usage().is_some() {
+                example = tools::line_thinning::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "local_binary_pattern" => {
+            description = tools::local_binary_pattern::get_tool_description();
+            parameters = tools::local_binary_pattern::get_tool_parameters();
+            if tools::local_binary_pattern::get_example_usage().is_some() {
+                example = tools::local_binary_pattern::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "majority_filter" => {
+            description = tools::majority_filter::get_tool_description();
+            parameters = tools::majority_filter::get_tool_parameters();
+            if tools::majority_filter::get_example_usage().is_some() {
+                example = tools::majority_filter::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "mosaic" => {
+            description = tools::mosaic::get_tool_description();
+            parameters = tools::mosaic::get_tool_parameters();
+            if tools::mosaic::get_example_usage().is_some() {
+                example = tools::mosaic::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "normalize" => {
+            description = tools::normalize::get_tool_description();
+            parameters = tools::normalize::get_tool_parameters();
+            if tools::normalize::get_example_usage().is_some() {
+                example = tools::normalize::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "num_inflowing_neighbors" => {
+            description = tools::num_inflowing_neighbors::get_tool_description();
+            parameters = tools::num_inflowing_neighbors::get_tool_parameters();
+            if tools::num_inflowing_neighbors::get_example_usage().is_some() {
+                example = tools::num_inflowing_neighbors::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "opening" => {
+            description = tools::opening::get_tool_description();
+            parameters = tools::opening::get_tool_parameters();
+            if tools::opening::get_example_usage().is_some() {
+                example = tools::opening::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "openness" => {
+            description = tools::openness::get_tool_description();
+            parameters = tools::openness::get_tool_parameters();
+            if tools::openness::get_example_usage().is_some() {
+                example = tools::openness::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "pad" => {
+            description = tools::pad::get_tool_description();
+            parameters = tools::pad::get_tool_parameters();
+            if tools::pad::get_example_usage().is_some() {
+                example = tools::pad::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "patch_statistics" => {
+            description = tools::patch_statistics::get_tool_description();
+            parameters = tools::patch_statistics::get_tool_parameters();
+            if tools::patch_statistics::get_example_usage().is_some() {
+                example = tools::patch_statistics::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "percentile_filter" => {
+            description = tools::percentile_filter::get_tool_description();
+            parameters = tools::percentile_filter::get_tool_parameters();
+            if tools::percentile_filter::get_example_usage().is_some() {
+                example = tools::percentile_filter::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "percentile_stretch" => {
+            description = tools::percentile_stretch::get_tool_description();
+            parameters = tools::percentile_stretch::get_tool_parameters();
+            if tools::percentile_stretch::get_example_usage().is_some() {
+                example = tools::percentile_stretch::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "raster_comparison" => {
+            description = tools::raster_comparison::get_tool_description();
+            parameters = tools::raster_comparison::get_tool_parameters();
+            if tools::raster_comparison::get_example_usage().is_some() {
+                example = tools::raster_comparison::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "raster_difference" => {
+            description = tools::raster_difference::get_tool_description();
+            parameters = tools::raster_difference::get_tool_parameters();
+            if tools::raster_difference::get_example_usage().is_some() {
+                example = tools::raster_difference::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "raster_streams_to_vector" => {
+            description = tools::raster_streams_to_vector::get_tool_description();
+            parameters = tools::raster_streams_to_vector::get_tool_parameters();
+            if tools::raster_streams_to_vector::get_example_usage().is_some() {
+                example = tools::raster_streams_to_vector::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "raster_to_points" => {
+            description = tools::raster_to_points::get_tool_description();
+            parameters = tools::raster_to_points::get_tool_parameters();
+            if tools::raster_to_points::get_example_usage().is_some() {
+                example = tools::raster_to_points::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
         "remove_off_terrain_objects" => {
             description = tools::remove_off_terrain_objects::get_tool_description();
             parameters = tools::remove_off_terrain_objects::get_tool_parameters();
@@ -88,6 +800,182 @@ impl ToolManager {
             }
             Ok(())
         },
+        "replace_nodata" => {
+            description = tools::replace_nodata::get_tool_description();
+            parameters = tools::replace_nodata::get_tool_parameters();
+            if tools::replace_nodata::get_example_usage().is_some() {
+                example = tools::replace_nodata::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "resample" => {
+            description = tools::resample::get_tool_description();
+            parameters = tools::resample::get_tool_parameters();
+            if tools::resample::get_example_usage().is_some() {
+                example = tools::resample::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "round_values" => {
+            description = tools::round_values::get_tool_description();
+            parameters = tools::round_values::get_tool_parameters();
+            if tools::round_values::get_example_usage().is_some() {
+                example = tools::round_values::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "ruggedness_index" => {
+            description = tools::ruggedness_index::get_tool_description();
+            parameters = tools::ruggedness_index::get_tool_parameters();
+            if tools::ruggedness_index::get_example_usage().is_some() {
+                example = tools::ruggedness_index::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "set_nodata_value" => {
+            description = tools::set_nodata_value::get_tool_description();
+            parameters = tools::set_nodata_value::get_tool_parameters();
+            if tools::set_nodata_value::get_example_usage().is_some() {
+                example = tools::set_nodata_value::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "snap_pour_points" => {
+            description = tools::snap_pour_points::get_tool_description();
+            parameters = tools::snap_pour_points::get_tool_parameters();
+            if tools::snap_pour_points::get_example_usage().is_some() {
+                example = tools::snap_pour_points::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "solar_radiation" => {
+            description = tools::solar_radiation::get_tool_description();
+            parameters = tools::solar_radiation::get_tool_parameters();
+            if tools::solar_radiation::get_example_usage().is_some() {
+                example = tools::solar_radiation::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "specific_catchment_area" => {
+            description = tools::specific_catchment_area::get_tool_description();
+            parameters = tools::specific_catchment_area::get_tool_parameters();
+            if tools::specific_catchment_area::get_example_usage().is_some() {
+                example = tools::specific_catchment_area::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "split_bands" => {
+            description = tools::split_bands::get_tool_description();
+            parameters = tools::split_bands::get_tool_parameters();
+            if tools::split_bands::get_example_usage().is_some() {
+                example = tools::split_bands::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "stack_bands" => {
+            description = tools::stack_bands::get_tool_description();
+            parameters = tools::stack_bands::get_tool_parameters();
+            if tools::stack_bands::get_example_usage().is_some() {
+                example = tools::stack_bands::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "stdev_filter" => {
+            description = tools::stdev_filter::get_tool_description();
+            parameters = tools::stdev_filter::get_tool_parameters();
+            if tools::stdev_filter::get_example_usage().is_some() {
+                example = tools::stdev_filter::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "stream_power_index" => {
+            description = tools::stream_power_index::get_tool_description();
+            parameters = tools::stream_power_index::get_tool_parameters();
+            if tools::stream_power_index::get_example_usage().is_some() {
+                example = tools::stream_power_index::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "surface_area_ratio" => {
+            description = tools::surface_area_ratio::get_tool_description();
+            parameters = tools::surface_area_ratio::get_tool_parameters();
+            if tools::surface_area_ratio::get_example_usage().is_some() {
+                example = tools::surface_area_ratio::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "terrain_fabric" => {
+            description = tools::terrain_fabric::get_tool_description();
+            parameters = tools::terrain_fabric::get_tool_parameters();
+            if tools::terrain_fabric::get_example_usage().is_some() {
+                example = tools::terrain_fabric::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "tophat_transform" => {
+            description = tools::tophat_transform::get_tool_description();
+            parameters = tools::tophat_transform::get_tool_parameters();
+            if tools::tophat_transform::get_example_usage().is_some() {
+                example = tools::tophat_transform::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "tpi" => {
+            description = tools::tpi::get_tool_description();
+            parameters = tools::tpi::get_tool_parameters();
+            if tools::tpi::get_example_usage().is_some() {
+                example = tools::tpi::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "trim_nodata" => {
+            description = tools::trim_nodata::get_tool_description();
+            parameters = tools::trim_nodata::get_tool_parameters();
+            if tools::trim_nodata::get_example_usage().is_some() {
+                example = tools::trim_nodata::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "upstream_average" => {
+            description = tools::upstream_average::get_tool_description();
+            parameters = tools::upstream_average::get_tool_parameters();
+            if tools::upstream_average::get_example_usage().is_some() {
+                example = tools::upstream_average::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "viewshed" => {
+            description = tools::viewshed::get_tool_description();
+            parameters = tools::viewshed::get_tool_parameters();
+            if tools::viewshed::get_example_usage().is_some() {
+                example = tools::viewshed::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "weighted_overlay" => {
+            description = tools::weighted_overlay::get_tool_description();
+            parameters = tools::weighted_overlay::get_tool_parameters();
+            if tools::weighted_overlay::get_example_usage().is_some() {
+                example = tools::weighted_overlay::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "wetness_index" => {
+            description = tools::wetness_index::get_tool_description();
+            parameters = tools::wetness_index::get_tool_parameters();
+            if tools::wetness_index::get_example_usage().is_some() {
+                example = tools::wetness_index::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
+        "wiener_filter" => {
+            description = tools::wiener_filter::get_tool_description();
+            parameters = tools::wiener_filter::get_tool_parameters();
+            if tools::wiener_filter::get_example_usage().is_some() {
+                example = tools::wiener_filter::get_example_usage().unwrap();
+            }
+            Ok(())
+        },
         _ => Err(Error::new(ErrorKind::NotFound, format!("Unrecognized tool name {}.", tool_name))),
         };
         if example.len() <= 1 {
@@ -116,6 +1004,78 @@ Example usage:
     pub fn list_tools(&self) {
         let mut tool_names = Vec::new();
         let mut tool_descriptions = Vec::new();
+        tool_names.push(tools::adaptive_smoothing::get_tool_name());
+        tool_descriptions.push(tools::adaptive_smoothing::get_tool_description());
+        tool_names.push(tools::affine_warp::get_tool_name());
+        tool_descriptions.push(tools::affine_warp::get_tool_description());
+        tool_names.push(tools::align_to::get_tool_name());
+        tool_descriptions.push(tools::align_to::get_tool_description());
+        tool_names.push(tools::apply_mask::get_tool_name());
+        tool_descriptions.push(tools::apply_mask::get_tool_description());
+        tool_names.push(tools::buffer::get_tool_name());
+        tool_descriptions.push(tools::buffer::get_tool_description());
+        tool_names.push(tools::burn_streams::get_tool_name());
+        tool_descriptions.push(tools::burn_streams::get_tool_description());
+        tool_names.push(tools::cast_shadows::get_tool_name());
+        tool_descriptions.push(tools::cast_shadows::get_tool_description());
+        tool_names.push(tools::clip::get_tool_name());
+        tool_descriptions.push(tools::clip::get_tool_description());
+        tool_names.push(tools::closing::get_tool_name());
+        tool_descriptions.push(tools::closing::get_tool_description());
+        tool_names.push(tools::clump::get_tool_name());
+        tool_descriptions.push(tools::clump::get_tool_description());
+        tool_names.push(tools::color_relief::get_tool_name());
+        tool_descriptions.push(tools::color_relief::get_tool_description());
+        tool_names.push(tools::conditional::get_tool_name());
+        tool_descriptions.push(tools::conditional::get_tool_description());
+        tool_names.push(tools::contours::get_tool_name());
+        tool_descriptions.push(tools::contours::get_tool_description());
+        tool_names.push(tools::convert_vertical_units::get_tool_name());
+        tool_descriptions.push(tools::convert_vertical_units::get_tool_description());
+        tool_names.push(tools::cost_distance::get_tool_name());
+        tool_descriptions.push(tools::cost_distance::get_tool_description());
+        tool_names.push(tools::d8_flow_accumulation::get_tool_name());
+        tool_descriptions.push(tools::d8_flow_accumulation::get_tool_description());
+        tool_names.push(tools::depression_stats::get_tool_name());
+        tool_descriptions.push(tools::depression_stats::get_tool_description());
+        tool_names.push(tools::detect_flats::get_tool_name());
+        tool_descriptions.push(tools::detect_flats::get_tool_description());
+        tool_names.push(tools::dev::get_tool_name());
+        tool_descriptions.push(tools::dev::get_tool_description());
+        tool_names.push(tools::dilation::get_tool_name());
+        tool_descriptions.push(tools::dilation::get_tool_description());
+        tool_names.push(tools::dinf_flow_accumulation::get_tool_name());
+        tool_descriptions.push(tools::dinf_flow_accumulation::get_tool_description());
+        tool_names.push(tools::dinf_pointer::get_tool_name());
+        tool_descriptions.push(tools::dinf_pointer::get_tool_description());
+        tool_names.push(tools::directional_slope::get_tool_name());
+        tool_descriptions.push(tools::directional_slope::get_tool_description());
+        tool_names.push(tools::downslope_index::get_tool_name());
+        tool_descriptions.push(tools::downslope_index::get_tool_description());
+        tool_names.push(tools::downslope_profile_curvature::get_tool_name());
+        tool_descriptions.push(tools::downslope_profile_curvature::get_tool_description());
+        tool_names.push(tools::elev_percentile::get_tool_name());
+        tool_descriptions.push(tools::elev_percentile::get_tool_description());
+        tool_names.push(tools::erosion::get_tool_name());
+        tool_descriptions.push(tools::erosion::get_tool_description());
+        tool_names.push(tools::euclidean_distance::get_tool_name());
+        tool_descriptions.push(tools::euclidean_distance::get_tool_description());
+        tool_names.push(tools::feature_preserving_smoothing::get_tool_name());
+        tool_descriptions.push(tools::feature_preserving_smoothing::get_tool_description());
+        tool_names.push(tools::fill_single_cell_pits::get_tool_name());
+        tool_descriptions.push(tools::fill_single_cell_pits::get_tool_description());
+        tool_names.push(tools::find_outlets::get_tool_name());
+        tool_descriptions.push(tools::find_outlets::get_tool_description());
+        tool_names.push(tools::histogram_match::get_tool_name());
+        tool_descriptions.push(tools::histogram_match::get_tool_description());
+        tool_names.push(tools::hypsometry::get_tool_name());
+        tool_descriptions.push(tools::hypsometry::get_tool_description());
+        tool_names.push(tools::kmeans::get_tool_name());
+        tool_descriptions.push(tools::kmeans::get_tool_description());
+        tool_names.push(tools::largest_clump::get_tool_name());
+        tool_descriptions.push(tools::largest_clump::get_tool_description());
+        tool_names.push(tools::least_cost_path::get_tool_name());
+        tool_descriptions.push(tools::least_cost_path::get_tool_description());
         tool_names.push(tools::lidar_elevation_slice::get_tool_name());
         tool_descriptions.push(tools::lidar_elevation_slice::get_tool_description());
         tool_names.push(tools::lidar_flightline_overlap::get_tool_name());
@@ -124,8 +1084,84 @@ Example usage:
         tool_descriptions.push(tools::lidar_info::get_tool_description());
         tool_names.push(tools::lidar_join::get_tool_name());
         tool_descriptions.push(tools::lidar_join::get_tool_description());
+        tool_names.push(tools::line_thinning::get_tool_name());
+        tool_descriptions.push(tools::line_thinning::get_tool_description());
+        tool_names.push(tools::local_binary_pattern::get_tool_name());
+        tool_descriptions.push(tools::local_binary_pattern::get_tool_description());
+        tool_names.push(tools::majority_filter::get_tool_name());
+        tool_descriptions.push(tools::majority_filter::get_tool_description());
+        tool_names.push(tools::mosaic::get_tool_name());
+        tool_descriptions.push(tools::mosaic::get_tool_description());
+        tool_names.push(tools::normalize::get_tool_name());
+        tool_descriptions.push(tools::normalize::get_tool_description());
+        tool_names.push(tools::num_inflowing_neighbors::get_tool_name());
+        tool_descriptions.push(tools::num_inflowing_neighbors::get_tool_description());
+        tool_names.push(tools::opening::get_tool_name());
+        tool_descriptions.push(tools::opening::get_tool_description());
+        tool_names.push(tools::openness::get_tool_name());
+        tool_descriptions.push(tools::openness::get_tool_description());
+        tool_names.push(tools::pad::get_tool_name());
+        tool_descriptions.push(tools::pad::get_tool_description());
+        tool_names.push(tools::patch_statistics::get_tool_name());
+        tool_descriptions.push(tools::patch_statistics::get_tool_description());
+        tool_names.push(tools::percentile_filter::get_tool_name());
+        tool_descriptions.push(tools::percentile_filter::get_tool_description());
+        tool_names.push(tools::percentile_stretch::get_tool_name());
+        tool_descriptions.push(tools::percentile_stretch::get_tool_description());
+        tool_names.push(tools::raster_comparison::get_tool_name());
+        tool_descriptions.push(tools::raster_comparison::get_tool_description());
+        tool_names.push(tools::raster_difference::get_tool_name());
+        tool_descriptions.push(tools::raster_difference::get_tool_description());
+        tool_names.push(tools::raster_streams_to_vector::get_tool_name());
+        tool_descriptions.push(tools::raster_streams_to_vector::get_tool_description());
+        tool_names.push(tools::raster_to_points::get_tool_name());
+        tool_descriptions.push(tools::raster_to_points::get_tool_description());
         tool_names.push(tools::remove_off_terrain_objects::get_tool_name());
         tool_descriptions.push(tools::remove_off_terrain_objects::get_tool_description());
+        tool_names.push(tools::replace_nodata::get_tool_name());
+        tool_descriptions.push(tools::replace_nodata::get_tool_description());
+        tool_names.push(tools::resample::get_tool_name());
+        tool_descriptions.push(tools::resample::get_tool_description());
+        tool_names.push(tools::round_values::get_tool_name());
+        tool_descriptions.push(tools::round_values::get_tool_description());
+        tool_names.push(tools::ruggedness_index::get_tool_name());
+        tool_descriptions.push(tools::ruggedness_index::get_tool_description());
+        tool_names.push(tools::set_nodata_value::get_tool_name());
+        tool_descriptions.push(tools::set_nodata_value::get_tool_description());
+        tool_names.push(tools::snap_pour_points::get_tool_name());
+        tool_descriptions.push(tools::snap_pour_points::get_tool_description());
+        tool_names.push(tools::solar_radiation::get_tool_name());
+        tool_descriptions.push(tools::solar_radiation::get_tool_description());
+        tool_names.push(tools::specific_catchment_area::get_tool_name());
+        tool_descriptions.push(tools::specific_catchment_area::get_tool_description());
+        tool_names.push(tools::split_bands::get_tool_name());
+        tool_descriptions.push(tools::split_bands::get_tool_description());
+        tool_names.push(tools::stack_bands::get_tool_name());
+        tool_descriptions.push(tools::stack_bands::get_tool_description());
+        tool_names.push(tools::stdev_filter::get_tool_name());
+        tool_descriptions.push(tools::stdev_filter::get_tool_description());
+        tool_names.push(tools::stream_power_index::get_tool_name());
+        tool_descriptions.push(tools::stream_power_index::get_tool_description());
+        tool_names.push(tools::surface_area_ratio::get_tool_name());
+        tool_descriptions.push(tools::surface_area_ratio::get_tool_description());
+        tool_names.push(tools::terrain_fabric::get_tool_name());
+        tool_descriptions.push(tools::terrain_fabric::get_tool_description());
+        tool_names.push(tools::tophat_transform::get_tool_name());
+        tool_descriptions.push(tools::tophat_transform::get_tool_description());
+        tool_names.push(tools::tpi::get_tool_name());
+        tool_descriptions.push(tools::tpi::get_tool_description());
+        tool_names.push(tools::trim_nodata::get_tool_name());
+        tool_descriptions.push(tools::trim_nodata::get_tool_description());
+        tool_names.push(tools::upstream_average::get_tool_name());
+        tool_descriptions.push(tools::upstream_average::get_tool_description());
+        tool_names.push(tools::viewshed::get_tool_name());
+        tool_descriptions.push(tools::viewshed::get_tool_description());
+        tool_names.push(tools::weighted_overlay::get_tool_name());
+        tool_descriptions.push(tools::weighted_overlay::get_tool_description());
+        tool_names.push(tools::wetness_index::get_tool_name());
+        tool_descriptions.push(tools::wetness_index::get_tool_description());
+        tool_names.push(tools::wiener_filter::get_tool_name());
+        tool_descriptions.push(tools::wiener_filter::get_tool_description());
 
         let mut ret = format!("All {} Available Tools:\n", tool_names.len());
         for i in 0..tool_names.len() {
@@ -0,0 +1,175 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "mosaic".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Combines adjacent or overlapping rasters with a common cell size into a single raster.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --inputs       Input raster files, separated by semicolons or commas.
+-o, --output       Output raster file.
+--keep_last        Optional flag indicating that the last, rather than first, non-nodata value is kept where inputs overlap.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=mosaic --wd=\"/dir/to/data\" --args=\"-i=\"tile1.dep;tile2.dep;tile3.dep\" -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_files = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keep_last = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--inputs" {
+            if keyval {
+                input_files = vec[1].to_string();
+            } else {
+                input_files = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-keep_last" || vec[0].to_lowercase() == "--keep_last" {
+            keep_last = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*********************");
+        println!("* Welcome to mosaic *");
+        println!("*********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut cmd = input_files.split(";");
+    let mut vec = cmd.collect::<Vec<&str>>();
+    if vec.len() == 1 {
+        cmd = input_files.split(",");
+        vec = cmd.collect::<Vec<&str>>();
+    }
+    let mut inputs: Vec<Raster> = vec![];
+    for value in vec {
+        if !value.trim().is_empty() {
+            let mut input_file = value.trim().to_owned();
+            if !input_file.contains(&sep) {
+                input_file = format!("{}{}", working_directory, input_file);
+            }
+            inputs.push(Raster::new(&input_file, "r")?);
+        }
+    }
+
+    let start = time::now();
+
+    if verbose { println!("Mosaicking...") };
+    let mut output = mosaic(&inputs, keep_last)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' mosaic tool".to_owned());
+    output.add_metadata_entry(format!("Input files: {}", input_files));
+    output.add_metadata_entry(format!("Keep last overlapping value: {}", keep_last));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Combines adjacent or overlapping rasters into a single raster covering the union of their
+/// extents. All inputs must share the same cell size. Where inputs overlap, the first
+/// non-nodata value is kept, or the last if `keep_last` is true.
+pub fn mosaic(inputs: &[Raster], keep_last: bool) -> Result<Raster, Error> {
+    if inputs.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "At least one input raster is required."));
+    }
+    let resolution_x = inputs[0].configs.resolution_x;
+    let resolution_y = inputs[0].configs.resolution_y;
+    let epsg_code = inputs[0].configs.epsg_code;
+    let (mut north, mut south, mut east, mut west) = (f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY);
+    for input in inputs {
+        if (input.configs.resolution_x - resolution_x).abs() > 0.001 * resolution_x ||
+            (input.configs.resolution_y - resolution_y).abs() > 0.001 * resolution_y {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("The cell size of {} does not match that of {}.", input.file_name, inputs[0].file_name)));
+        }
+        if input.configs.epsg_code != 0 && epsg_code != 0 && input.configs.epsg_code != epsg_code {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("The coordinate reference system of {} does not match that of {}.", input.file_name, inputs[0].file_name)));
+        }
+        if input.configs.north > north { north = input.configs.north; }
+        if input.configs.south < south { south = input.configs.south; }
+        if input.configs.east > east { east = input.configs.east; }
+        if input.configs.west < west { west = input.configs.west; }
+    }
+
+    let rows = ((north - south) / resolution_y).round() as usize;
+    let columns = ((east - west) / resolution_x).round() as usize;
+    let mut output = Raster::initialize_using_extent(&inputs[0].file_name, &inputs[0], rows, columns,
+        north, west, resolution_x, resolution_y);
+
+    let mut z: f64;
+    for input in inputs {
+        let row_offset = ((north - input.configs.north) / resolution_y).round() as isize;
+        let col_offset = ((input.configs.west - west) / resolution_x).round() as isize;
+        for row in 0..input.configs.rows as isize {
+            for col in 0..input.configs.columns as isize {
+                z = input.get_value(row, col);
+                if !input.is_nodata(z) && (keep_last || output.is_nodata(output.get_value(row + row_offset, col + col_offset))) {
+                    output.set_value(row + row_offset, col + col_offset, z);
+                }
+            }
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,172 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "normalize".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Rescales a raster to the range 0-1 (min-max) or to zero mean and unit variance (z-score).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--method           Normalization method, 'minmax' or 'zscore'; default is minmax.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=normalize --wd=\"/dir/to/data\" --args=\"-i=slope.dep -o=output.dep --method=zscore\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut method = NormMethod::MinMax;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-method" || vec[0].to_lowercase() == "--method" {
+            if keyval {
+                method = NormMethod::from_str(vec[1]);
+            } else {
+                method = NormMethod::from_str(&args[i+1]);
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("************************");
+        println!("* Welcome to normalize *");
+        println!("************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Normalizing...") };
+    let mut output = normalize(&input, method);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' normalize tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Method: {:?}", method));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NormMethod {
+    MinMax,
+    ZScore,
+}
+
+impl Default for NormMethod {
+    fn default() -> NormMethod { NormMethod::MinMax }
+}
+
+impl NormMethod {
+    pub fn from_str<'a>(val: &'a str) -> NormMethod {
+        let val_lc: &str = &val.to_lowercase();
+        if val_lc.contains("z") || val_lc.contains("std") {
+            return NormMethod::ZScore;
+        }
+        NormMethod::MinMax
+    }
+}
+
+/// Rescales the valid cells either to the range [0, 1] (MinMax) or to zero mean and unit
+/// (population) standard deviation (ZScore). A constant raster maps to zero under both methods.
+pub fn normalize(input: &Raster, method: NormMethod) -> Raster {
+    let (offset, scale) = match method {
+        NormMethod::MinMax => {
+            let (min, max) = input.min_max();
+            (min, max - min)
+        },
+        NormMethod::ZScore => {
+            let (mut n, mut sum, mut sum_sqr) = (0f64, 0f64, 0f64);
+            let first = input.iter_valid().next().map(|(_, _, z)| z).unwrap_or(0f64);
+            // Offsetting by the first value keeps the sums well conditioned.
+            for (_, _, z) in input.iter_valid() {
+                n += 1f64;
+                sum += z - first;
+                sum_sqr += (z - first) * (z - first);
+            }
+            let mean = if n > 0f64 { sum / n } else { 0f64 };
+            let variance = if n > 0f64 { (sum_sqr / n - mean * mean).max(0f64) } else { 0f64 };
+            (first + mean, variance.sqrt())
+        },
+    };
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for (row, col, z) in input.iter_valid() {
+        output.set_value(row, col, if scale > 0f64 { (z - offset) / scale } else { 0f64 });
+    }
+
+    output
+}
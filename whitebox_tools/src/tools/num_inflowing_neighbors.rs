@@ -0,0 +1,140 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::d8_flow_accumulation::d8_receiver;
+
+pub fn get_tool_name() -> String {
+    return "num_inflowing_neighbors".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Counts the neighbours that drain into each cell according to a D8 flow pointer, e.g. to locate headwaters (0) and confluences (2+).";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input D8 flow pointer raster file.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=num_inflowing_neighbors --wd=\"/dir/to/data\" --args=\"-i=pointer.dep -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("**************************************");
+        println!("* Welcome to num_inflowing_neighbors *");
+        println!("**************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Counting inflowing neighbours...") };
+    let mut output = num_inflowing_neighbors(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' num_inflowing_neighbors tool".to_owned());
+    output.add_metadata_entry(format!("Pointer file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Counts, for each cell, the number of its eight neighbours that drain into it according to a
+/// D8 flow pointer raster (encoded as for `d8_flow_accumulation`). Cells with no inflowing
+/// neighbours are headwater or ridge cells, while cells with two or more are confluences.
+/// Nodata pointer cells are nodata in the output.
+pub fn num_inflowing_neighbors(pointer: &Raster) -> Raster {
+    let rows = pointer.configs.rows as isize;
+    let columns = pointer.configs.columns as isize;
+    let mut output = Raster::initialize_using_file(&pointer.file_name, pointer);
+    output.set_data_type(DataType::I16);
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for row in 0..rows {
+        for col in 0..columns {
+            if !pointer.is_nodata(pointer[(row, col)]) {
+                output.set_value(row, col, 0f64);
+            }
+        }
+    }
+    for row in 0..rows {
+        for col in 0..columns {
+            if let Some((row_n, col_n)) = d8_receiver(pointer, row, col) {
+                let n = output[(row_n, col_n)];
+                output.set_value(row_n, col_n, n + 1f64);
+            }
+        }
+    }
+
+    output
+}
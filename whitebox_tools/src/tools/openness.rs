@@ -0,0 +1,260 @@
+extern crate time;
+
+extern crate num_cpus;
+extern crate rayon;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "openness".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates positive and negative topographic openness from a DEM.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+--pos_output       Output positive openness raster file.
+--neg_output       Output negative openness raster file.
+--dist             Search distance (cells); default is 20.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=openness --wd=\"/dir/to/data\" --args=\"-i=DEM.dep --pos_output=pos_open.dep --neg_output=neg_open.dep --dist=50\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut pos_output_file = String::new();
+    let mut neg_output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut max_dist = 20f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-pos_output" || vec[0].to_lowercase() == "--pos_output" {
+            if keyval {
+                pos_output_file = vec[1].to_string();
+            } else {
+                pos_output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-neg_output" || vec[0].to_lowercase() == "--neg_output" {
+            if keyval {
+                neg_output_file = vec[1].to_string();
+            } else {
+                neg_output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-dist" || vec[0].to_lowercase() == "--dist" {
+            if keyval {
+                max_dist = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                max_dist = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if max_dist < 1f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --dist parameter must be at least one cell."));
+    }
+
+    if verbose {
+        println!("***********************");
+        println!("* Welcome to openness *");
+        println!("***********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !pos_output_file.contains(&sep) {
+        pos_output_file = format!("{}{}", working_directory, pos_output_file);
+    }
+    if !neg_output_file.contains(&sep) {
+        neg_output_file = format!("{}{}", working_directory, neg_output_file);
+    }
+    check_output_file(&pos_output_file, overwrite)?;
+    check_output_file(&neg_output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating openness...") };
+    let (mut pos_output, mut neg_output) = openness(&input, max_dist);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    pos_output.set_file_name(&pos_output_file);
+    neg_output.set_file_name(&neg_output_file);
+    for output in [&mut pos_output, &mut neg_output].iter_mut() {
+        output.add_metadata_entry("Created by whitebox_tools\' openness tool".to_owned());
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Search distance: {}", max_dist));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        pos_output.configs.compression = compression;
+    }
+    let _ = match pos_output.write() {
+        Ok(_) => if verbose { println!("Positive openness file written") },
+        Err(e) => return Err(e),
+    };
+    if let Some(compression) = compression {
+        neg_output.configs.compression = compression;
+    }
+    let _ = match neg_output.write() {
+        Ok(_) => if verbose { println!("Negative openness file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates positive and negative topographic openness (Yokoyama et al., 2002), in degrees.
+/// Each of the 8 directions is traced out to `max_dist` cells; positive openness averages
+/// 90 degrees minus the maximum elevation angle found along each direction, negative openness
+/// averages 90 degrees plus the minimum. Rows are split among threads when more than one CPU is
+/// available.
+pub fn openness(input: &Raster, max_dist: f64) -> (Raster, Raster) {
+    let rows = input.configs.rows;
+    let columns = input.configs.columns;
+    let mut pos = vec![0f64; rows * columns];
+    let mut neg = vec![0f64; rows * columns];
+    let min_rows = if num_cpus::get() > 1 { 16 } else { rows };
+    openness_rows(input, max_dist, 0, &mut pos[..], &mut neg[..], min_rows);
+
+    let mut pos_output = Raster::initialize_using_file(&input.file_name, input);
+    let mut neg_output = Raster::initialize_using_file(&input.file_name, input);
+    neg_output.configs.data_type = DataType::F32;
+    neg_output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    pos_output.configs.data_type = DataType::F32;
+    pos_output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let out_nodata = pos_output.configs.nodata;
+    let mut idx: usize;
+    for row in 0..rows {
+        for col in 0..columns {
+            idx = row * columns + col;
+            if !input.is_nodata(pos[idx]) {
+                pos_output.set_value(row as isize, col as isize, pos[idx]);
+                neg_output.set_value(row as isize, col as isize, neg[idx]);
+            } else {
+                pos_output.set_value(row as isize, col as isize, out_nodata);
+                neg_output.set_value(row as isize, col as isize, out_nodata);
+            }
+        }
+    }
+
+    (pos_output, neg_output)
+}
+
+/// Fills the openness values for the rows starting at `start_row` that the slices cover,
+/// splitting the rows in half with rayon::join until there are fewer than `min_rows` of them.
+fn openness_rows(input: &Raster, max_dist: f64, start_row: usize, pos: &mut [f64], neg: &mut [f64], min_rows: usize) {
+    let columns = input.configs.columns;
+    let num_rows = pos.len() / columns;
+    if num_rows > min_rows {
+        let mid = num_rows / 2;
+        let (pos_top, pos_bottom) = pos.split_at_mut(mid * columns);
+        let (neg_top, neg_bottom) = neg.split_at_mut(mid * columns);
+        rayon::join(|| openness_rows(input, max_dist, start_row, pos_top, neg_top, min_rows),
+                    || openness_rows(input, max_dist, start_row + mid, pos_bottom, neg_bottom, min_rows));
+        return;
+    }
+
+    let nodata = input.configs.nodata;
+    let res_x = input.configs.resolution_x;
+    let res_y = input.configs.resolution_y;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+    let mut step_len = [0f64; 8];
+    let mut num_steps = [0isize; 8];
+    for i in 0..8 {
+        step_len[i] = ((d_x[i] as f64 * res_x).powi(2) + (d_y[i] as f64 * res_y).powi(2)).sqrt();
+        num_steps[i] = (max_dist / ((d_x[i] * d_x[i] + d_y[i] * d_y[i]) as f64).sqrt()).floor() as isize;
+    }
+    let (mut z, mut z_n, mut angle): (f64, f64, f64);
+    let (mut max_angle, mut min_angle): (f64, f64);
+    let (mut sum_pos, mut sum_neg, mut num_dirs): (f64, f64, f64);
+    let (mut row_n, mut col_n): (isize, isize);
+    for r in 0..num_rows {
+        let row = (start_row + r) as isize;
+        for col in 0..columns as isize {
+            z = input[(row, col)];
+            if input.is_nodata(z) {
+                pos[r * columns + col as usize] = nodata;
+                neg[r * columns + col as usize] = nodata;
+                continue;
+            }
+            sum_pos = 0f64;
+            sum_neg = 0f64;
+            num_dirs = 0f64;
+            for i in 0..8 {
+                max_angle = f64::NEG_INFINITY;
+                min_angle = f64::INFINITY;
+                for d in 1..num_steps[i] + 1 {
+                    row_n = row + d * d_y[i];
+                    col_n = col + d * d_x[i];
+                    if row_n < 0 || col_n < 0 || row_n >= input.configs.rows as isize || col_n >= columns as isize {
+                        break;
+                    }
+                    z_n = input[(row_n, col_n)];
+                    if !input.is_nodata(z_n) {
+                        angle = ((z_n - z) / (d as f64 * step_len[i])).atan();
+                        if angle > max_angle { max_angle = angle; }
+                        if angle < min_angle { min_angle = angle; }
+                    }
+                }
+                if max_angle != f64::NEG_INFINITY {
+                    sum_pos += 90f64 - max_angle.to_degrees();
+                    sum_neg += 90f64 + min_angle.to_degrees();
+                    num_dirs += 1f64;
+                }
+            }
+            if num_dirs > 0f64 {
+                pos[r * columns + col as usize] = sum_pos / num_dirs;
+                neg[r * columns + col as usize] = sum_neg / num_dirs;
+            } else {
+                pos[r * columns + col as usize] = nodata;
+                neg[r * columns + col as usize] = nodata;
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "pad".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Expands a raster by a border of the given width on all sides, filled with nodata or a constant value.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--width            Width of the border, in cells.
+--fill             Optional value assigned to the border cells; default is nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=pad --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --width=10\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut width = 0usize;
+    let mut fill: Option<f64> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-width" || vec[0].to_lowercase() == "--width" {
+            if keyval {
+                width = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                width = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-fill" || vec[0].to_lowercase() == "--fill" {
+            if keyval {
+                fill = Some(vec[1].to_string().parse::<f64>().unwrap());
+            } else {
+                fill = Some(args[i+1].to_string().parse::<f64>().unwrap());
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("******************");
+        println!("* Welcome to pad *");
+        println!("******************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Padding raster...") };
+    let mut output = pad(&input, width, fill.unwrap_or(input.configs.nodata));
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' pad tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Border width: {}", width));
+    if let Some(f) = fill {
+        output.add_metadata_entry(format!("Fill value: {}", f));
+    }
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Expands the raster by `width` rows and columns on every side, shifting the origin so that
+/// the original cells keep their georeferencing. Border cells are set to `fill`; pass the
+/// input's nodata value for a nodata border. This is the inverse of `trim_nodata` for a nodata
+/// border around an input whose edge rows and columns contain valid data.
+pub fn pad(input: &Raster, width: usize, fill: f64) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let w = width as isize;
+    let mut output = Raster::initialize_using_extent(&input.file_name, input,
+        input.configs.rows + 2 * width, input.configs.columns + 2 * width,
+        input.configs.north + width as f64 * input.configs.resolution_y,
+        input.configs.west - width as f64 * input.configs.resolution_x,
+        input.configs.resolution_x, input.configs.resolution_y);
+    let out_nodata = output.configs.nodata;
+    let fill = if input.is_nodata(fill) { out_nodata } else { fill };
+    let mut z: f64;
+    for row in 0..rows + 2 * w {
+        for col in 0..columns + 2 * w {
+            if row < w || col < w || row >= rows + w || col >= columns + w {
+                output.set_value(row, col, fill);
+            } else {
+                z = input[(row - w, col - w)];
+                output.set_value(row, col, if input.is_nodata(z) { out_nodata } else { z });
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,146 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::io::BufWriter;
+use std::io::prelude::*;
+use std::fs::File;
+use std::path;
+use std::collections::HashMap;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "patch_statistics".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Reports the area, perimeter and perimeter-area ratio of each patch in a clumped raster as a CSV file.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input clumped (patch id) raster file.
+-o, --output       Output CSV file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=patch_statistics --wd=\"/dir/to/data\" --args=\"-i=patches.dep -o=patch_stats.csv\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to patch_statistics *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating patch statistics...") };
+    let stats = patch_statistics(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let mut ids: Vec<&i32> = stats.keys().collect();
+    ids.sort();
+    let f = File::create(&output_file)?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all("ID,CELLS,AREA,PERIMETER,PERIM_AREA_RATIO\n".as_bytes())?;
+    for id in ids {
+        let s = &stats[id];
+        writer.write_all(format!("{},{},{},{},{}\n", id, s.num_cells, s.area, s.perimeter, s.shape_index).as_bytes())?;
+    }
+    let _ = writer.flush();
+    if verbose {
+        println!("Output file written ({} patches)", stats.len());
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct PatchStats {
+    pub num_cells: usize,
+    pub area: f64,
+    pub perimeter: f64,
+    /// perimeter / area
+    pub shape_index: f64,
+}
+
+/// Calculates the area and perimeter of each patch of a clumped (patch id) raster, in map
+/// units. The perimeter counts every cell edge that borders a different patch, nodata or the
+/// edge of the grid.
+pub fn patch_statistics(clumped: &Raster) -> HashMap<i32, PatchStats> {
+    let res_x = clumped.configs.resolution_x;
+    let res_y = clumped.configs.resolution_y;
+    let d_x = [ 1, 0, -1, 0 ];
+    let d_y = [ 0, 1, 0, -1 ];
+    // Edges shared with east/west neighbours are res_y long, those with north/south ones res_x.
+    let edge_length = [ res_y, res_x, res_y, res_x ];
+    let mut stats: HashMap<i32, PatchStats> = HashMap::new();
+    for (row, col, z) in clumped.iter_valid() {
+        let entry = stats.entry(z.round() as i32).or_insert(PatchStats::default());
+        entry.num_cells += 1;
+        for n in 0..4 {
+            if clumped[(row + d_y[n], col + d_x[n])] != z {
+                entry.perimeter += edge_length[n];
+            }
+        }
+    }
+    for entry in stats.values_mut() {
+        entry.area = entry.num_cells as f64 * res_x * res_y;
+        entry.shape_index = entry.perimeter / entry.area;
+    }
+
+    stats
+}
@@ -0,0 +1,288 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "percentile_filter".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Performs a percentile (quantile) filter on a raster, e.g. the 10th or 90th percentile of the values within a moving window.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 11.
+--percentile       Percentile (0-100) of the neighbourhood values to output; default is 50 (median).
+--bins             Optional number of histogram bins; if specified, a fast approximate filter, accurate to the bin width, is used for large filters.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=percentile_filter --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --filter=25 --percentile=90.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 11usize;
+    let mut percentile = 50f64;
+    let mut num_bins = 0usize;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-percentile" || vec[0].to_lowercase() == "--percentile" {
+            if keyval {
+                percentile = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                percentile = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-bins" || vec[0].to_lowercase() == "--bins" {
+            if keyval {
+                num_bins = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                num_bins = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if percentile < 0f64 || percentile > 100f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The percentile must be between 0 and 100."));
+    }
+
+    if verbose {
+        println!("********************************");
+        println!("* Welcome to percentile_filter *");
+        println!("********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Performing percentile filter...") };
+    let mut output = if num_bins > 0 {
+        approx_percentile_filter(&input, filter_size, percentile, num_bins)
+    } else {
+        percentile_filter(&input, filter_size, percentile)
+    };
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' percentile_filter tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Percentile: {}", percentile));
+    if num_bins > 0 {
+        output.add_metadata_entry(format!("Histogram bins: {}", num_bins));
+    }
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Replaces each grid cell with the given percentile (0-100) of the non-nodata values within
+/// the filter window, linearly interpolating between the two nearest ranks. A percentile of 50
+/// gives the median filter.
+pub fn percentile_filter(input: &Raster, filter_size: usize, percentile: f64) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let mut z_n: f64;
+    let mut filter_vals: Vec<f64> = Vec::with_capacity(filter_size * filter_size);
+    for row in 0..rows {
+        for col in 0..columns {
+            if !input.is_nodata(input.get_value(row, col)) {
+                filter_vals.clear();
+                for row2 in (row - midpoint)..(row + midpoint + 1) {
+                    for col2 in (col - midpoint)..(col + midpoint + 1) {
+                        z_n = input.get_value(row2, col2);
+                        if !input.is_nodata(z_n) { filter_vals.push(z_n); }
+                    }
+                }
+                filter_vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                output.set_value(row, col, interpolate_percentile(&filter_vals, percentile));
+            }
+        }
+    }
+
+    output
+}
+
+/// An approximate `percentile_filter` for large filters. The raster's value range is divided
+/// into `num_bins` equal bins and each row is swept with a histogram of the window's bins,
+/// which is updated as the window slides by adding the entering column and removing the
+/// leaving one (Huang et al., 1979). The cost per cell therefore grows with the filter width
+/// rather than its area and no sorting is needed. The percentile is interpolated within its
+/// bin, so the results are accurate to about the bin width, (max - min) / `num_bins`.
+pub fn approx_percentile_filter(input: &Raster, filter_size: usize, percentile: f64, num_bins: usize) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let num_bins = num_bins.max(1);
+    let (min, max) = input.min_max();
+    if min > max {
+        return output; // no valid data
+    }
+    let bin_width = if max > min { (max - min) / num_bins as f64 } else { 1f64 };
+
+    // The bin of each cell, with num_bins marking nodata.
+    let bins: Vec<usize> = input.as_slice().iter().map(|&z| {
+        if input.is_nodata(z) { num_bins } else { (((z - min) / bin_width).floor() as usize).min(num_bins - 1) }
+    }).collect();
+    let bin_at = |row: isize, col: isize| -> usize {
+        if row < 0 || col < 0 || row >= rows || col >= columns { num_bins } else { bins[(row * columns + col) as usize] }
+    };
+
+    let mut counts = vec![0usize; num_bins + 1];
+    let mut b: usize;
+    for row in 0..rows {
+        for c in counts.iter_mut() {
+            *c = 0;
+        }
+        for row2 in (row - midpoint)..(row + midpoint + 1) {
+            for col2 in (-midpoint - 1)..midpoint {
+                counts[bin_at(row2, col2)] += 1;
+            }
+        }
+        for col in 0..columns {
+            // Slide the window one column to the east.
+            for row2 in (row - midpoint)..(row + midpoint + 1) {
+                b = bin_at(row2, col + midpoint);
+                counts[b] += 1;
+                b = bin_at(row2, col - midpoint - 1);
+                counts[b] -= 1;
+            }
+            if !input.is_nodata(input[(row, col)]) {
+                let z = histogram_rank_value(&counts[0..num_bins], percentile);
+                output.set_value(row, col, (min + z * bin_width).max(min).min(max));
+            }
+        }
+    }
+
+    output
+}
+
+/// A median filter is simply the 50th percentile filter.
+pub fn median_filter(input: &Raster, filter_size: usize) -> Raster {
+    percentile_filter(input, filter_size, 50f64)
+}
+
+// Returns the position, in bins, of the percentile of the values counted in a histogram,
+// interpolating between the two nearest ranks like interpolate_percentile, with each bin's
+// values spread evenly across it.
+fn histogram_rank_value(counts: &[usize], percentile: f64) -> f64 {
+    let n: usize = counts.iter().sum();
+    if n == 0 {
+        return 0f64;
+    }
+    let rank = percentile / 100f64 * (n - 1) as f64;
+    let position = |k: usize| -> f64 {
+        let mut cumulative = 0usize;
+        for (bin, &count) in counts.iter().enumerate() {
+            if cumulative + count > k {
+                return bin as f64 + ((k - cumulative) as f64 + 0.5) / count as f64;
+            }
+            cumulative += count;
+        }
+        counts.len() as f64
+    };
+    let lower = position(rank.floor() as usize);
+    let upper = position(rank.ceil() as usize);
+    lower + (rank - rank.floor()) * (upper - lower)
+}
+
+// Returns the percentile of a sorted, non-empty slice of values.
+fn interpolate_percentile(sorted_vals: &[f64], percentile: f64) -> f64 {
+    let rank = percentile / 100f64 * (sorted_vals.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted_vals[lower] + (rank - lower as f64) * (sorted_vals[upper] - sorted_vals[lower])
+}
@@ -0,0 +1,168 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "percentile_stretch".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Performs a linear contrast stretch to the range 0-255 between two percentiles of the data.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--low_pct          Lower percentile clip point; default is 2.0.
+--high_pct         Upper percentile clip point; default is 98.0.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=percentile_stretch --wd=\"/dir/to/data\" --args=\"-i=hillshade.dep -o=output.dep --low_pct=1.0 --high_pct=99.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut low_pct = 2f64;
+    let mut high_pct = 98f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-low_pct" || vec[0].to_lowercase() == "--low_pct" {
+            if keyval {
+                low_pct = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                low_pct = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-high_pct" || vec[0].to_lowercase() == "--high_pct" {
+            if keyval {
+                high_pct = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                high_pct = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if low_pct < 0f64 || high_pct > 100f64 || low_pct >= high_pct {
+        return Err(Error::new(ErrorKind::InvalidInput, "The percentiles must satisfy 0 <= --low_pct < --high_pct <= 100."));
+    }
+
+    if verbose {
+        println!("*********************************");
+        println!("* Welcome to percentile_stretch *");
+        println!("*********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Stretching...") };
+    let mut output = percentile_stretch(&input, low_pct, high_pct);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' percentile_stretch tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Low percentile: {}", low_pct));
+    output.add_metadata_entry(format!("High percentile: {}", high_pct));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Linearly maps the values between the `low_pct` and `high_pct` percentiles to the range
+/// 0-255, clamping values outside of that range. The clip points are estimated from a
+/// 10,000-bin histogram of the data.
+pub fn percentile_stretch(input: &Raster, low_pct: f64, high_pct: f64) -> Raster {
+    let (min, bin_width, counts) = input.histogram(10000);
+    let low = histogram_percentile(min, bin_width, &counts, low_pct);
+    let high = histogram_percentile(min, bin_width, &counts, high_pct);
+    let range = high - low;
+
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    let mut z_n: f64;
+    for (row, col, z) in input.iter_valid() {
+        z_n = if range > 0f64 { (z - low) / range * 255f64 } else { 0f64 };
+        if z_n < 0f64 { z_n = 0f64; }
+        if z_n > 255f64 { z_n = 255f64; }
+        output.set_value(row, col, z_n.round());
+    }
+
+    output
+}
+
+/// Estimates the value at percentile `pct` (0-100), interpolating within the bin that contains
+/// it.
+pub fn histogram_percentile(min: f64, bin_width: f64, counts: &[usize], pct: f64) -> f64 {
+    let n: usize = counts.iter().sum();
+    let target = pct / 100f64 * n as f64;
+    let mut cumulative = 0f64;
+    for (bin, &count) in counts.iter().enumerate() {
+        if count > 0 && cumulative + count as f64 >= target {
+            return min + (bin as f64 + (target - cumulative) / count as f64) * bin_width;
+        }
+        cumulative += count as f64;
+    }
+    min + counts.len() as f64 * bin_width
+}
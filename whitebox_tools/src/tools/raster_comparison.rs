@@ -0,0 +1,299 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::fs::File;
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+/// The maximum number of points drawn in the report's scatter plot.
+const MAX_SCATTER_POINTS: usize = 5000;
+
+pub fn get_tool_name() -> String {
+    return "raster_comparison".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Compares two aligned rasters, reporting their correlation, RMSE and mean bias with a scatter plot in an HTML file.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--input1          First input raster file, e.g. the DEM being assessed.
+--input2          Second input raster file, e.g. the reference DEM.
+-o, --output      Output HTML file.
+--overwrite       Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=raster_comparison --wd=\"/dir/to/data\" --args=\"--input1=DEM.dep --input2=reference.dep -o=comparison.html\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input1_file = String::new();
+    let mut input2_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-input1" || vec[0].to_lowercase() == "--input1" {
+            if keyval {
+                input1_file = vec[1].to_string();
+            } else {
+                input1_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-input2" || vec[0].to_lowercase() == "--input2" {
+            if keyval {
+                input2_file = vec[1].to_string();
+            } else {
+                input2_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("********************************");
+        println!("* Welcome to raster_comparison *");
+        println!("********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input1_file.contains(&sep) {
+        input1_file = format!("{}{}", working_directory, input1_file);
+    }
+    if !input2_file.contains(&sep) {
+        input2_file = format!("{}{}", working_directory, input2_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    if !output_file.ends_with(".html") {
+        output_file = output_file + ".html";
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input1 = Raster::new(&input1_file, "r")?;
+    let input2 = Raster::new(&input2_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Comparing rasters...") };
+    let stats = raster_comparison(&input1, &input2)?;
+    if stats.n == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The input rasters do not have any valid cells in common."));
+    }
+    let points = scatter_sample(&input1, &input2, stats.n, MAX_SCATTER_POINTS);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let f = File::create(output_file.clone())?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all(&format!("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+    <head>
+        <meta content=\"text/html; charset=iso-8859-1\" http-equiv=\"content-type\">
+        <title>Raster Comparison</title>
+        <style  type=\"text/css\">
+            h1 {{
+                font-size: 14pt;
+                margin-left: 15px;
+                margin-right: 15px;
+                text-align: center;
+                font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+            }}
+            p, caption {{
+                font-size: 12pt;
+                font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                margin-left: 15px;
+                margin-right: 15px;
+            }}
+            table {{
+                font-size: 12pt;
+                font-family: Helvetica, Verdana, Geneva, Arial, sans-serif;
+                border-collapse: collapse;
+                align: center;
+            }}
+            td, th {{
+                border: 1px solid #222222;
+                text-align: left;
+                padding: 8px;
+            }}
+            .numberCell {{
+                text-align: right;
+            }}
+        </style>
+    </head>
+    <body>
+        <h1>Raster Comparison</h1>
+        <p><strong>Input 1</strong>: {}<br><strong>Input 2</strong>: {}</p>
+        <table align=\"center\">
+            <tr><th>Statistic</th><th>Value</th></tr>
+            <tr><td>Paired cells (n)</td><td class=\"numberCell\">{}</td></tr>
+            <tr><td>Pearson correlation (r)</td><td class=\"numberCell\">{:.4}</td></tr>
+            <tr><td>RMSE</td><td class=\"numberCell\">{:.4}</td></tr>
+            <tr><td>Mean bias (input 1 - input 2)</td><td class=\"numberCell\">{:.4}</td></tr>
+        </table>
+", input1_file, input2_file, stats.n, stats.pearson_r, stats.rmse, stats.mean_bias).as_bytes())?;
+    writer.write_all(scatter_plot_svg(&points, points.len() < stats.n).as_bytes())?;
+    writer.write_all("    </body>\n</html>\n".as_bytes())?;
+    let _ = writer.flush();
+
+    if verbose {
+        println!("Output file written");
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Summary statistics of the agreement between two rasters over their paired valid cells.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct ComparisonStats {
+    /// The number of cells that are valid in both rasters.
+    pub n: usize,
+    pub pearson_r: f64,
+    /// Root-mean-square of the differences, a - b.
+    pub rmse: f64,
+    /// Mean of the differences, a - b.
+    pub mean_bias: f64,
+}
+
+/// Calculates the Pearson correlation, RMSE and mean bias (a - b) of two aligned rasters (see
+/// `check_alignment`) over the cells that are valid in both. The statistics are NaN if there
+/// are no such cells, and the correlation is NaN if either raster is constant over them.
+pub fn raster_comparison(a: &Raster, b: &Raster) -> Result<ComparisonStats, Error> {
+    check_alignment(a, b)?;
+
+    // Accumulate about the first pair to limit the round-off of the sums of squares.
+    let mut shift: Option<(f64, f64)> = None;
+    let (mut sum_a, mut sum_b, mut sum_aa, mut sum_bb, mut sum_ab) = (0f64, 0f64, 0f64, 0f64, 0f64);
+    let mut n = 0usize;
+    let (mut z_a, mut z_b): (f64, f64);
+    for row in 0..a.configs.rows as isize {
+        for col in 0..a.configs.columns as isize {
+            z_a = a[(row, col)];
+            z_b = b[(row, col)];
+            if a.is_nodata(z_a) || b.is_nodata(z_b) {
+                continue;
+            }
+            let (k_a, k_b) = *shift.get_or_insert((z_a, z_b));
+            z_a -= k_a;
+            z_b -= k_b;
+            sum_a += z_a;
+            sum_b += z_b;
+            sum_aa += z_a * z_a;
+            sum_bb += z_b * z_b;
+            sum_ab += z_a * z_b;
+            n += 1;
+        }
+    }
+    if n == 0 {
+        return Ok(ComparisonStats { n: 0, pearson_r: f64::NAN, rmse: f64::NAN, mean_bias: f64::NAN });
+    }
+
+    let (k_a, k_b) = shift.unwrap();
+    let nf = n as f64;
+    let cov = sum_ab - sum_a * sum_b / nf;
+    let var_a = sum_aa - sum_a * sum_a / nf;
+    let var_b = sum_bb - sum_b * sum_b / nf;
+    let pearson_r = if var_a > 0f64 && var_b > 0f64 { cov / (var_a * var_b).sqrt() } else { f64::NAN };
+    // The differences of the shifted values are offset from the true differences by k_a - k_b.
+    let mean_diff = (sum_a - sum_b) / nf;
+    let sum_sq_diff = sum_aa - 2f64 * sum_ab + sum_bb;
+    let offset = k_a - k_b;
+    let mean_sq_diff = sum_sq_diff / nf + 2f64 * offset * mean_diff + offset * offset;
+
+    Ok(ComparisonStats {
+        n: n,
+        pearson_r: pearson_r,
+        rmse: mean_sq_diff.max(0f64).sqrt(),
+        mean_bias: mean_diff + offset,
+    })
+}
+
+/// Takes every k-th paired valid cell so that no more than `max_points` are returned.
+fn scatter_sample(a: &Raster, b: &Raster, n: usize, max_points: usize) -> Vec<(f64, f64)> {
+    let stride = (n + max_points - 1) / max_points;
+    let mut points = vec![];
+    let mut i = 0usize;
+    let (mut z_a, mut z_b): (f64, f64);
+    for row in 0..a.configs.rows as isize {
+        for col in 0..a.configs.columns as isize {
+            z_a = a[(row, col)];
+            z_b = b[(row, col)];
+            if a.is_nodata(z_a) || b.is_nodata(z_b) {
+                continue;
+            }
+            if i % stride == 0 {
+                points.push((z_b, z_a));
+            }
+            i += 1;
+        }
+    }
+    points
+}
+
+/// Draws an SVG scatter plot of (input 2, input 1) pairs with a 1:1 line.
+fn scatter_plot_svg(points: &[(f64, f64)], subsampled: bool) -> String {
+    let (width, height, margin) = (500f64, 500f64, 60f64);
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    for &(x, y) in points {
+        min = min.min(x).min(y);
+        max = max.max(x).max(y);
+    }
+    if max <= min {
+        max = min + 1f64;
+    }
+    let scale_x = |v: f64| margin + (v - min) / (max - min) * (width - 2f64 * margin);
+    let scale_y = |v: f64| height - margin - (v - min) / (max - min) * (height - 2f64 * margin);
+
+    let mut s = format!("        <p align=\"center\">
+        <svg width=\"{w}\" height=\"{h}\" xmlns=\"http://www.w3.org/2000/svg\" font-family=\"Helvetica, Arial, sans-serif\" font-size=\"12\">
+            <rect x=\"{m}\" y=\"{m}\" width=\"{pw}\" height=\"{ph}\" fill=\"none\" stroke=\"#222222\"/>
+            <line x1=\"{x0}\" y1=\"{y0}\" x2=\"{x1}\" y2=\"{y1}\" stroke=\"#cc0000\" stroke-dasharray=\"4,4\"/>
+            <text x=\"{m}\" y=\"{ty}\">{min:.2}</text>
+            <text x=\"{tx}\" y=\"{ty}\" text-anchor=\"end\">{max:.2}</text>
+            <text x=\"{cx}\" y=\"{ly}\" text-anchor=\"middle\">Input 2</text>
+            <text x=\"15\" y=\"{cy}\" text-anchor=\"middle\" transform=\"rotate(-90 15 {cy})\">Input 1</text>
+",
+        w = width, h = height, m = margin, pw = width - 2f64 * margin, ph = height - 2f64 * margin,
+        x0 = scale_x(min), y0 = scale_y(min), x1 = scale_x(max), y1 = scale_y(max),
+        min = min, max = max, tx = width - margin, ty = height - margin + 15f64,
+        cx = width / 2f64, ly = height - 20f64, cy = height / 2f64);
+    for &(x, y) in points {
+        s.push_str(&format!("            <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"1.5\" fill=\"#1f4e99\" fill-opacity=\"0.5\"/>\n", scale_x(x), scale_y(y)));
+    }
+    s.push_str("        </svg>\n");
+    if subsampled {
+        s.push_str(&format!("        <br>A systematic sample of {} paired cells is plotted.\n", points.len()));
+    }
+    s.push_str("        </p>\n");
+    s
+}
@@ -0,0 +1,174 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "raster_difference".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Subtracts an older raster from a newer one after checking that their grids are aligned.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--newer            Newer input raster file.
+--older            Older input raster file.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=raster_difference --wd=\"/dir/to/data\" --args=\"--newer=DEM_2016.dep --older=DEM_2010.dep -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut newer_file = String::new();
+    let mut older_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-newer" || vec[0].to_lowercase() == "--newer" {
+            if keyval {
+                newer_file = vec[1].to_string();
+            } else {
+                newer_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-older" || vec[0].to_lowercase() == "--older" {
+            if keyval {
+                older_file = vec[1].to_string();
+            } else {
+                older_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("********************************");
+        println!("* Welcome to raster_difference *");
+        println!("********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !newer_file.contains(&sep) {
+        newer_file = format!("{}{}", working_directory, newer_file);
+    }
+    if !older_file.contains(&sep) {
+        older_file = format!("{}{}", working_directory, older_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let newer = Raster::new(&newer_file, "r")?;
+    let older = Raster::new(&older_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating difference...") };
+    let mut output = raster_difference(&newer, &older)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' raster_difference tool".to_owned());
+    output.add_metadata_entry(format!("Newer file: {}", newer_file));
+    output.add_metadata_entry(format!("Older file: {}", older_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Subtracts `older` from `newer` cell by cell. The two rasters must be aligned (see
+/// `check_alignment`). Nodata in either input is nodata in the output.
+pub fn raster_difference(newer: &Raster, older: &Raster) -> Result<Raster, Error> {
+    check_alignment(newer, older)?;
+
+    let mut output = Raster::initialize_using_file(&newer.file_name, newer);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let out_nodata = output.configs.nodata;
+    let (mut z1, mut z2): (f64, f64);
+    for row in 0..newer.configs.rows as isize {
+        for col in 0..newer.configs.columns as isize {
+            z1 = newer[(row, col)];
+            z2 = older[(row, col)];
+            if !newer.is_nodata(z1) && !older.is_nodata(z2) {
+                output.set_value(row, col, z1 - z2);
+            } else {
+                output.set_value(row, col, out_nodata);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Checks that two rasters share the same rows, columns, cell size and origin; origins and cell
+/// sizes may differ by up to 0.1% of a cell to allow for floating-point round-off in the headers.
+pub fn check_alignment(a: &Raster, b: &Raster) -> Result<(), Error> {
+    let tolerance_x = 0.001 * a.configs.resolution_x;
+    let tolerance_y = 0.001 * a.configs.resolution_y;
+    if a.configs.rows != b.configs.rows || a.configs.columns != b.configs.columns {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("{} has {} rows and {} columns but {} has {} rows and {} columns.",
+            a.file_name, a.configs.rows, a.configs.columns,
+            b.file_name, b.configs.rows, b.configs.columns)));
+    }
+    if (a.configs.resolution_x - b.configs.resolution_x).abs() > tolerance_x ||
+        (a.configs.resolution_y - b.configs.resolution_y).abs() > tolerance_y {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("The cell size of {} ({} x {}) does not match that of {} ({} x {}).",
+            a.file_name, a.configs.resolution_x, a.configs.resolution_y,
+            b.file_name, b.configs.resolution_x, b.configs.resolution_y)));
+    }
+    if (a.configs.north - b.configs.north).abs() > tolerance_y ||
+        (a.configs.west - b.configs.west).abs() > tolerance_x {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("The origin of {} ({}, {}) does not match that of {} ({}, {}).",
+            a.file_name, a.configs.west, a.configs.north,
+            b.file_name, b.configs.west, b.configs.north)));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,288 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::collections::HashSet;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+use vector::Polyline;
+use vector::geojson;
+
+pub fn get_tool_name() -> String {
+    return "raster_streams_to_vector".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Converts a raster of single-cell-wide lines (e.g. thinned streams) into GeoJSON LineString features, broken at junctions.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input line raster file; non-zero cells are line cells.
+--pointer          Optional D8 flow pointer raster used to connect and orient the lines downstream.
+-o, --output       Output GeoJSON file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=raster_streams_to_vector --wd=\"/dir/to/data\" --args=\"-i=streams.dep --pointer=pointer.dep -o=streams.geojson\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut pointer_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-pointer" || vec[0].to_lowercase() == "--pointer" {
+            if keyval {
+                pointer_file = vec[1].to_string();
+            } else {
+                pointer_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("***************************************");
+        println!("* Welcome to raster_streams_to_vector *");
+        println!("***************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+    let pointer = if !pointer_file.is_empty() {
+        if !pointer_file.contains(&sep) {
+            pointer_file = format!("{}{}", working_directory, pointer_file);
+        }
+        Some(Raster::new(&pointer_file, "r")?)
+    } else {
+        None
+    };
+
+    let start = time::now();
+
+    if verbose { println!("Tracing lines...") };
+    let lines = raster_streams_to_vector(&input, pointer.as_ref())?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let _ = match geojson::write_polylines(&output_file, &lines, "VALUE") {
+        Ok(_) => if verbose { println!("Output file written ({} lines)", lines.len()) },
+        Err(e) => return Err(e),
+    };
+    if verbose {
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Traces the line cells (non-zero, non-nodata) of a raster of single-cell-wide lines, such as
+/// the output of `line_thinning`, into polylines through the cell centres. Lines are broken at
+/// junctions and each link between two cells belongs to exactly one polyline. Each polyline's
+/// value is that of its second cell, i.e. the first cell after the junction it starts from.
+///
+/// Without a pointer, cells are connected to their eight neighbours, except that diagonal
+/// links are ignored where the two cells already share an orthogonal line neighbour, so that
+/// corners of staircase lines aren't mistaken for junctions. Polylines run between endpoints
+/// and junctions (cells with other than two neighbours); closed loops without junctions are
+/// returned as closed polylines.
+///
+/// With a D8 `pointer` (see `d8_flow_accumulation` for the encoding), which must be aligned
+/// with the line raster, each line cell is connected only to the line cell it drains to.
+/// Polylines are oriented downstream and run from channel heads and confluences to the next
+/// confluence or the outlet.
+///
+/// Isolated line cells are skipped.
+pub fn raster_streams_to_vector(lines: &Raster, pointer: Option<&Raster>) -> Result<Vec<Polyline>, Error> {
+    if let Some(p) = pointer {
+        check_alignment(lines, p)?;
+    }
+    let rows = lines.configs.rows as isize;
+    let columns = lines.configs.columns as isize;
+    let d_x = [ 1, 1, 1, 0, -1, -1, -1, 0 ];
+    let d_y = [ -1, 0, 1, 1, 1, 0, -1, -1 ];
+
+    let is_line = |row: isize, col: isize| -> bool {
+        let z = lines[(row, col)];
+        !lines.is_nodata(z) && z != 0f64
+    };
+    let to_polyline = |cells: &[(isize, isize)]| -> Polyline {
+        let (row, col) = cells[1];
+        Polyline::new(cells.iter().map(|&(r, c)| (lines.get_x_from_column(c), lines.get_y_from_row(r))).collect(),
+            lines[(row, col)])
+    };
+
+    let mut polylines = vec![];
+    match pointer {
+        Some(p) => {
+            // The line cell that each line cell drains to, if any.
+            let downstream = |row: isize, col: isize| -> Option<(isize, isize)> {
+                let z = p[(row, col)];
+                if p.is_nodata(z) || z == 0f64 {
+                    return None;
+                }
+                let n = match (0..8).find(|&n| z == (1 << n) as f64) {
+                    Some(n) => n,
+                    None => return None,
+                };
+                let (row_n, col_n) = (row + d_y[n], col + d_x[n]);
+                if is_line(row_n, col_n) { Some((row_n, col_n)) } else { None }
+            };
+
+            let mut num_inflowing = vec![0u8; (rows * columns) as usize];
+            for row in 0..rows {
+                for col in 0..columns {
+                    if is_line(row, col) {
+                        if let Some((row_n, col_n)) = downstream(row, col) {
+                            num_inflowing[(row_n * columns + col_n) as usize] += 1;
+                        }
+                    }
+                }
+            }
+
+            // Each segment starts at a head or confluence and ends at the next confluence or
+            // outlet; the visited flags guard against cycles in an invalid pointer.
+            let mut visited = vec![false; (rows * columns) as usize];
+            for row in 0..rows {
+                for col in 0..columns {
+                    if !is_line(row, col) || num_inflowing[(row * columns + col) as usize] == 1 {
+                        continue;
+                    }
+                    let mut cells = vec![(row, col)];
+                    visited[(row * columns + col) as usize] = true;
+                    let mut cell = (row, col);
+                    while let Some((row_n, col_n)) = downstream(cell.0, cell.1) {
+                        let idx_n = (row_n * columns + col_n) as usize;
+                        cells.push((row_n, col_n));
+                        if num_inflowing[idx_n] != 1 || visited[idx_n] {
+                            break;
+                        }
+                        visited[idx_n] = true;
+                        cell = (row_n, col_n);
+                    }
+                    if cells.len() > 1 {
+                        polylines.push(to_polyline(&cells));
+                    }
+                }
+            }
+        },
+        None => {
+            let neighbours = |row: isize, col: isize| -> Vec<(isize, isize)> {
+                let mut cells = vec![];
+                for n in 0..8 {
+                    let (row_n, col_n) = (row + d_y[n], col + d_x[n]);
+                    if !is_line(row_n, col_n) {
+                        continue;
+                    }
+                    if d_x[n] != 0 && d_y[n] != 0 && (is_line(row + d_y[n], col) || is_line(row, col + d_x[n])) {
+                        continue;
+                    }
+                    cells.push((row_n, col_n));
+                }
+                cells
+            };
+            let index = |cell: (isize, isize)| -> isize { cell.0 * columns + cell.1 };
+            let link = |a: (isize, isize), b: (isize, isize)| -> (isize, isize) {
+                if index(a) < index(b) { (index(a), index(b)) } else { (index(b), index(a)) }
+            };
+
+            // Follows the chain of two-neighbour cells from `start` through `next` until it
+            // reaches a junction or endpoint, or returns to `start`.
+            let mut visited_links = HashSet::new();
+            let trace = |start: (isize, isize), next: (isize, isize), visited_links: &mut HashSet<(isize, isize)>| -> Vec<(isize, isize)> {
+                let mut cells = vec![start, next];
+                visited_links.insert(link(start, next));
+                let (mut prev, mut cell) = (start, next);
+                while cell != start {
+                    let n = neighbours(cell.0, cell.1);
+                    if n.len() != 2 {
+                        break;
+                    }
+                    let following = if n[0] == prev { n[1] } else { n[0] };
+                    if !visited_links.insert(link(cell, following)) {
+                        break;
+                    }
+                    cells.push(following);
+                    prev = cell;
+                    cell = following;
+                }
+                cells
+            };
+
+            for row in 0..rows {
+                for col in 0..columns {
+                    if !is_line(row, col) {
+                        continue;
+                    }
+                    let n = neighbours(row, col);
+                    if n.len() == 2 {
+                        continue;
+                    }
+                    for &next in &n {
+                        if !visited_links.contains(&link((row, col), next)) {
+                            let cells = trace((row, col), next, &mut visited_links);
+                            polylines.push(to_polyline(&cells));
+                        }
+                    }
+                }
+            }
+
+            // Whatever remains are loops with no junctions or endpoints.
+            for row in 0..rows {
+                for col in 0..columns {
+                    if !is_line(row, col) {
+                        continue;
+                    }
+                    let n = neighbours(row, col);
+                    if n.len() == 2 && !visited_links.contains(&link((row, col), n[0])) {
+                        let cells = trace((row, col), n[0], &mut visited_links);
+                        polylines.push(to_polyline(&cells));
+                    }
+                }
+            }
+        },
+    }
+
+    Ok(polylines)
+}
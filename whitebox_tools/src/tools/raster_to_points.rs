@@ -0,0 +1,104 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::io::BufWriter;
+use std::io::prelude::*;
+use std::fs::File;
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "raster_to_points".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Exports the valid cells of a raster as X,Y,Z points, using the cell centre coordinates, to a CSV file.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output CSV file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=raster_to_points --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=points.csv\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to raster_to_points *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Saving data...") };
+    let points = input.to_xyz();
+    let f = File::create(&output_file)?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all("X,Y,Z\n".as_bytes())?;
+    for &(x, y, z) in points.iter() {
+        writer.write_all(format!("{},{},{}\n", x, y, z).as_bytes())?;
+    }
+    let _ = writer.flush();
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose {
+        println!("Output file written ({} points)", points.len());
+        println!("{}", format!("Elapsed Time: {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
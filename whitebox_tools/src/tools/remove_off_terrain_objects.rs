@@ -4,6 +4,7 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::f64;
 use std::collections::VecDeque;
+use io_utils::file_utils::check_output_file;
 use raster::*;
 use structures::fixed_radius_search::FixedRadiusSearch;
 use structures::array2d::Array2D;
@@ -22,7 +23,8 @@ pub fn get_tool_parameters() -> String {
     let s = "-i, --input        Input raster file.
 -o, --output       Output raster file.
 --filter           Filter size (cells); default is 11.
---slope            Slope threshold; default is 15.0.";
+--slope            Slope threshold; default is 15.0.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
     return s.to_string();
 }
 
@@ -34,6 +36,8 @@ pub fn get_example_usage() -> Option<String> {
 pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
     let mut input_file = String::new();
     let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
     let mut filter_size = 11usize;
     let mut slope_threshold = 15f64;
     let mut keyval: bool;
@@ -71,6 +75,14 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
             } else {
                 slope_threshold = args[i+1].to_string().parse::<f64>().unwrap();
             }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
         }
     }
     if verbose {
@@ -99,6 +111,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     if !output_file.contains(&sep) {
         output_file = format!("{}{}", working_directory, output_file);
     }
+    check_output_file(&output_file, overwrite)?;
 
     if verbose { println!("Reading data...") };
     let input = Raster::new(&input_file, "r")?;
@@ -139,7 +152,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
                     let mut min_val = f64::INFINITY;
                     for row2 in start_row..end_row+1 {
                         z_n = input.get_value(row2, col + midpoint);
-                        if z_n < min_val && z_n != nodata { min_val = z_n; }
+                        if z_n < min_val && !input.is_nodata(z_n) { min_val = z_n; }
                     }
                     filter_vals.push_back(min_val);
                 } else {
@@ -150,13 +163,13 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
                         let mut min_val = f64::INFINITY;
                         for row2 in start_row..end_row+1 {
                             z_n = input.get_value(row2, col2);
-                            if z_n < min_val && z_n != nodata { min_val = z_n; }
+                            if z_n < min_val && !input.is_nodata(z_n) { min_val = z_n; }
                         }
                         filter_vals.push_back(min_val);
                     }
                 }
                 z = input.get_value(row, col);
-                if z != nodata {
+                if !input.is_nodata(z) {
                     let mut min_val = f64::INFINITY;
                     for v in filter_vals.iter() {
                         if *v < min_val { min_val = *v; }
@@ -191,7 +204,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
                     let mut max_val = f64::NEG_INFINITY;
                     for row2 in start_row..end_row+1 {
                         z_n = erosion[(row2, col + midpoint)];
-                        if z_n > max_val && z_n != nodata { max_val = z_n; }
+                        if z_n > max_val && !input.is_nodata(z_n) { max_val = z_n; }
                     }
                     filter_vals.push_back(max_val);
                 } else {
@@ -202,13 +215,13 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
                         let mut max_val = f64::NEG_INFINITY;
                         for row2 in start_row..end_row+1 {
                             z_n = erosion[(row2, col2)];
-                            if z_n > max_val && z_n != nodata { max_val = z_n; }
+                            if z_n > max_val && !input.is_nodata(z_n) { max_val = z_n; }
                         }
                         filter_vals.push_back(max_val);
                     }
                 }
                 z = input.get_value(row, col);
-                if z != nodata {
+                if !input.is_nodata(z) {
                     let mut max_val = f64::NEG_INFINITY;
                     for v in filter_vals.iter() {
                         if *v > max_val { max_val = *v; }
@@ -239,7 +252,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     for row in 0..rows {
         for col in 0..columns {
             out[(row, col)] = initial_value;
-            if tophat[(row, col)] != nodata {
+            if !input.is_nodata(tophat[(row, col)]) {
                 if tophat[(row, col)] <= height_diff_threshold[1] { // == 0f64 {
                     stack.push(GridCell { row: row, column: col });
                     out[(row, col)] = tophat[(row, col)];
@@ -266,7 +279,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
             row_n = row + d_y[i];
             col_n = col + d_x[i];
             z_n = tophat[(row_n, col_n)];
-            if z_n != nodata && out[(row_n, col_n)] == initial_value {
+            if !input.is_nodata(z_n) && out[(row_n, col_n)] == initial_value {
                 if z_n - z < height_diff_threshold[i] {
                     out[(row_n, col_n)] = z_n;
                     stack.push(GridCell { row: row_n, column: col_n });
@@ -280,11 +293,11 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     let mut frs: FixedRadiusSearch<f64> = FixedRadiusSearch::new(filter_size as f64 / 1.5f64);
     for row in 0..rows {
         for col in 0..columns {
-            if tophat[(row, col)] != nodata && out[(row, col)] != initial_value {
+            if !input.is_nodata(tophat[(row, col)]) && out[(row, col)] != initial_value {
                 for i in 0..8 {
                     row_n = row + d_y[i];
                     col_n = col + d_x[i];
-                    if tophat[(row_n, col_n)] != nodata && out[(row_n, col_n)] == initial_value {
+                    if !input.is_nodata(tophat[(row_n, col_n)]) && out[(row_n, col_n)] == initial_value {
                         frs.insert(col as f64, row as f64, opening[(row, col)] + tophat[(row, col)]);
                         break;
                     }
@@ -341,7 +354,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     let mut output = Raster::initialize_using_file(&output_file, &input);
     for row in 0..rows {
         for col in 0..columns {
-            if out[(row, col)] != initial_value && input.get_value(row, col) != nodata {
+            if out[(row, col)] != initial_value && !input.is_nodata(input.get_value(row, col)) {
                 output.set_value(row, col, out[(row, col)]);
             } else {
                 output.set_value(row, col, nodata);
@@ -363,6 +376,9 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
 
     if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
     let _ = match output.write() {
         Ok(_) => if verbose { println!("Output file written") },
         Err(e) => return Err(e),
@@ -402,11 +418,11 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     // for row in 0..rows {
     //     for col in 0..columns {
     //         z = input.get_value(row, col);
-    //         if z != nodata {
+    //         if !input.is_nodata(z) {
     //             let mut min_val = f64::INFINITY;
     //             for i in 0..num_pixels_in_filter {
     //                 z_n = input.get_value(row + d_y[i], col + d_x[i]);
-    //                 if z_n < min_val && filter_shape[i] && z_n != nodata { min_val = z_n }
+    //                 if z_n < min_val && filter_shape[i] && !input.is_nodata(z_n) { min_val = z_n }
     //             }
     //             erosion[(row, col)] = min_val;
     //         } else {
@@ -428,13 +444,13 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     // for row in 0..rows {
     //     for col in 0..columns {
     //         z = input.get_value(row, col);
-    //         if z != nodata {
+    //         if !input.is_nodata(z) {
     //             let mut max_val = f64::NEG_INFINITY;
     //             for i in 0..num_pixels_in_filter {
     //                 col_n = col + d_x[i];
     //                 row_n = row + d_y[i];
     //                 z_n = erosion[(row_n, col_n)];
-    //                 if z_n > max_val && filter_shape[i] && z_n != nodata { max_val = z_n }
+    //                 if z_n > max_val && filter_shape[i] && !input.is_nodata(z_n) { max_val = z_n }
     //             }
     //             tophat[(row, col)] = z - max_val;
     //             opening[(row, col)] = max_val;
@@ -454,10 +470,11 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     ///////////////////////////////////////////////////////////////////////////////////////////////
     // NOTE:
     // This disused code perfomed peak cleaving using a modified depression filling algorithm on
-    // the tophat transform. The current method of region growing is more straight forward.
+    // the tophat transform. The current method of region growing is more straight forward. If
+    // revived, it should use the f64 priorities of structures::grid_cell::GridCell; scaling
+    // elevations into isize priorities by a multiplier can overflow on large DEMs.
     ///////////////////////////////////////////////////////////////////////////////////////////////
     // find grid cells with nodata neighbours
-    // let multiplier = 10000f64;
     // let mut heap = BinaryHeap::new();
     // let initial_value = f64::NEG_INFINITY;
     // let mut num_solved_cells = 0usize;
@@ -468,16 +485,16 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     //     for col in 0..columns as isize {
     //         output.set_value(row, col, initial_value);
     //         z = input.get_value(row, col);
-    //         if z != nodata {
+    //         if !input.is_nodata(z) {
     //             let mut flag = false;
     //             for i in 0..8 {
     //                 z_n = input.get_value(row + d_y[i], col + d_x[i]);
-    //                 if z_n == nodata {
+    //                 if input.is_nodata(z_n) {
     //                     flag = true;
     //                 }
     //             }
     //             if flag {
-    //                 heap.push(GridCell { priority: -(tophat[row as usize][col as usize] * multiplier).floor() as isize, row: row, column: col });
+    //                 heap.push(GridCell::new(row, col, -tophat[row as usize][col as usize]));
     //                 output.set_value(row, col, tophat[row as usize][col as usize]);
     //                 num_solved_cells += 1;
     //             }
@@ -501,13 +518,13 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     //     let gc = heap.pop().unwrap();
     //     row = gc.row;
     //     col = gc.column;
-    //     z = -(gc.priority as f64 / multiplier);
+    //     z = -gc.priority;
     //     for i in 0..8 {
     //         row_n = row + d_y[i];
     //         col_n = col + d_x[i];
     //         if col_n >= 0 && col_n < columns as isize && row_n >= 0 && row_n < rows as isize {
     //             z_n = tophat[row_n as usize][col_n as usize];
-    //             if z_n != nodata && output.get_value(row_n, col_n) == initial_value {
+    //             if !input.is_nodata(z_n) && output.get_value(row_n, col_n) == initial_value {
     //                 if z_n - z >= height_diff_threshold { //z_n >= z {
     //                     z_n = z;
     //                     modified[row_n as usize][col_n as usize] = true;
@@ -517,7 +534,7 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
     //                 }
     //                 output.set_value(row_n, col_n, z_n);
     //                 num_solved_cells += 1;
-    //                 heap.push(GridCell { priority: -(z_n * multiplier).floor() as isize, row: row_n, column: col_n });
+    //                 heap.push(GridCell::new(row_n, col_n, -z_n));
     //             }
     //         }
     //     }
@@ -600,23 +617,6 @@ pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) ->
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 struct GridCell {
-// priority: isize,
-row: isize,
-column: isize,
+    row: isize,
+    column: isize,
 }
-
-// The priority queue depends on `Ord`.
-// Explicitly implement the trait so the queue becomes a min-heap instead of a max-heap.
-// impl Ord for GridCell {
-//     fn cmp(&self, other: &GridCell) -> Ordering {
-//         // Notice that the we flip the ordering here
-//         other.priority.cmp(&self.priority)
-//     }
-// }
-//
-// // `PartialOrd` needs to be implemented as well.
-// impl PartialOrd for GridCell {
-//     fn partial_cmp(&self, other: &GridCell) -> Option<Ordering> {
-//         Some(self.cmp(other))
-//     }
-// }
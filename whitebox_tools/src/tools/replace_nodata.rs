@@ -0,0 +1,199 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::percentile_stretch::histogram_percentile;
+
+pub fn get_tool_name() -> String {
+    return "replace_nodata".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Replaces nodata cells with a constant or with the global mean or median of the valid cells.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--method           Replacement method: 'mean' (default), 'median' or 'constant'.
+--fill_value       Value assigned to nodata cells; implies the 'constant' method.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=replace_nodata --wd=\"/dir/to/data\" --args=\"-i=input.dep -o=output.dep --method=median\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut method = "mean".to_string();
+    let mut fill_value = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-method" || vec[0].to_lowercase() == "--method" {
+            if keyval {
+                method = vec[1].to_lowercase();
+            } else {
+                method = args[i+1].to_lowercase();
+            }
+        } else if vec[0].to_lowercase() == "-fill_value" || vec[0].to_lowercase() == "--fill_value" {
+            if keyval {
+                fill_value = Some(vec[1].to_string().parse::<f64>().unwrap());
+            } else {
+                fill_value = Some(args[i+1].to_string().parse::<f64>().unwrap());
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    let value = match fill_value {
+        Some(v) => ReplaceValue::Constant(v),
+        None => {
+            if method.contains("median") {
+                ReplaceValue::GlobalMedian
+            } else if method.contains("mean") {
+                ReplaceValue::GlobalMean
+            } else {
+                return Err(Error::new(ErrorKind::InvalidInput, "The 'constant' method requires a --fill_value."));
+            }
+        },
+    };
+
+    if verbose {
+        println!("*****************************");
+        println!("* Welcome to replace_nodata *");
+        println!("*****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Replacing nodata...") };
+    let mut output = replace_nodata(&input, value);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' replace_nodata tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Replacement value: {:?}", value));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// The value that `replace_nodata` assigns to nodata cells.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplaceValue {
+    Constant(f64),
+    GlobalMean,
+    /// Estimated from a 10,000-bin histogram of the data.
+    GlobalMedian,
+}
+
+/// Replaces the nodata cells of a raster with a constant or with the mean or median of its
+/// valid cells, copying the valid cells unchanged. The output is F32 if the input's data type
+/// can't hold the fill value. A raster without valid cells is returned unchanged by the mean
+/// and median methods.
+pub fn replace_nodata(input: &Raster, value: ReplaceValue) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    for (row, col, z) in input.iter_valid() {
+        output.set_value(row, col, z);
+    }
+
+    let fill_value = match value {
+        ReplaceValue::Constant(v) => v,
+        ReplaceValue::GlobalMean => {
+            let (mut sum, mut n) = (0f64, 0f64);
+            for (_, _, z) in input.iter_valid() {
+                sum += z;
+                n += 1f64;
+            }
+            if n == 0f64 {
+                return output;
+            }
+            sum / n
+        },
+        ReplaceValue::GlobalMedian => {
+            let (min, bin_width, counts) = input.histogram(10000);
+            if counts.iter().all(|&c| c == 0) {
+                return output;
+            }
+            histogram_percentile(min, bin_width, &counts, 50f64)
+        },
+    };
+    // The mean and median are rarely integers, and would be truncated in an integer type.
+    if !output.configs.data_type.can_hold(fill_value) {
+        output.configs.data_type = DataType::F32;
+    }
+
+    for row in 0..rows {
+        for col in 0..columns {
+            if input.is_nodata(input[(row, col)]) {
+                output.set_value(row, col, fill_value);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,165 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::resample::{sample, Resampling};
+
+pub fn get_tool_name() -> String {
+    return "resample".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Resamples a raster to a new cell size using nearest-neighbour or bilinear interpolation.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--cell_size        Output grid cell size.
+--method           Resampling method, 'nearest', 'bilinear' or 'cubic'; default is nearest.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=resample --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --cell_size=10.0 --method=bilinear\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut cell_size = 0f64;
+    let mut method = Resampling::Nearest;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-cell_size" || vec[0].to_lowercase() == "--cell_size" {
+            if keyval {
+                cell_size = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                cell_size = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-method" || vec[0].to_lowercase() == "--method" {
+            if keyval {
+                method = Resampling::from_str(vec[1]);
+            } else {
+                method = Resampling::from_str(&args[i+1]);
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if cell_size <= 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The output cell size must be a positive value."));
+    }
+
+    if verbose {
+        println!("***********************");
+        println!("* Welcome to resample *");
+        println!("***********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Resampling...") };
+    let mut output = resample(&input, cell_size, method);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' resample tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Cell size: {}", cell_size));
+    output.add_metadata_entry(format!("Resampling method: {:?}", method));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Resamples the input to a new cell size. The output grid keeps the input's north-west corner
+/// and has enough rows and columns to cover the input's extent.
+pub fn resample(input: &Raster, new_cell_size: f64, method: Resampling) -> Raster {
+    let rows = ((input.configs.north - input.configs.south) / new_cell_size).ceil() as usize;
+    let columns = ((input.configs.east - input.configs.west) / new_cell_size).ceil() as usize;
+    let mut output = Raster::initialize_using_extent(&input.file_name, input, rows, columns,
+        input.configs.north, input.configs.west, new_cell_size, new_cell_size);
+
+    let (mut x, mut y): (f64, f64);
+    let out_nodata = output.configs.nodata;
+    for row in 0..rows as isize {
+        y = output.configs.north - (row as f64 + 0.5) * new_cell_size;
+        for col in 0..columns as isize {
+            x = output.configs.west + (col as f64 + 0.5) * new_cell_size;
+            if y < input.configs.south || x > input.configs.east {
+                continue;
+            }
+            let z = sample(input,
+                (input.configs.north - y) / input.configs.resolution_y - 0.5,
+                (x - input.configs.west) / input.configs.resolution_x - 0.5,
+                method);
+            if !input.is_nodata(z) {
+                output.set_value(row, col, z);
+            } else {
+                output.set_value(row, col, out_nodata);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,164 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "round_values".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Rounds raster values to a given number of decimal places or to a multiple of a step.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--decimals         Number of decimal places to round to; default is 2.
+--step             Optional rounding step, e.g. 0.25; overrides --decimals when specified.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=round_values --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --decimals=1\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut decimals = 2u32;
+    let mut step = 0f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-decimals" || vec[0].to_lowercase() == "--decimals" {
+            if keyval {
+                decimals = vec[1].to_string().parse::<u32>().unwrap();
+            } else {
+                decimals = args[i+1].to_string().parse::<u32>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-step" || vec[0].to_lowercase() == "--step" {
+            if keyval {
+                step = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                step = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if step < 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The rounding step must be positive."));
+    }
+
+    if verbose {
+        println!("***************************");
+        println!("* Welcome to round_values *");
+        println!("***************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Rounding values...") };
+    let mut output = if step > 0f64 {
+        round_to_step(&input, step)
+    } else {
+        round_values(&input, decimals)
+    };
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' round_values tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(if step > 0f64 { format!("Step: {}", step) } else { format!("Decimals: {}", decimals) });
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Rounds each grid cell that is not nodata to `decimals` decimal places. Nodata cells are left
+/// untouched.
+pub fn round_values(input: &Raster, decimals: u32) -> Raster {
+    let factor = 10f64.powi(decimals as i32);
+    round_with(input, |z| (z * factor).round() / factor)
+}
+
+/// Rounds each grid cell that is not nodata to the nearest multiple of `step`, e.g. 0.25. Nodata
+/// cells are left untouched.
+pub fn round_to_step(input: &Raster, step: f64) -> Raster {
+    round_with(input, |z| (z / step).round() * step)
+}
+
+fn round_with<F>(input: &Raster, round: F) -> Raster where F: Fn(f64) -> f64 {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    let out_nodata = output.configs.nodata;
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input[(row, col)];
+            output.set_value(row, col, if !input.is_nodata(z) { round(z) } else { out_nodata });
+        }
+    }
+
+    output
+}
@@ -0,0 +1,167 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "set_nodata_value".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Marks cells with a sentinel value as nodata or changes a raster's declared nodata value.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--old_value        Cell value to be treated as nodata (e.g. -9999).
+--new_value        New value to use as the declared nodata value.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=set_nodata_value --wd=\"/dir/to/data\" --args=\"-i=input.dep -o=output.dep --old_value=-9999.0 --new_value=-32768.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut old_value = None;
+    let mut new_value = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-old_value" || vec[0].to_lowercase() == "--old_value" {
+            if keyval {
+                old_value = Some(vec[1].to_string().parse::<f64>().unwrap());
+            } else {
+                old_value = Some(args[i+1].to_string().parse::<f64>().unwrap());
+            }
+        } else if vec[0].to_lowercase() == "-new_value" || vec[0].to_lowercase() == "--new_value" {
+            if keyval {
+                new_value = Some(vec[1].to_string().parse::<f64>().unwrap());
+            } else {
+                new_value = Some(args[i+1].to_string().parse::<f64>().unwrap());
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if old_value.is_none() && new_value.is_none() {
+        return Err(Error::new(ErrorKind::InvalidInput, "At least one of --old_value or --new_value must be specified."));
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to set_nodata_value *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Setting nodata values...") };
+    let mut output = match (old_value, new_value) {
+        (Some(old), Some(new)) => set_nodata_value(&set_nodata_value(&input, old, true), new, false),
+        (Some(old), None) => set_nodata_value(&input, old, true),
+        (None, Some(new)) => set_nodata_value(&input, new, false),
+        (None, None) => unreachable!(),
+    };
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' set_nodata_value tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    if let Some(old) = old_value {
+        output.add_metadata_entry(format!("Value assigned as nodata: {}", old));
+    }
+    if let Some(new) = new_value {
+        output.add_metadata_entry(format!("New nodata value: {}", new));
+    }
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// When `assign_as_nodata` is true, cells equal to `old` are marked as nodata. Otherwise the
+/// raster's declared nodata value is replaced by `old`, both in the configs and in every nodata
+/// cell.
+pub fn set_nodata_value(input: &Raster, old: f64, assign_as_nodata: bool) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    if !assign_as_nodata {
+        output.configs.nodata = old;
+    }
+    let out_nodata = output.configs.nodata;
+    let mut z: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input[(row, col)];
+            if input.is_nodata(z) || (assign_as_nodata && z == old) {
+                output.set_value(row, col, out_nodata);
+            } else {
+                output.set_value(row, col, z);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,177 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use vector::geojson;
+use vector::shapefile::Shapefile;
+
+pub fn get_tool_name() -> String {
+    return "snap_pour_points".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Moves outlet points to the highest flow accumulation cell within a snap distance.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--pour_pts         Input point shapefile of pour points (outlets).
+--flow_accum       Input flow accumulation raster file.
+-o, --output       Output GeoJSON file.
+--snap_dist        Maximum snap distance in map units.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=snap_pour_points --wd=\"/dir/to/data\" --args=\"--pour_pts=outlets.shp --flow_accum=accum.dep -o=snapped.geojson --snap_dist=50.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut pour_pts_file = String::new();
+    let mut flow_accum_file = String::new();
+    let mut output_file = String::new();
+    let mut snap_dist = 0f64;
+    let mut overwrite = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-pour_pts" || vec[0].to_lowercase() == "--pour_pts" {
+            if keyval {
+                pour_pts_file = vec[1].to_string();
+            } else {
+                pour_pts_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-flow_accum" || vec[0].to_lowercase() == "--flow_accum" {
+            if keyval {
+                flow_accum_file = vec[1].to_string();
+            } else {
+                flow_accum_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-snap_dist" || vec[0].to_lowercase() == "--snap_dist" {
+            if keyval {
+                snap_dist = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                snap_dist = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        }
+    }
+
+    if snap_dist < 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The snap distance must not be negative."));
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to snap_pour_points *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !pour_pts_file.contains(&sep) {
+        pour_pts_file = format!("{}{}", working_directory, pour_pts_file);
+    }
+    if !flow_accum_file.contains(&sep) {
+        flow_accum_file = format!("{}{}", working_directory, flow_accum_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let flow_accum = Raster::new(&flow_accum_file, "r")?;
+    let pour_pts = Shapefile::read_points(&pour_pts_file)?
+                        .iter()
+                        .map(|&(x, y, _)| (x, y))
+                        .collect::<Vec<(f64, f64)>>();
+
+    let start = time::now();
+
+    if verbose { println!("Snapping pour points...") };
+    let snapped = snap_pour_points(&pour_pts, &flow_accum, snap_dist)
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &(x, y))| (x, y, (i + 1) as f64))
+                        .collect::<Vec<(f64, f64, f64)>>();
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    if verbose { println!("Saving data...") };
+    let _ = match geojson::write_points(&output_file, &snapped, "FID") {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+    if verbose {
+        println!("{}", format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+    }
+
+    Ok(())
+}
+
+/// Moves each pour point to the centre of the grid cell with the highest flow accumulation
+/// within `snap_dist` (in map units) of it, so that hand-digitized outlets land on the stream
+/// they were meant to mark. Ties go to the cell closest to the original point. Points with no
+/// valid accumulation cell within `snap_dist` are returned unchanged.
+pub fn snap_pour_points(points: &[(f64, f64)], flow_accum: &Raster, snap_dist: f64) -> Vec<(f64, f64)> {
+    let rows = flow_accum.configs.rows as isize;
+    let columns = flow_accum.configs.columns as isize;
+    let res_x = flow_accum.configs.resolution_x;
+    let res_y = flow_accum.configs.resolution_y;
+    let radius_x = (snap_dist / res_x).ceil() as isize;
+    let radius_y = (snap_dist / res_y).ceil() as isize;
+
+    let mut snapped = Vec::with_capacity(points.len());
+    let (mut x_n, mut y_n, mut z_n, mut dist): (f64, f64, f64, f64);
+    for &(x, y) in points {
+        let row = flow_accum.get_row_from_y(y);
+        let col = flow_accum.get_column_from_x(x);
+        let mut best = (x, y);
+        let mut best_accum = f64::NEG_INFINITY;
+        let mut best_dist = f64::INFINITY;
+        for row_n in (row - radius_y).max(0)..(row + radius_y + 1).min(rows) {
+            for col_n in (col - radius_x).max(0)..(col + radius_x + 1).min(columns) {
+                z_n = flow_accum[(row_n, col_n)];
+                if flow_accum.is_nodata(z_n) {
+                    continue;
+                }
+                x_n = flow_accum.get_x_from_column(col_n);
+                y_n = flow_accum.get_y_from_row(row_n);
+                dist = ((x_n - x) * (x_n - x) + (y_n - y) * (y_n - y)).sqrt();
+                if dist > snap_dist {
+                    continue;
+                }
+                if z_n > best_accum || (z_n == best_accum && dist < best_dist) {
+                    best = (x_n, y_n);
+                    best_accum = z_n;
+                    best_dist = dist;
+                }
+            }
+        }
+        snapped.push(best);
+    }
+
+    snapped
+}
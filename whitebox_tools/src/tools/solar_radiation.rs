@@ -0,0 +1,205 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::neighborhood::{for_each_3x3, horn_gradient};
+
+pub fn get_tool_name() -> String {
+    return "solar_radiation".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates a relative (0-1) potential solar radiation index from slope, aspect and the sun's path on a given day.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--latitude         Latitude of the site, in degrees (north positive).
+--day              Day of the year (1-366); default is 172 (June 21).
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=solar_radiation --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=solar.dep --latitude=43.5 --day=172\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut latitude = 0f64;
+    let mut day_of_year = 172u32;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-latitude" || vec[0].to_lowercase() == "--latitude" {
+            if keyval {
+                latitude = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                latitude = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-day" || vec[0].to_lowercase() == "--day" {
+            if keyval {
+                day_of_year = vec[1].to_string().parse::<u32>().unwrap();
+            } else {
+                day_of_year = args[i+1].to_string().parse::<u32>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if latitude < -90f64 || latitude > 90f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --latitude parameter must be between -90 and 90 degrees."));
+    }
+    if day_of_year < 1 || day_of_year > 366 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --day parameter must be between 1 and 366."));
+    }
+
+    if verbose {
+        println!("******************************");
+        println!("* Welcome to solar_radiation *");
+        println!("******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating solar radiation...") };
+    let mut output = solar_radiation(&input, latitude, day_of_year);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' solar_radiation tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Latitude: {}", latitude));
+    output.add_metadata_entry(format!("Day of year: {}", day_of_year));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// The hour-angle step, in degrees (four minutes), used to integrate over daylight hours.
+const HOUR_ANGLE_STEP: f64 = 1f64;
+
+/// Calculates a potential solar radiation index for each grid cell on the given day of the year
+/// at the given latitude (degrees, north positive). The cosine of the angle of incidence
+/// between the sun and the cell's surface, from Horn's (1981) slope and aspect, is integrated
+/// over the daylight hours and divided by the value for a surface that faces the sun all day,
+/// giving relative values from 0 to 1. The sun's path comes from the solar declination (Cooper,
+/// 1969) and hour angle; the atmosphere and shading of cells by surrounding terrain are
+/// ignored, although slopes facing away from the sun receive nothing. Cells at latitudes
+/// in polar night are 0. Nodata cells remain nodata.
+pub fn solar_radiation(dem: &Raster, latitude: f64, day_of_year: u32) -> Raster {
+    let lat = latitude.to_radians();
+    let declination = (23.45f64 * (2f64 * f64::consts::PI * (284f64 + day_of_year as f64) / 365f64).sin()).to_radians();
+
+    // Hour angle at sunset; the sun either never sets or never rises near the poles.
+    let cos_sunset = -lat.tan() * declination.tan();
+    let sunset = if cos_sunset <= -1f64 {
+        f64::consts::PI
+    } else if cos_sunset >= 1f64 {
+        0f64
+    } else {
+        cos_sunset.acos()
+    };
+
+    // Unit vectors (east, north, up) towards the sun at the midpoints of the daylight steps.
+    let num_steps = (2f64 * sunset.to_degrees() / HOUR_ANGLE_STEP).ceil() as usize;
+    let step = if num_steps > 0 { 2f64 * sunset / num_steps as f64 } else { 0f64 };
+    let mut sun = Vec::with_capacity(num_steps);
+    for i in 0..num_steps {
+        let hour_angle = -sunset + (i as f64 + 0.5) * step;
+        let east = -declination.cos() * hour_angle.sin();
+        let north = lat.cos() * declination.sin() - lat.sin() * declination.cos() * hour_angle.cos();
+        let up = lat.sin() * declination.sin() + lat.cos() * declination.cos() * hour_angle.cos();
+        if up > 0f64 {
+            sun.push((east, north, up));
+        }
+    }
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for_each_3x3(dem, |row, col, window| {
+        if sun.is_empty() {
+            output.set_value(row, col, 0f64);
+            return;
+        }
+        // The cell's unit surface normal is proportional to (-dz/dx, -dz/dy, 1).
+        let (dz_dx, dz_dy) = horn_gradient(window, dem);
+        let norm = (dz_dx * dz_dx + dz_dy * dz_dy + 1f64).sqrt();
+        let mut sum = 0f64;
+        for &(east, north, up) in &sun {
+            let cos_incidence = (-dz_dx * east - dz_dy * north + up) / norm;
+            if cos_incidence > 0f64 {
+                sum += cos_incidence;
+            }
+        }
+        output.set_value(row, col, sum / sun.len() as f64);
+    });
+
+    output
+}
@@ -0,0 +1,135 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "specific_catchment_area".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Converts flow accumulation (in cells) to specific catchment area, the contributing area per unit contour width.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input flow accumulation raster file (number of cells).
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=specific_catchment_area --wd=\"/dir/to/data\" --args=\"-i=flow_accum.dep -o=sca.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("**************************************");
+        println!("* Welcome to specific_catchment_area *");
+        println!("**************************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating specific catchment area...") };
+    let mut output = specific_catchment_area(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' specific_catchment_area tool".to_owned());
+    output.add_metadata_entry(format!("Flow accumulation file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the specific catchment area, the contributing area per unit contour width, from a
+/// flow accumulation raster measured as the number of contributing cells including the cell
+/// itself (e.g. from `d8_flow_accumulation` or `dinf_flow_accumulation`). Nodata accumulation
+/// cells are nodata in the output (see `specific_area` for the conversion).
+pub fn specific_catchment_area(flow_accum: &Raster) -> Raster {
+    let res_x = flow_accum.configs.resolution_x;
+    let res_y = flow_accum.configs.resolution_y;
+    let mut output = Raster::initialize_using_file(&flow_accum.file_name, flow_accum);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for (row, col, accum) in flow_accum.iter_valid() {
+        output.set_value(row, col, specific_area(accum, res_x, res_y));
+    }
+
+    output
+}
+
+/// Converts a flow accumulation, in cells, to specific catchment area: the contributing area
+/// divided by the contour width, which is taken to be the mean cell size for both D8 and
+/// D-infinity flow. Accumulations below one cell are treated as one cell.
+pub fn specific_area(accum: f64, res_x: f64, res_y: f64) -> f64 {
+    let contour_width = (res_x + res_y) / 2f64;
+    accum.max(1f64) * res_x * res_y / contour_width
+}
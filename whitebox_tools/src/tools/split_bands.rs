@@ -0,0 +1,153 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::multiband::MultiBandRaster;
+
+pub fn get_tool_name() -> String {
+    return "split_bands".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Splits a multi-band (e.g. RGB) raster into single-band rasters named after the input, e.g. image_band1.dep, image_band2.dep, ...";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input multi-band raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=split_bands --wd=\"/dir/to/data\" --args=\"-i=image.tif\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("**************************");
+        println!("* Welcome to split_bands *");
+        println!("**************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+
+    if verbose { println!("Reading data...") };
+    let start = time::now();
+    let bands = split_bands(&input_file)?;
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    let output_files: Vec<String> = (0..bands.len()).map(|i| band_file_name(&input_file, i + 1)).collect();
+    for f in &output_files {
+        check_output_file(f, overwrite)?;
+    }
+
+    if verbose { println!("Saving data...") };
+    for (i, mut band) in bands.into_iter().enumerate() {
+        band.set_file_name(&output_files[i]);
+        band.add_metadata_entry("Created by whitebox_tools\' split_bands tool".to_owned());
+        band.add_metadata_entry(format!("Input file: {}", input_file));
+        band.add_metadata_entry(format!("Band: {}", i + 1));
+        band.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+        if let Some(compression) = compression {
+            band.configs.compression = compression;
+        }
+        band.write()?;
+        if verbose { println!("Output file written: {}", output_files[i]) };
+    }
+
+    Ok(())
+}
+
+/// Reads a multi-band raster and splits it into one single-band raster per band, each with the
+/// configs of the file it came from (apart from the data type and photometric
+/// interpretation). The multi-band rasters read by this crate are RGB colour composites, e.g.
+/// RGB GeoTIFFs and Whitebox or Idrisi RGB rasters, whose cells pack the channels into one
+/// value; these give red, green and blue bands of 0-255 values, in that order, with any alpha
+/// channel dropped. Whitebox files with more than one stack (see `MultiBandRaster`) give one
+/// raster per stack, and single-band rasters are returned as they are. Nodata cells are
+/// nodata in every band. The bands share the input's file name; call `set_file_name` (see
+/// `band_file_name`) before writing them.
+pub fn split_bands(path: &str) -> Result<Vec<Raster>, Error> {
+    let input = Raster::new(path, "r")?;
+    if input.configs.bands > 1 {
+        return Ok(MultiBandRaster::new(path)?.into_bands());
+    }
+    let is_rgb = input.configs.photometric_interp == PhotometricInterpretation::RGB ||
+        input.configs.data_type == DataType::RGB24 || input.configs.data_type == DataType::RGB48 ||
+        input.configs.data_type == DataType::RGBA32;
+    if !is_rgb {
+        return Ok(vec![input]);
+    }
+
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let mut bands = vec![];
+    // The packed values are (alpha << 24) | (red << 16) | (green << 8) | blue.
+    for &shift in [16u32, 8, 0].iter() {
+        let mut band = Raster::initialize_using_file(path, &input);
+        band.set_data_type(DataType::I16);
+        band.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        for row in 0..rows {
+            for col in 0..columns {
+                let z = input[(row, col)];
+                if !input.is_nodata(z) {
+                    band.set_value(row, col, ((z as u32 >> shift) & 0xFF) as f64);
+                }
+            }
+        }
+        bands.push(band);
+    }
+
+    Ok(bands)
+}
+
+/// Returns the name of the file for band `band` (numbered from 1) of a split raster, e.g.
+/// `/data/image_band2.dep` for band 2 of `/data/image.tif`.
+pub fn band_file_name(input_file: &str, band: usize) -> String {
+    let stem = match Path::new(input_file).extension() {
+        Some(ext) => &input_file[..input_file.len() - ext.len() - 1],
+        None => input_file,
+    };
+    format!("{}_band{}.dep", stem, band)
+}
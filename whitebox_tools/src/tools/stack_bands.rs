@@ -0,0 +1,171 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::multiband::MultiBandRaster;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "stack_bands".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Stacks aligned single-band rasters into one multi-band Whitebox raster; the inverse of split_bands.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --inputs       Input raster files, separated by semicolons or commas.
+-o, --output       Output multi-band raster file (.dep).
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=stack_bands --wd=\"/dir/to/data\" --args=\"-i=\"band1.dep;band2.dep;band3.dep\" -o=image.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_files = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--inputs" {
+            if keyval {
+                input_files = vec[1].to_string();
+            } else {
+                input_files = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("**************************");
+        println!("* Welcome to stack_bands *");
+        println!("**************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut cmd = input_files.split(";");
+    let mut vec = cmd.collect::<Vec<&str>>();
+    if vec.len() == 1 {
+        cmd = input_files.split(",");
+        vec = cmd.collect::<Vec<&str>>();
+    }
+    let mut inputs: Vec<Raster> = vec![];
+    for value in vec {
+        if !value.trim().is_empty() {
+            let mut input_file = value.trim().to_owned();
+            if !input_file.contains(&sep) {
+                input_file = format!("{}{}", working_directory, input_file);
+            }
+            inputs.push(Raster::new(&input_file, "r")?);
+        }
+    }
+
+    let start = time::now();
+
+    if verbose { println!("Stacking bands...") };
+    let mut output = stack_bands(&inputs)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' stack_bands tool".to_owned());
+    output.add_metadata_entry(format!("Input files: {}", input_files));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Stacks single-band rasters into a `MultiBandRaster`, in the order given, after checking
+/// that they share a grid (see `check_alignment`); a mismatch is an error naming both files.
+/// The stack takes the first band's grid and nodata value and the widest of the bands' data
+/// types, so that no band loses precision, widened further if needed to hold the nodata value. Colour composites are stacked as their packed
+/// values; split them with `split_bands` first.
+pub fn stack_bands(inputs: &[Raster]) -> Result<MultiBandRaster, Error> {
+    if inputs.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "At least one input raster is required."));
+    }
+    if inputs.len() > u8::max_value() as usize {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("{} bands were given but a stack can hold at most {}.", inputs.len(), u8::max_value())));
+    }
+    let first = &inputs[0];
+    for band in &inputs[1..] {
+        check_alignment(first, band)?;
+    }
+    // Data types other than the four stored by Whitebox rasters are stacked as F32.
+    let width = |data_type: DataType| -> usize {
+        match data_type {
+            DataType::U8 => 0,
+            DataType::I16 => 1,
+            DataType::F64 => 3,
+            _ => 2,
+        }
+    };
+    let from_width = |width: usize| -> DataType {
+        match width {
+            0 => DataType::U8,
+            1 => DataType::I16,
+            3 => DataType::F64,
+            _ => DataType::F32,
+        }
+    };
+    // The type is widened further if it can't hold the stack's nodata value, e.g. the crate's
+    // default of -32768 in U8 bands, rather than saturating it onto valid values.
+    let mut w = inputs.iter().map(|b| width(b.configs.data_type)).max().unwrap();
+    while !from_width(w).can_hold(first.configs.nodata) {
+        w += 1;
+    }
+    let data_type = from_width(w);
+
+    let mut output = MultiBandRaster::from_bands(&first.file_name, inputs.to_vec());
+    output.configs.data_type = data_type;
+
+    Ok(output)
+}
@@ -0,0 +1,165 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::neighborhood::for_each_3x3;
+
+pub fn get_tool_name() -> String {
+    return "surface_area_ratio".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the ratio of surface area to planimetric area of each cell (Jenness, 2004), a measure of roughness.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output raster file.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=surface_area_ratio --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=sar.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*********************************");
+        println!("* Welcome to surface_area_ratio *");
+        println!("*********************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating surface area ratio...") };
+    let mut output = surface_area_ratio(&input);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' surface_area_ratio tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the ratio of the surface area of each grid cell to its planimetric area using
+/// Jenness's (2004) method: the cell is divided into eight triangles joining its centre to
+/// each pair of adjacent neighbours, with the triangles' edges, measured in three dimensions,
+/// halved so that they lie within the cell. Triangles that involve a nodata or off-grid
+/// neighbour are left out and the ratio is taken over the remaining triangles, but cells with
+/// fewer than four valid triangles (e.g. grid corners) are nodata. Flat cells have a ratio of
+/// one, and the ratio grows with roughness.
+pub fn surface_area_ratio(dem: &Raster) -> Raster {
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    // The window indices of the neighbours, clockwise from north.
+    let ring = [1, 2, 5, 8, 7, 6, 3, 0];
+    let triangle_area = res_x * res_y / 8f64;
+
+    // Half the three-dimensional distance between two cells in the 3x3 window.
+    let half_dist = |window: &[f64; 9], a: usize, b: usize| -> f64 {
+        let dx = ((a % 3) as f64 - (b % 3) as f64) * res_x;
+        let dy = ((a / 3) as f64 - (b / 3) as f64) * res_y;
+        let dz = window[a] - window[b];
+        (dx * dx + dy * dy + dz * dz).sqrt() / 2f64
+    };
+
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for_each_3x3(dem, |row, col, window| {
+        let mut surface_area = 0f64;
+        let mut num_triangles = 0;
+        for i in 0..8 {
+            let (a, b) = (ring[i], ring[(i + 1) % 8]);
+            if dem.is_nodata(window[a]) || dem.is_nodata(window[b]) {
+                continue;
+            }
+            // Heron's formula
+            let e1 = half_dist(window, 4, a);
+            let e2 = half_dist(window, 4, b);
+            let e3 = half_dist(window, a, b);
+            let s = (e1 + e2 + e3) / 2f64;
+            surface_area += (s * (s - e1) * (s - e2) * (s - e3)).max(0f64).sqrt();
+            num_triangles += 1;
+        }
+        if num_triangles >= 4 {
+            output.set_value(row, col, surface_area / (num_triangles as f64 * triangle_area));
+        }
+    });
+
+    output
+}
@@ -0,0 +1,250 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::resample::{sample, Resampling};
+
+pub fn get_tool_name() -> String {
+    return "terrain_fabric".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Maps terrain anisotropy: the direction of greatest elevation variance and the ratio of the greatest to least directional standard deviation.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --dem          Input DEM raster file.
+-o, --output       Output dominant direction raster file (degrees clockwise from north).
+--ratio            Output anisotropy ratio raster file.
+--filter           Filter size (cells); default is 11.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=terrain_fabric --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=fabric_dir.dep --ratio=fabric_ratio.dep --filter=15\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut ratio_file = String::new();
+    let mut filter_size = 11usize;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--dem" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-ratio" || vec[0].to_lowercase() == "--ratio" {
+            if keyval {
+                ratio_file = vec[1].to_string();
+            } else {
+                ratio_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*****************************");
+        println!("* Welcome to terrain_fabric *");
+        println!("*****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    if ratio_file.is_empty() {
+        return Err(Error::new(ErrorKind::InvalidInput, "An output anisotropy ratio file (--ratio) must be specified."));
+    }
+    if !ratio_file.contains(&sep) {
+        ratio_file = format!("{}{}", working_directory, ratio_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+    check_output_file(&ratio_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating terrain fabric...") };
+    let (mut output, mut ratio) = terrain_fabric(&input, filter_size);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' terrain_fabric tool".to_owned());
+    output.add_metadata_entry(format!("DEM file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    ratio.set_file_name(&ratio_file);
+    ratio.add_metadata_entry("Created by whitebox_tools\' terrain_fabric tool".to_owned());
+    ratio.add_metadata_entry(format!("DEM file: {}", input_file));
+    ratio.add_metadata_entry(format!("Filter size: {}", filter_size));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+    if let Some(compression) = compression {
+        ratio.configs.compression = compression;
+    }
+    let _ = match ratio.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// The number of azimuths, evenly spaced over 180 degrees, along which variance is measured.
+const NUM_DIRECTIONS: usize = 8;
+
+/// Characterizes the terrain fabric around each grid cell by the variance of the elevations
+/// sampled (by bilinear interpolation) at cell-size intervals along lines through the cell at
+/// eight azimuths, 22.5 degrees apart, spanning the filter window. Returns the azimuth of the
+/// line with the greatest variance, in degrees clockwise from north in [0, 180), and the
+/// anisotropy ratio, the ratio of the greatest to the least of the lines' standard deviations.
+/// The direction of least variance, perpendicular to the dominant direction on simple terrain,
+/// is the direction of continuity, i.e. `aniso_dir` in `fill_missing_data`.
+///
+/// Cells where any line has fewer than half of its samples on valid data are nodata in both
+/// outputs. Flat windows have a direction of 0 and a ratio of 1, while windows with a
+/// perfectly uniform line but some variation elsewhere have an undefined ratio and are nodata
+/// in the ratio output.
+pub fn terrain_fabric(dem: &Raster, filter_size: usize) -> (Raster, Raster) {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    let step = res_x.min(res_y);
+    let min_samples = (filter_size + 1) / 2;
+
+    // The (row, column) offset per step along each azimuth.
+    let mut azimuths = [0f64; NUM_DIRECTIONS];
+    let mut steps = [(0f64, 0f64); NUM_DIRECTIONS];
+    for d in 0..NUM_DIRECTIONS {
+        azimuths[d] = d as f64 * 180f64 / NUM_DIRECTIONS as f64;
+        let a = azimuths[d].to_radians();
+        steps[d] = (-a.cos() * step / res_y, a.sin() * step / res_x);
+    }
+
+    let mut direction = Raster::initialize_using_file(&dem.file_name, dem);
+    direction.configs.data_type = DataType::F32;
+    direction.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let mut ratio = Raster::initialize_using_file(&dem.file_name, dem);
+    ratio.configs.data_type = DataType::F32;
+    ratio.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let (mut z, mut sum, mut sum_sqr, mut n, mut variance): (f64, f64, f64, f64, f64);
+    for row in 0..rows {
+        for col in 0..columns {
+            // Samples are offset by the cell's elevation to limit the loss of precision when
+            // differencing large sums of squares. Bilinear interpolation still leaves round-off
+            // of a few ulps of the elevation on flat ground, so variances below a tolerance
+            // relative to the elevation are taken as zero.
+            let reference = dem[(row, col)];
+            if dem.is_nodata(reference) {
+                continue;
+            }
+            let tolerance = (1e-9 * reference.abs().max(1f64)).powi(2);
+            let mut max_var = f64::NEG_INFINITY;
+            let mut min_var = f64::INFINITY;
+            let mut max_dir = 0f64;
+            let mut valid = true;
+            for d in 0..NUM_DIRECTIONS {
+                sum = 0f64;
+                sum_sqr = 0f64;
+                n = 0f64;
+                for k in -midpoint..midpoint + 1 {
+                    z = sample(dem, row as f64 + k as f64 * steps[d].0, col as f64 + k as f64 * steps[d].1, Resampling::Bilinear);
+                    if !dem.is_nodata(z) {
+                        z -= reference;
+                        sum += z;
+                        sum_sqr += z * z;
+                        n += 1f64;
+                    }
+                }
+                if (n as usize) < min_samples || n < 2f64 {
+                    valid = false;
+                    break;
+                }
+                variance = (sum_sqr - sum * sum / n) / n;
+                if variance < tolerance { variance = 0f64; }
+                if variance > max_var {
+                    max_var = variance;
+                    max_dir = azimuths[d];
+                }
+                if variance < min_var {
+                    min_var = variance;
+                }
+            }
+            if !valid {
+                continue;
+            }
+            direction.set_value(row, col, max_dir);
+            if min_var > 0f64 {
+                ratio.set_value(row, col, (max_var / min_var).sqrt());
+            } else if max_var == 0f64 {
+                ratio.set_value(row, col, 1f64);
+            }
+        }
+    }
+
+    (direction, ratio)
+}
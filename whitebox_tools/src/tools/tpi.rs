@@ -0,0 +1,181 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::structuring_element::annulus_offsets;
+
+pub fn get_tool_name() -> String {
+    return "tpi".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the topographic position index, the difference between each cell's elevation and the mean elevation of an annulus around it.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster DEM file.
+-o, --output       Output raster file.
+--inner_radius     Optional inner radius of the annulus, in map units; default is 0.0.
+--outer_radius     Outer radius of the annulus, in map units.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=tpi --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --inner_radius=100.0 --outer_radius=500.0\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut inner_radius = 0f64;
+    let mut outer_radius = 0f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-inner_radius" || vec[0].to_lowercase() == "--inner_radius" {
+            if keyval {
+                inner_radius = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                inner_radius = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-outer_radius" || vec[0].to_lowercase() == "--outer_radius" {
+            if keyval {
+                outer_radius = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                outer_radius = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if outer_radius <= 0f64 || inner_radius < 0f64 || inner_radius > outer_radius {
+        return Err(Error::new(ErrorKind::InvalidInput, "The radii must satisfy 0 <= inner_radius <= outer_radius and outer_radius > 0."));
+    }
+
+    if verbose {
+        println!("******************");
+        println!("* Welcome to tpi *");
+        println!("******************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating TPI...") };
+    let mut output = tpi(&input, inner_radius, outer_radius);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' tpi tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Inner radius: {}", inner_radius));
+    output.add_metadata_entry(format!("Outer radius: {}", outer_radius));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the topographic position index (TPI), the elevation of each cell minus the mean
+/// elevation of the cells in the annulus between `inner_radius` and `outer_radius` (map units)
+/// around it. Positive values indicate ridges and hilltops, negative values valleys. Cells
+/// for which fewer than half of the annulus cells are valid, e.g. near the grid edge or large
+/// nodata areas, are nodata.
+pub fn tpi(input: &Raster, inner_radius: f64, outer_radius: f64) -> Raster {
+    let rows = input.configs.rows as isize;
+    let columns = input.configs.columns as isize;
+    let offsets = annulus_offsets(inner_radius, outer_radius, input.configs.resolution_x, input.configs.resolution_y);
+    let min_count = (offsets.len() + 1) / 2;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let out_nodata = output.configs.nodata;
+    let (mut z, mut z_n, mut sum): (f64, f64, f64);
+    let mut n: usize;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input[(row, col)];
+            if input.is_nodata(z) {
+                output.set_value(row, col, out_nodata);
+                continue;
+            }
+            sum = 0f64;
+            n = 0;
+            for &(dx, dy) in offsets.iter() {
+                z_n = input[(row + dy, col + dx)];
+                if !input.is_nodata(z_n) {
+                    sum += z_n;
+                    n += 1;
+                }
+            }
+            if n > 0 && n >= min_count {
+                output.set_value(row, col, z - sum / n as f64);
+            } else {
+                output.set_value(row, col, out_nodata);
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,139 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+
+pub fn get_tool_name() -> String {
+    return "trim_nodata".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Crops a raster to the bounding box of its valid (non-nodata) cells.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=trim_nodata --wd=\"/dir/to/data\" --args=\"-i=masked.dep -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("**************************");
+        println!("* Welcome to trim_nodata *");
+        println!("**************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Trimming nodata borders...") };
+    let mut output = trim_nodata(&input)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' trim_nodata tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Trims the rows and columns of nodata surrounding the valid data, shifting the origin so
+/// that the remaining cells keep their georeferencing. An input without any valid cells is an
+/// error.
+pub fn trim_nodata(input: &Raster) -> Result<Raster, Error> {
+    let (mut min_row, mut max_row) = (isize::max_value(), -1isize);
+    let (mut min_col, mut max_col) = (isize::max_value(), -1isize);
+    for (row, col, _) in input.iter_valid() {
+        if row < min_row { min_row = row; }
+        if row > max_row { max_row = row; }
+        if col < min_col { min_col = col; }
+        if col > max_col { max_col = col; }
+    }
+    if max_row < 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("{} does not contain any valid cells.", input.file_name)));
+    }
+
+    let rows = (max_row - min_row + 1) as usize;
+    let columns = (max_col - min_col + 1) as usize;
+    let mut output = Raster::initialize_using_extent(&input.file_name, input, rows, columns,
+        input.configs.north - min_row as f64 * input.configs.resolution_y,
+        input.configs.west + min_col as f64 * input.configs.resolution_x,
+        input.configs.resolution_x, input.configs.resolution_y);
+    for (row, col, z) in input.iter_valid() {
+        output.set_value(row - min_row, col - min_col, z);
+    }
+
+    Ok(output)
+}
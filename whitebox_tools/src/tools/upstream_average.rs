@@ -0,0 +1,166 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+use tools::d8_flow_accumulation::accumulate_downstream;
+
+pub fn get_tool_name() -> String {
+    return "upstream_average".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the average of an attribute over the upstream area of each cell, following a D8 flow pointer.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--pointer          Input D8 flow pointer raster file.
+--attribute        Input attribute raster file (e.g. rainfall).
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=upstream_average --wd=\"/dir/to/data\" --args=\"--pointer=pointer.dep --attribute=rainfall.dep -o=catchment_rainfall.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut pointer_file = String::new();
+    let mut attribute_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-pointer" || vec[0].to_lowercase() == "--pointer" {
+            if keyval {
+                pointer_file = vec[1].to_string();
+            } else {
+                pointer_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-attribute" || vec[0].to_lowercase() == "--attribute" {
+            if keyval {
+                attribute_file = vec[1].to_string();
+            } else {
+                attribute_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to upstream_average *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !pointer_file.contains(&sep) {
+        pointer_file = format!("{}{}", working_directory, pointer_file);
+    }
+    if !attribute_file.contains(&sep) {
+        attribute_file = format!("{}{}", working_directory, attribute_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let pointer = Raster::new(&pointer_file, "r")?;
+    let attribute = Raster::new(&attribute_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating upstream averages...") };
+    let mut output = upstream_average(&pointer, &attribute)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' upstream_average tool".to_owned());
+    output.add_metadata_entry(format!("Pointer file: {}", pointer_file));
+    output.add_metadata_entry(format!("Attribute file: {}", attribute_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates, for each cell, the average of an attribute (e.g. rainfall) over the cell and all
+/// of the cells upstream of it along a D8 flow pointer raster (encoded as for
+/// `d8_flow_accumulation`). The attribute sum and the number of contributing cells are
+/// accumulated together in one traversal and divided at the end. Cells where the attribute is
+/// nodata are excluded from the average but still pass on the flow they receive, and cells with
+/// no valid attribute values upstream are nodata, as are nodata pointer cells. The attribute
+/// raster must be aligned with the pointer raster (see `check_alignment`).
+pub fn upstream_average(pointer: &Raster, attribute: &Raster) -> Result<Raster, Error> {
+    check_alignment(pointer, attribute)?;
+    let rows = pointer.configs.rows as isize;
+    let columns = pointer.configs.columns as isize;
+
+    let mut sum = vec![0f64; (rows * columns) as usize];
+    let mut count = vec![0f64; (rows * columns) as usize];
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = attribute[(row, col)];
+            if !pointer.is_nodata(pointer[(row, col)]) && !attribute.is_nodata(z) {
+                sum[(row * columns + col) as usize] = z;
+                count[(row * columns + col) as usize] = 1f64;
+            }
+        }
+    }
+    let mut accumulators = vec![sum, count];
+    accumulate_downstream(pointer, &mut accumulators);
+
+    let mut output = Raster::initialize_using_file(&pointer.file_name, pointer);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    for row in 0..rows {
+        for col in 0..columns {
+            let idx = (row * columns + col) as usize;
+            if !pointer.is_nodata(pointer[(row, col)]) && accumulators[1][idx] > 0f64 {
+                output.set_value(row, col, accumulators[0][idx] / accumulators[1][idx]);
+            }
+        }
+    }
+
+    Ok(output)
+}
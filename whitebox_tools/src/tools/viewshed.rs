@@ -0,0 +1,250 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::f64;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use raster::resample::{sample, Resampling};
+use vector::shapefile::Shapefile;
+
+pub fn get_tool_name() -> String {
+    return "viewshed".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Identifies the cells of a DEM that are visible from an observer point.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--observer         Observer location as x,y in world coordinates, or a point shapefile whose first point is used.
+--height           Observer height above the ground surface; default is 2.0.
+--max_dist         Optional maximum viewing distance, in map units; default is unlimited.
+--curvature        Optional flag indicating that elevations are corrected for earth curvature and refraction.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=viewshed --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --observer=670000.0,4885000.0 --height=10.0 --max_dist=5000.0 --curvature\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut observer = String::new();
+    let mut obs_height = 2f64;
+    let mut max_dist = f64::INFINITY;
+    let mut earth_curvature = false;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-observer" || vec[0].to_lowercase() == "--observer" {
+            if keyval {
+                observer = vec[1].to_string();
+            } else {
+                observer = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-height" || vec[0].to_lowercase() == "--height" {
+            if keyval {
+                obs_height = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                obs_height = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-max_dist" || vec[0].to_lowercase() == "--max_dist" {
+            if keyval {
+                max_dist = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                max_dist = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-curvature" || vec[0].to_lowercase() == "--curvature" {
+            earth_curvature = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("***********************");
+        println!("* Welcome to viewshed *");
+        println!("***********************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    // The observer is either an x,y coordinate pair or a point shapefile, of which the first
+    // point is used.
+    let observer_vals: Vec<f64> = if observer.to_lowercase().ends_with(".shp") {
+        if !observer.contains(&sep) {
+            observer = format!("{}{}", working_directory, observer);
+        }
+        match Shapefile::read_points(&observer)?.first() {
+            Some(&(x, y, _)) => vec![x, y],
+            None => return Err(Error::new(ErrorKind::InvalidInput, format!("{} does not contain any points.", observer))),
+        }
+    } else {
+        observer.split(",")
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| s.trim().parse::<f64>().unwrap())
+                .collect()
+    };
+    if observer_vals.len() != 2 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The --observer parameter must contain two values: x,y."));
+    }
+
+    if verbose { println!("Reading data...") };
+    let input = Raster::new(&input_file, "r")?;
+
+    let start = time::now();
+
+    let obs_row = input.get_row_from_y(observer_vals[1]);
+    let obs_col = input.get_column_from_x(observer_vals[0]);
+    if input.is_nodata(input.get_value(obs_row, obs_col)) {
+        return Err(Error::new(ErrorKind::InvalidInput, "The observer must lie on a valid cell of the input DEM."));
+    }
+
+    if verbose { println!("Calculating viewshed...") };
+    let mut output = viewshed_with_options(&input, (observer_vals[0], observer_vals[1]), obs_height, max_dist, earth_curvature);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' viewshed tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Observer: {}", observer));
+    output.add_metadata_entry(format!("Observer height: {}", obs_height));
+    output.add_metadata_entry(format!("Maximum distance: {}", max_dist));
+    output.add_metadata_entry(format!("Earth curvature correction: {}", earth_curvature));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates a binary viewshed, without earth-curvature correction. See
+/// `viewshed_with_options`.
+pub fn viewshed(dem: &Raster, observer: (f64, f64), obs_height: f64, max_dist: f64) -> Raster {
+    viewshed_with_options(dem, observer, obs_height, max_dist, false)
+}
+
+/// Calculates a binary viewshed (1 visible, 0 not visible) for an observer at the world
+/// coordinate `observer` standing `obs_height` above the DEM surface. Cells further than
+/// `max_dist` (map units) are not visible. Each target cell is tested with its own ray (the R3
+/// algorithm), bilinearly interpolating the DEM wherever the ray crosses a row or column.
+/// Samples that touch nodata cells are skipped, i.e. nodata never blocks the view; nodata
+/// targets remain nodata. If the observer lies outside the DEM or on a nodata cell, nothing
+/// is visible.
+///
+/// With `earth_curvature`, elevations are lowered by d^2 / 2R, reduced by the conventional
+/// atmospheric refraction coefficient of 0.13.
+pub fn viewshed_with_options(dem: &Raster, observer: (f64, f64), obs_height: f64, max_dist: f64, earth_curvature: bool) -> Raster {
+    let rows = dem.configs.rows as isize;
+    let columns = dem.configs.columns as isize;
+    let res_x = dem.configs.resolution_x;
+    let res_y = dem.configs.resolution_y;
+    let mut output = Raster::initialize_using_file(&dem.file_name, dem);
+    let out_nodata = output.configs.nodata;
+
+    let obs_row = dem.get_row_from_y(observer.1);
+    let obs_col = dem.get_column_from_x(observer.0);
+    let z_obs = dem.get_value(obs_row, obs_col);
+    let observer_valid = obs_row >= 0 && obs_row < rows && obs_col >= 0 && obs_col < columns && !dem.is_nodata(z_obs);
+    let z_obs = z_obs + obs_height;
+
+    let curvature_factor = if earth_curvature { (1f64 - 0.13) / (2f64 * 6371000f64) } else { 0f64 };
+    let max_dist_sqr = max_dist * max_dist;
+    let (mut d_row, mut d_col, mut dist_sqr, mut dist): (f64, f64, f64, f64);
+    let (mut max_slope, mut slope, mut z, mut t): (f64, f64, f64, f64);
+    let mut num_steps: isize;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = dem[(row, col)];
+            if dem.is_nodata(z) {
+                output.set_value(row, col, out_nodata);
+                continue;
+            }
+            if !observer_valid {
+                output.set_value(row, col, 0f64);
+                continue;
+            }
+            d_row = (row - obs_row) as f64;
+            d_col = (col - obs_col) as f64;
+            dist_sqr = (d_row * res_y) * (d_row * res_y) + (d_col * res_x) * (d_col * res_x);
+            if dist_sqr > max_dist_sqr {
+                output.set_value(row, col, 0f64);
+                continue;
+            }
+            if row == obs_row && col == obs_col {
+                output.set_value(row, col, 1f64);
+                continue;
+            }
+
+            // Step along the ray one row or column at a time, whichever is the major axis.
+            num_steps = (row - obs_row).abs().max((col - obs_col).abs());
+            max_slope = f64::NEG_INFINITY;
+            for k in 1..num_steps {
+                t = k as f64 / num_steps as f64;
+                let z_n = sample(dem, obs_row as f64 + t * d_row, obs_col as f64 + t * d_col, Resampling::Bilinear);
+                if !dem.is_nodata(z_n) {
+                    dist = t * dist_sqr.sqrt();
+                    slope = (z_n - curvature_factor * dist * dist - z_obs) / dist;
+                    if slope > max_slope { max_slope = slope; }
+                }
+            }
+            dist = dist_sqr.sqrt();
+            slope = (z - curvature_factor * dist_sqr - z_obs) / dist;
+            output.set_value(row, col, if slope >= max_slope { 1f64 } else { 0f64 });
+        }
+    }
+
+    output
+}
@@ -0,0 +1,188 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+
+pub fn get_tool_name() -> String {
+    return "weighted_overlay".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Combines several rasters into a weighted sum, e.g. for multi-criteria suitability modelling.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --inputs       Input raster files, separated by semicolons or commas.
+-o, --output       Output raster file.
+--weights          Weight of each input raster, in the same order; the weights are normalized to sum to one.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=weighted_overlay --wd=\"/dir/to/data\" --args=\"-i=\"slope.dep;dist_to_road.dep;landcover.dep\" --weights=\"0.5;0.3;0.2\" -o=output.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_files = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut weights_str = String::new();
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--inputs" {
+            if keyval {
+                input_files = vec[1].to_string();
+            } else {
+                input_files = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-weights" || vec[0].to_lowercase() == "--weights" {
+            if keyval {
+                weights_str = vec[1].to_string();
+            } else {
+                weights_str = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("*******************************");
+        println!("* Welcome to weighted_overlay *");
+        println!("*******************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut cmd = input_files.split(";");
+    let mut vec = cmd.collect::<Vec<&str>>();
+    if vec.len() == 1 {
+        cmd = input_files.split(",");
+        vec = cmd.collect::<Vec<&str>>();
+    }
+    let mut inputs: Vec<Raster> = vec![];
+    for value in vec {
+        if !value.trim().is_empty() {
+            let mut input_file = value.trim().to_owned();
+            if !input_file.contains(&sep) {
+                input_file = format!("{}{}", working_directory, input_file);
+            }
+            inputs.push(Raster::new(&input_file, "r")?);
+        }
+    }
+
+    let mut cmd = weights_str.split(";");
+    let mut vec = cmd.collect::<Vec<&str>>();
+    if vec.len() == 1 {
+        cmd = weights_str.split(",");
+        vec = cmd.collect::<Vec<&str>>();
+    }
+    let weights: Vec<f64> = vec.iter()
+                               .filter(|s| !s.trim().is_empty())
+                               .map(|s| s.trim().parse::<f64>().unwrap())
+                               .collect();
+    if weights.len() != inputs.len() {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("{} weights were specified for {} input files.", weights.len(), inputs.len())));
+    }
+    let layers: Vec<(Raster, f64)> = inputs.into_iter().zip(weights.into_iter()).collect();
+
+    let start = time::now();
+
+    if verbose { println!("Performing weighted overlay...") };
+    let mut output = weighted_overlay(&layers)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' weighted_overlay tool".to_owned());
+    output.add_metadata_entry(format!("Input files: {}", input_files));
+    output.add_metadata_entry(format!("Weights: {}", weights_str));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Calculates the weighted sum of the layers, dividing by the total weight so that the weights
+/// need not sum to one. A cell that is nodata in any layer is nodata in the output.
+pub fn weighted_overlay(layers: &[(Raster, f64)]) -> Result<Raster, Error> {
+    if layers.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "At least one input raster is required."));
+    }
+    let first = &layers[0].0;
+    let rows = first.configs.rows;
+    let columns = first.configs.columns;
+    let mut sum_weights = 0f64;
+    for &(ref layer, weight) in layers {
+        check_alignment(first, layer)?;
+        sum_weights += weight;
+    }
+    if sum_weights == 0f64 {
+        return Err(Error::new(ErrorKind::InvalidInput, "The layer weights must not sum to zero."));
+    }
+
+    let mut output = Raster::initialize_using_file(&first.file_name, first);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let mut z: f64;
+    let mut z_n: f64;
+    for row in 0..rows as isize {
+        for col in 0..columns as isize {
+            z = 0f64;
+            for &(ref layer, weight) in layers {
+                z_n = layer[(row, col)];
+                if layer.is_nodata(z_n) {
+                    z = output.configs.nodata;
+                    break;
+                }
+                z += z_n * weight / sum_weights;
+            }
+            output.set_value(row, col, z);
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,160 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use tools::raster_difference::check_alignment;
+use tools::specific_catchment_area::specific_area;
+
+pub fn get_tool_name() -> String {
+    return "wetness_index".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Calculates the topographic wetness index, ln(a / tan(slope)), from flow accumulation and slope rasters.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "--flow_accum       Input flow accumulation raster file, in cells.
+--slope            Input slope raster file, in degrees.
+-o, --output       Output raster file.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=wetness_index --wd=\"/dir/to/data\" --args=\"--flow_accum=flow_accum.dep --slope=slope.dep -o=twi.dep\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut accum_file = String::new();
+    let mut slope_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-flow_accum" || vec[0].to_lowercase() == "--flow_accum" {
+            if keyval {
+                accum_file = vec[1].to_string();
+            } else {
+                accum_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-slope" || vec[0].to_lowercase() == "--slope" {
+            if keyval {
+                slope_file = vec[1].to_string();
+            } else {
+                slope_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("****************************");
+        println!("* Welcome to wetness_index *");
+        println!("****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !accum_file.contains(&sep) {
+        accum_file = format!("{}{}", working_directory, accum_file);
+    }
+    if !slope_file.contains(&sep) {
+        slope_file = format!("{}{}", working_directory, slope_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let flow_accum = Raster::new(&accum_file, "r")?;
+    let slope = Raster::new(&slope_file, "r")?;
+
+    let start = time::now();
+
+    if verbose { println!("Calculating wetness index...") };
+    let mut output = wetness_index(&flow_accum, &slope)?;
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' wetness_index tool".to_owned());
+    output.add_metadata_entry(format!("Flow accumulation file: {}", accum_file));
+    output.add_metadata_entry(format!("Slope file: {}", slope_file));
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// The tangent of the slope substituted for flatter cells, avoiding division by zero.
+const MIN_TAN_SLOPE: f64 = 0.001;
+
+/// Calculates the topographic wetness index, ln(a / tan(slope)), from aligned rasters of flow
+/// accumulation, as the number of contributing cells including the cell itself, and slope in
+/// degrees (see `check_alignment`). The specific catchment area, a, is the contributing area per
+/// unit contour width (see `specific_area`). Slopes with a tangent below 0.001 (about 0.06
+/// degrees) are raised to it. Nodata in either input is nodata in the output.
+pub fn wetness_index(flow_accum: &Raster, slope: &Raster) -> Result<Raster, Error> {
+    check_alignment(flow_accum, slope)?;
+
+    let res_x = flow_accum.configs.resolution_x;
+    let res_y = flow_accum.configs.resolution_y;
+    let mut output = Raster::initialize_using_file(&flow_accum.file_name, flow_accum);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    let out_nodata = output.configs.nodata;
+    let (mut accum, mut s, mut sca, mut tan_slope): (f64, f64, f64, f64);
+    for row in 0..flow_accum.configs.rows as isize {
+        for col in 0..flow_accum.configs.columns as isize {
+            accum = flow_accum[(row, col)];
+            s = slope[(row, col)];
+            if flow_accum.is_nodata(accum) || slope.is_nodata(s) {
+                output.set_value(row, col, out_nodata);
+                continue;
+            }
+            sca = specific_area(accum, res_x, res_y);
+            tan_slope = s.to_radians().tan().abs().max(MIN_TAN_SLOPE);
+            output.set_value(row, col, (sca / tan_slope).ln());
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,235 @@
+extern crate time;
+
+use std::io::{Error, ErrorKind};
+use std::path;
+use io_utils::file_utils::check_output_file;
+use raster::*;
+use structures::array2d::Array2D;
+
+pub fn get_tool_name() -> String {
+    return "wiener_filter".to_string();
+}
+
+pub fn get_tool_description() -> String {
+    let s = "Performs an adaptive Wiener filter that smooths noise while preserving edges.";
+
+    return s.to_string();
+}
+
+pub fn get_tool_parameters() -> String {
+    let s = "-i, --input        Input raster file.
+-o, --output       Output raster file.
+--filter           Filter size (cells); default is 5.
+--noise            Optional noise variance; estimated from the average local variance if not specified.
+--zero_is_nodata   Optional flag indicating that zero-valued cells are treated as nodata.
+--overwrite        Optional flag indicating that an existing output file may be replaced.";
+    return s.to_string();
+}
+
+pub fn get_example_usage() -> Option<String> {
+    let s = "./whitebox-tools -r=wiener_filter --wd=\"/dir/to/data\" --args=\"-i=DEM.dep -o=output.dep --filter=7 --noise=0.25\"";
+    return Some(s.to_string());
+}
+
+pub fn run<'a>(args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    let mut input_file = String::new();
+    let mut output_file = String::new();
+    let mut overwrite = false;
+    let mut compression: Option<Compression> = None;
+    let mut zero_is_nodata = false;
+    let mut filter_size = 5usize;
+    let mut noise_var = -1f64;
+    let mut keyval: bool;
+    if args.len() == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Tool run with no paramters. Please see help (-h) for parameter descriptions."));
+    }
+    for i in 0..args.len() {
+        let mut arg = args[i].replace("\"", "");
+        arg = arg.replace("\'", "");
+        let cmd = arg.split("="); // in case an equals sign was used
+        let vec = cmd.collect::<Vec<&str>>();
+        keyval = false;
+        if vec.len() > 1 { keyval = true; }
+        if vec[0].to_lowercase() == "-i" || vec[0].to_lowercase() == "--input" {
+            if keyval {
+                input_file = vec[1].to_string();
+            } else {
+                input_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
+            if keyval {
+                output_file = vec[1].to_string();
+            } else {
+                output_file = args[i+1].to_string();
+            }
+        } else if vec[0].to_lowercase() == "-filter" || vec[0].to_lowercase() == "--filter" {
+            if keyval {
+                filter_size = vec[1].to_string().parse::<usize>().unwrap();
+            } else {
+                filter_size = args[i+1].to_string().parse::<usize>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-noise" || vec[0].to_lowercase() == "--noise" {
+            if keyval {
+                noise_var = vec[1].to_string().parse::<f64>().unwrap();
+            } else {
+                noise_var = args[i+1].to_string().parse::<f64>().unwrap();
+            }
+        } else if vec[0].to_lowercase() == "-zero_is_nodata" || vec[0].to_lowercase() == "--zero_is_nodata" {
+            zero_is_nodata = true;
+        } else if vec[0].to_lowercase() == "-overwrite" || vec[0].to_lowercase() == "--overwrite" {
+            overwrite = true;
+        } else if vec[0].to_lowercase() == "-compress" || vec[0].to_lowercase() == "--compress" {
+            if keyval {
+                compression = Some(vec[1].parse()?);
+            } else {
+                compression = Some(args[i+1].parse()?);
+            }
+        }
+    }
+
+    if verbose {
+        println!("****************************");
+        println!("* Welcome to wiener_filter *");
+        println!("****************************");
+    }
+
+    let sep: String = path::MAIN_SEPARATOR.to_string();
+    if !input_file.contains(&sep) {
+        input_file = format!("{}{}", working_directory, input_file);
+    }
+    if !output_file.contains(&sep) {
+        output_file = format!("{}{}", working_directory, output_file);
+    }
+    check_output_file(&output_file, overwrite)?;
+
+    if verbose { println!("Reading data...") };
+    let mut input = Raster::new(&input_file, "r")?;
+    input.configs.zero_is_nodata = zero_is_nodata;
+
+    let start = time::now();
+
+    if verbose { println!("Performing Wiener filter...") };
+    let mut output = wiener_filter(&input, filter_size, noise_var);
+
+    let end = time::now();
+    let elapsed_time = end - start;
+
+    output.set_file_name(&output_file);
+    output.add_metadata_entry("Created by whitebox_tools\' wiener_filter tool".to_owned());
+    output.add_metadata_entry(format!("Input file: {}", input_file));
+    output.add_metadata_entry(format!("Filter size: {}", filter_size));
+    if noise_var >= 0f64 {
+        output.add_metadata_entry(format!("Noise variance: {}", noise_var));
+    }
+    output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+
+    if verbose { println!("Saving data...") };
+    if let Some(compression) = compression {
+        output.configs.compression = compression;
+    }
+    let _ = match output.write() {
+        Ok(_) => if verbose { println!("Output file written") },
+        Err(e) => return Err(e),
+    };
+
+    Ok(())
+}
+
+/// Applies an adaptive Wiener filter: each cell is shrunk towards the mean of the non-nodata
+/// values within the filter window by the proportion of the local variance attributed to
+/// noise, i.e. mean + max(variance - noise_var, 0) / max(variance, noise_var) * (z - mean),
+/// so that smooth areas are averaged while areas of high local variance (edges) are kept. If
+/// `noise_var` is negative, it is estimated as the average of the local variances. The local
+/// statistics are calculated from integral images, so the cost per cell is independent of the
+/// filter size. Nodata cells are excluded from the statistics and remain nodata.
+pub fn wiener_filter(input: &Raster, filter_size: usize, noise_var: f64) -> Raster {
+    let mut filter_size = filter_size;
+    // The filter dimensions must be odd numbers such that there is a middle pixel
+    if (filter_size as f64 / 2f64).floor() == (filter_size as f64 / 2f64) {
+        filter_size += 1;
+    }
+    let midpoint = (filter_size as f64 / 2f64).floor() as isize;
+    let columns = input.configs.columns as isize;
+    let rows = input.configs.rows as isize;
+    let mut output = Raster::initialize_using_file(&input.file_name, input);
+    output.configs.data_type = DataType::F32;
+    output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+
+    // Values are offset by the first valid value to limit the loss of precision when
+    // differencing large sums of squares.
+    let reference = match input.iter_valid().next() {
+        Some((_, _, z)) => z,
+        None => return output,
+    };
+
+    // The integral images have an extra leading row and column of zeros.
+    let (mut z, mut sum, mut sum_sqr, mut n): (f64, f64, f64, f64);
+    let mut values: Vec<f64>;
+    let mut i_sum: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    let mut i_sum_sqr: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    let mut i_n: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64).unwrap();
+    for row in 0..rows {
+        values = input.get_row_data(row);
+        sum = 0f64;
+        sum_sqr = 0f64;
+        n = 0f64;
+        for col in 0..columns {
+            z = values[col as usize];
+            if !input.is_nodata(z) {
+                z -= reference;
+                sum += z;
+                sum_sqr += z * z;
+                n += 1f64;
+            }
+            i_sum[(row + 1, col + 1)] = sum + i_sum[(row, col + 1)];
+            i_sum_sqr[(row + 1, col + 1)] = sum_sqr + i_sum_sqr[(row, col + 1)];
+            i_n[(row + 1, col + 1)] = n + i_n[(row, col + 1)];
+        }
+    }
+
+    // The local mean (relative to the reference value) and variance of each valid cell.
+    let mut means: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64).unwrap();
+    let mut variances: Array2D<f64> = Array2D::new(rows, columns, 0f64, 0f64).unwrap();
+    let (mut y1, mut y2, mut x1, mut x2): (isize, isize, isize, isize);
+    let mut variance: f64;
+    let (mut total_variance, mut num_cells) = (0f64, 0f64);
+    for row in 0..rows {
+        // rows and columns in the integral images are shifted by one
+        y1 = if row - midpoint < 0 { 0 } else { row - midpoint };
+        y2 = if row + midpoint + 1 > rows { rows } else { row + midpoint + 1 };
+        for col in 0..columns {
+            if !input.is_nodata(input.get_value(row, col)) {
+                x1 = if col - midpoint < 0 { 0 } else { col - midpoint };
+                x2 = if col + midpoint + 1 > columns { columns } else { col + midpoint + 1 };
+                n = i_n[(y2, x2)] + i_n[(y1, x1)] - i_n[(y1, x2)] - i_n[(y2, x1)];
+                sum = i_sum[(y2, x2)] + i_sum[(y1, x1)] - i_sum[(y1, x2)] - i_sum[(y2, x1)];
+                sum_sqr = i_sum_sqr[(y2, x2)] + i_sum_sqr[(y1, x1)] - i_sum_sqr[(y1, x2)] - i_sum_sqr[(y2, x1)];
+                variance = sum_sqr / n - (sum / n) * (sum / n);
+                if variance < 0f64 { variance = 0f64; } // rounding errors on flat areas
+                means[(row, col)] = sum / n;
+                variances[(row, col)] = variance;
+                total_variance += variance;
+                num_cells += 1f64;
+            }
+        }
+    }
+
+    let noise_var = if noise_var < 0f64 { total_variance / num_cells } else { noise_var };
+    let mut mean: f64;
+    for row in 0..rows {
+        for col in 0..columns {
+            z = input.get_value(row, col);
+            if !input.is_nodata(z) {
+                mean = means[(row, col)] + reference;
+                variance = variances[(row, col)];
+                if variance > noise_var {
+                    output.set_value(row, col, mean + (variance - noise_var) / variance * (z - mean));
+                } else {
+                    output.set_value(row, col, mean);
+                }
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,45 @@
+use std::io::Error;
+use std::io::BufWriter;
+use std::io::prelude::*;
+use std::fs::File;
+use vector::Polyline;
+
+/// Writes the polylines as a GeoJSON FeatureCollection of LineString features, storing each
+/// line's value in the property `attribute_name`.
+pub fn write_polylines<'a>(file_name: &'a str, lines: &[Polyline], attribute_name: &'a str) -> Result<(), Error> {
+    let f = File::create(file_name)?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all("{\"type\": \"FeatureCollection\", \"features\": [\n".as_bytes())?;
+    for (i, line) in lines.iter().enumerate() {
+        let coords = line.points.iter()
+                                .map(|&(x, y)| format!("[{}, {}]", x, y))
+                                .collect::<Vec<String>>()
+                                .join(", ");
+        let s = format!("{{\"type\": \"Feature\", \"properties\": {{\"{}\": {}}}, \"geometry\": {{\"type\": \"LineString\", \"coordinates\": [{}]}}}}{}\n",
+            attribute_name, line.value, coords, if i < lines.len() - 1 { "," } else { "" });
+        writer.write_all(s.as_bytes())?;
+    }
+    writer.write_all("]}\n".as_bytes())?;
+
+    let _ = writer.flush();
+
+    Ok(())
+}
+
+/// Writes the points as a GeoJSON FeatureCollection of Point features, storing each point's
+/// value (its third element) in the property `attribute_name`.
+pub fn write_points<'a>(file_name: &'a str, points: &[(f64, f64, f64)], attribute_name: &'a str) -> Result<(), Error> {
+    let f = File::create(file_name)?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all("{\"type\": \"FeatureCollection\", \"features\": [\n".as_bytes())?;
+    for (i, &(x, y, value)) in points.iter().enumerate() {
+        let s = format!("{{\"type\": \"Feature\", \"properties\": {{\"{}\": {}}}, \"geometry\": {{\"type\": \"Point\", \"coordinates\": [{}, {}]}}}}{}\n",
+            attribute_name, value, x, y, if i < points.len() - 1 { "," } else { "" });
+        writer.write_all(s.as_bytes())?;
+    }
+    writer.write_all("]}\n".as_bytes())?;
+
+    let _ = writer.flush();
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+pub mod geojson;
+pub mod shapefile;
+
+/// A line through a sequence of (x, y) world coordinates, carrying a single attribute value
+/// (e.g. the elevation of a contour).
+#[derive(Default, Clone, Debug)]
+pub struct Polyline {
+    pub points: Vec<(f64, f64)>,
+    pub value: f64,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<(f64, f64)>, value: f64) -> Polyline {
+        Polyline { points: points, value: value }
+    }
+
+    /// A polyline is closed when its last point repeats its first.
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 2 && self.points[0] == self.points[self.points.len() - 1]
+    }
+}
@@ -0,0 +1,138 @@
+use std::io::{Error, ErrorKind};
+use std::io::prelude::*;
+use std::fs::File;
+use std::path::Path;
+use io_utils::byte_order_reader::{ByteOrderReader, Endianness};
+
+/// A minimal ESRI Shapefile reader for point data, used by tools that take control points.
+pub struct Shapefile {}
+
+impl Shapefile {
+    /// Reads the point geometries of a Point, PointZ, PointM or MultiPoint shapefile. Each
+    /// point of a multipoint record is returned separately; null records are skipped.
+    pub fn read_points<'a>(file_name: &'a str) -> Result<Vec<(f64, f64, Option<f64>)>, Error> {
+        let points = read_shp_points(file_name)?;
+        Ok(points.into_iter().map(|(_, x, y)| (x, y, None)).collect())
+    }
+
+    /// Like `read_points`, but also looks up each point's value of `field_name` in the
+    /// shapefile's .dbf table. The value is None where the field is blank or not numeric.
+    pub fn read_points_with_attribute<'a>(file_name: &'a str, field_name: &'a str) -> Result<Vec<(f64, f64, Option<f64>)>, Error> {
+        let points = read_shp_points(file_name)?;
+        let values = read_dbf_field(&replace_extension(file_name, "dbf"), field_name)?;
+        Ok(points.into_iter()
+                 .map(|(record, x, y)| (x, y, if record < values.len() { values[record] } else { None }))
+                 .collect())
+    }
+}
+
+fn replace_extension<'a>(file_name: &'a str, extension: &'a str) -> String {
+    Path::new(file_name).with_extension(extension).to_str().unwrap_or(file_name).to_string()
+}
+
+/// Returns the (zero-based record index, x, y) of every point in the .shp file.
+fn read_shp_points<'a>(file_name: &'a str) -> Result<Vec<(usize, f64, f64)>, Error> {
+    let mut f = File::open(replace_extension(file_name, "shp"))?;
+    let mut buffer = vec![];
+    f.read_to_end(&mut buffer)?;
+    if buffer.len() < 100 {
+        return Err(Error::new(ErrorKind::InvalidData, format!("{} is too short to be a shapefile.", file_name)));
+    }
+
+    // The main header mixes big-endian (file code, length) and little-endian (shape type) fields.
+    let mut bor = ByteOrderReader::new(buffer, Endianness::BigEndian);
+    if bor.read_i32() != 9994 {
+        return Err(Error::new(ErrorKind::InvalidData, format!("{} is not a shapefile.", file_name)));
+    }
+    bor.seek(24);
+    let file_length = (bor.read_i32() as usize * 2).min(bor.len());
+    bor.byte_order = Endianness::LittleEndian;
+    bor.seek(32);
+    let shape_type = bor.read_i32();
+    match shape_type {
+        1 | 8 | 11 | 18 | 21 | 28 => {},
+        _ => return Err(Error::new(ErrorKind::InvalidData, format!("{} does not contain point data (shape type {}).", file_name, shape_type))),
+    }
+
+    let mut points = vec![];
+    let mut pos = 100usize;
+    let mut record = 0usize;
+    while pos + 8 <= file_length {
+        bor.byte_order = Endianness::BigEndian;
+        bor.seek(pos + 4);
+        let content_length = bor.read_i32() as usize * 2;
+        let content_start = pos + 8;
+        if content_start + content_length > file_length {
+            break;
+        }
+        bor.byte_order = Endianness::LittleEndian;
+        bor.seek(content_start);
+        match bor.read_i32() {
+            1 | 11 | 21 => {
+                let x = bor.read_f64();
+                let y = bor.read_f64();
+                points.push((record, x, y));
+            },
+            8 | 18 | 28 => {
+                bor.seek(content_start + 36); // skip the shape type and bounding box
+                let num_points = bor.read_i32() as usize;
+                for _ in 0..num_points {
+                    let x = bor.read_f64();
+                    let y = bor.read_f64();
+                    points.push((record, x, y));
+                }
+            },
+            _ => {}, // null shape
+        }
+        pos = content_start + content_length;
+        record += 1;
+    }
+
+    Ok(points)
+}
+
+/// Reads the values of one field for every record of a .dbf table.
+fn read_dbf_field<'a>(file_name: &'a str, field_name: &'a str) -> Result<Vec<Option<f64>>, Error> {
+    let mut f = File::open(file_name)?;
+    let mut buffer = vec![];
+    f.read_to_end(&mut buffer)?;
+    if buffer.len() < 32 {
+        return Err(Error::new(ErrorKind::InvalidData, format!("{} is too short to be a dBASE table.", file_name)));
+    }
+    let mut bor = ByteOrderReader::new(buffer, Endianness::LittleEndian);
+    bor.seek(4);
+    let num_records = bor.read_u32() as usize;
+    let header_length = bor.read_u16() as usize;
+    let record_length = bor.read_u16() as usize;
+
+    // Field descriptors are 32 bytes each and the list is terminated by 0x0D. Each record
+    // starts with a one-byte deletion flag.
+    let mut field_offset = 1usize;
+    let mut field: Option<(usize, usize)> = None;
+    let mut pos = 32usize;
+    while pos + 32 <= header_length && bor.buffer[pos] != 0x0D {
+        let name = String::from_utf8_lossy(&bor.buffer[pos..pos + 11]).trim_matches('\0').trim().to_string();
+        let length = bor.buffer[pos + 16] as usize;
+        if name.to_lowercase() == field_name.to_lowercase() {
+            field = Some((field_offset, length));
+        }
+        field_offset += length;
+        pos += 32;
+    }
+    let (offset, length) = match field {
+        Some(v) => v,
+        None => return Err(Error::new(ErrorKind::NotFound, format!("The field {} was not found in {}.", field_name, file_name))),
+    };
+
+    let mut values = Vec::with_capacity(num_records);
+    for i in 0..num_records {
+        let start = header_length + i * record_length + offset;
+        if start + length > bor.len() {
+            break;
+        }
+        let text = String::from_utf8_lossy(&bor.buffer[start..start + length]).trim().to_string();
+        values.push(text.parse::<f64>().ok());
+    }
+
+    Ok(values)
+}